@@ -4,13 +4,18 @@ use alloy_primitives::bytes::{Buf, BytesMut};
 use alloy_rlp::Decodable;
 use clap::Parser;
 use kakarot_rpc::{
+    config::ConfigSource,
     into_via_try_wrapper,
-    providers::{eth_provider::starknet::relayer::Relayer, sn_provider::StarknetProvider},
+    providers::{
+        eth_provider::starknet::{kakarot_core::KakarotDeployment, relayer::Relayer},
+        sn_provider::StarknetProvider,
+    },
 };
 use reth_primitives::{Block, BlockBody};
 use starknet::{
     core::types::{BlockId, BlockTag, Felt},
     providers::{jsonrpc::HttpTransport, JsonRpcClient, Provider},
+    signers::{LocalWallet, SigningKey},
 };
 use std::{path::PathBuf, str::FromStr};
 use tokio::{fs::File, io::AsyncReadExt};
@@ -50,7 +55,6 @@ pub struct Args {
 }
 
 const STARKNET_RPC_URL: &str = "http://0.0.0.0:5050";
-const MAX_FELTS_IN_CALLDATA: &str = "30000";
 
 /// Inspired by the Import command from Reth.
 /// https://github.com/paradigmxyz/reth/blob/main/bin/reth/src/commands/import.rs
@@ -62,10 +66,14 @@ async fn main() -> eyre::Result<()> {
     let provider = JsonRpcClient::new(HttpTransport::new(Url::from_str(STARKNET_RPC_URL)?));
     let starknet_provider = StarknetProvider::new(provider);
 
-    // Set the env
-    std::env::set_var("RELAYER_PRIVATE_KEY", format!("0x{:x}", args.relayer_pk));
-    std::env::set_var("MAX_FELTS_IN_CALLDATA", MAX_FELTS_IN_CALLDATA);
-    std::env::set_var("STARKNET_NETWORK", STARKNET_RPC_URL);
+    // Load the Kakarot deployment
+    let source = ConfigSource::env();
+    let deployment = KakarotDeployment::new(
+        Url::from_str(STARKNET_RPC_URL)?,
+        source.require("KAKAROT_ADDRESS")?,
+        source.require("UNINITIALIZED_ACCOUNT_CLASS_HASH")?,
+        starknet_provider.chain_id().await?,
+    );
 
     // Prepare the relayer
     let relayer_balance = starknet_provider.balance_at(args.relayer_address, BlockId::Tag(BlockTag::Latest)).await?;
@@ -75,6 +83,8 @@ async fn main() -> eyre::Result<()> {
         args.relayer_address,
         relayer_balance,
         JsonRpcClient::new(HttpTransport::new(Url::from_str(STARKNET_RPC_URL)?)),
+        LocalWallet::from_signing_key(SigningKey::from_secret_scalar(args.relayer_pk)).into(),
+        deployment,
         None,
     );
 
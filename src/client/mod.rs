@@ -1,7 +1,12 @@
 use crate::{
-    constants::{ETH_CHAIN_ID, KKRT_BLOCK_GAS_LIMIT},
+    config::{LimitsConfig, SpamProtectionConfig},
+    constants::KKRT_BLOCK_GAS_LIMIT,
+    eth_rpc::middleware::ClientId,
     pool::{
+        journal::TransactionJournal,
         mempool::{KakarotPool, TransactionOrdering},
+        monitor::RelayerFunds,
+        spam::SpamFilter,
         validate::KakarotTransactionValidatorBuilder,
     },
     providers::{
@@ -12,8 +17,9 @@ use crate::{
                 types::transaction::{ExtendedTransaction, StoredEthStarknetTransactionHash},
                 Database,
             },
-            error::SignatureError,
+            error::{SignatureError, TransactionError},
             provider::{EthApiResult, EthDataProvider},
+            starknet::kakarot_core::KakarotDeployment,
             TransactionProvider, TxPoolProvider,
         },
         sn_provider::StarknetProvider,
@@ -39,7 +45,13 @@ use std::{collections::BTreeMap, sync::Arc};
 #[async_trait]
 pub trait KakarotTransactions {
     /// Send a raw transaction to the network and returns the transactions hash.
-    async fn send_raw_transaction(&self, transaction: Bytes) -> EthApiResult<B256>;
+    async fn send_raw_transaction(&self, transaction: Bytes) -> EthApiResult<B256> {
+        self.send_raw_transaction_from(transaction, ClientId::Anonymous).await
+    }
+
+    /// Send a raw transaction submitted by the RPC client to the network and returns the
+    /// transactions hash. The transaction counts against the pool rate limit of the client.
+    async fn send_raw_transaction_from(&self, transaction: Bytes, client: ClientId) -> EthApiResult<B256>;
 }
 
 #[async_trait]
@@ -54,6 +66,9 @@ pub trait TransactionHashProvider {
 pub struct EthClient<SP: Provider + Send + Sync> {
     eth_provider: EthDataProvider<SP>,
     pool: Arc<KakarotPool<EthDataProvider<SP>>>,
+    journal: Option<Arc<TransactionJournal>>,
+    relayer_funds: Arc<RelayerFunds>,
+    spam_filter: Arc<SpamFilter>,
 }
 
 impl<SP> EthClient<SP>
@@ -65,17 +80,29 @@ where
         self.eth_provider.starknet_provider()
     }
 
-    /// Tries to start a [`EthClient`] by fetching the current chain id, initializing a [`EthDataProvider`] and a [`Pool`].
-    pub fn new(starknet_provider: SP, pool_config: PoolConfig, database: Database) -> Self {
+    /// Tries to start a [`EthClient`] serving the Kakarot deployment, initializing a [`EthDataProvider`] and a [`Pool`].
+    pub fn new(
+        starknet_provider: SP,
+        deployment: KakarotDeployment,
+        limits: LimitsConfig,
+        pool_config: PoolConfig,
+        spam_protection: SpamProtectionConfig,
+        database: Database,
+    ) -> Self {
+        let chain_id = deployment.chain_id;
         // Create a new EthDataProvider instance with the initialized database and Starknet provider.
-        let eth_provider = EthDataProvider::new(database, StarknetProvider::new(starknet_provider));
+        let eth_provider =
+            EthDataProvider::new(database, StarknetProvider::new(starknet_provider), deployment, limits.clone());
 
         let validator = KakarotTransactionValidatorBuilder::new(&Arc::new(ChainSpec {
-            chain: (*ETH_CHAIN_ID).into(),
+            chain: chain_id.into(),
             max_gas_limit: KKRT_BLOCK_GAS_LIMIT,
             ..Default::default()
         }))
+        .with_spam_protection(spam_protection)
+        .with_limits(limits)
         .build::<_, EthPooledTransaction>(eth_provider.clone());
+        let spam_filter = validator.spam_filter();
 
         let pool = Arc::new(KakarotPool::new(
             validator,
@@ -84,7 +111,14 @@ where
             pool_config,
         ));
 
-        Self { eth_provider, pool }
+        Self { eth_provider, pool, journal: None, relayer_funds: Default::default(), spam_filter }
+    }
+
+    /// Persists the transactions added to the pool in the given [`TransactionJournal`].
+    #[must_use]
+    pub fn with_journal(mut self, journal: TransactionJournal) -> Self {
+        self.journal = Some(Arc::new(journal));
+        self
     }
 
     /// Returns a clone of the [`EthDataProvider`]
@@ -96,6 +130,62 @@ where
     pub fn mempool(&self) -> Arc<KakarotPool<EthDataProvider<SP>>> {
         self.pool.clone()
     }
+
+    /// Returns whether the relayers can pay for new transactions, as reported by the relayers
+    /// monitor.
+    pub fn relayer_funds(&self) -> &RelayerFunds {
+        &self.relayer_funds
+    }
+
+    /// Returns the [`TransactionJournal`] of the pool, if any.
+    pub fn journal(&self) -> Option<&TransactionJournal> {
+        self.journal.as_deref()
+    }
+
+    /// Replays the transactions of the journal through the pool validator.
+    ///
+    /// Returns the number of transactions added back to the pool. Transactions which are no
+    /// longer valid (e.g. mined while the pool was down) are dropped.
+    pub async fn replay_journal(&self) -> std::io::Result<usize> {
+        let Some(journal) = &self.journal else {
+            return Ok(0);
+        };
+
+        let transactions = journal.load().await?;
+
+        let mut replayed = 0;
+        for transaction_signed in transactions {
+            let hash = transaction_signed.hash();
+            let pool_transaction = match into_pool_transaction(transaction_signed) {
+                Ok(pool_transaction) => pool_transaction,
+                Err(err) => {
+                    tracing::warn!(target: "transaction_journal", ?err, ?hash, "failed to recover journaled transaction");
+                    continue;
+                }
+            };
+
+            match self.pool.add_transaction(TransactionOrigin::Local, pool_transaction).await {
+                Ok(_) => replayed += 1,
+                Err(err) => {
+                    tracing::debug!(target: "transaction_journal", ?err, ?hash, "dropping journaled transaction")
+                }
+            }
+        }
+
+        Ok(replayed)
+    }
+}
+
+/// Recovers the signer of the transaction and converts it into a [`EthPooledTransaction`].
+fn into_pool_transaction(transaction_signed: TransactionSigned) -> EthApiResult<EthPooledTransaction> {
+    let signer = transaction_signed.recover_signer().ok_or(SignatureError::Recovery)?;
+
+    let transaction_signed_ec_recovered =
+        TransactionSignedEcRecovered::from_signed_transaction(transaction_signed, signer);
+
+    let encoded_length = transaction_signed_ec_recovered.clone().encode_2718_len();
+
+    Ok(EthPooledTransaction::new(transaction_signed_ec_recovered, encoded_length))
 }
 
 #[async_trait]
@@ -103,32 +193,38 @@ impl<SP> KakarotTransactions for EthClient<SP>
 where
     SP: Provider + Clone + Sync + Send,
 {
-    async fn send_raw_transaction(&self, transaction: Bytes) -> EthApiResult<B256> {
+    async fn send_raw_transaction_from(&self, transaction: Bytes, client: ClientId) -> EthApiResult<B256> {
+        // Fail fast if no relayer can pay for the transaction, instead of queuing it indefinitely
+        if !self.relayer_funds.is_funded() {
+            return Err(TransactionError::NoFundedRelayer.into());
+        }
+
         // Decode the transaction data
         let transaction_signed = TransactionSigned::decode(&mut transaction.0.as_ref())?;
 
         // Recover the signer from the transaction
-        let signer = transaction_signed.recover_signer().ok_or(SignatureError::Recovery)?;
         let hash = transaction_signed.hash();
         let to = transaction_signed.to();
-
-        let transaction_signed_ec_recovered =
-            TransactionSignedEcRecovered::from_signed_transaction(transaction_signed.clone(), signer);
-
-        let encoded_length = transaction_signed_ec_recovered.clone().encode_2718_len();
-
-        let pool_transaction = EthPooledTransaction::new(transaction_signed_ec_recovered, encoded_length);
+        let pool_transaction = into_pool_transaction(transaction_signed.clone())?;
+        let signer = pool_transaction.sender();
 
         // Deploy EVM transaction signer if Hive feature is enabled
         #[cfg(feature = "hive")]
         self.eth_provider.deploy_evm_transaction_signer(signer).await?;
 
-        // Add the transaction to the pool and wait for it to be picked up by a relayer
-        let hash = self
-            .pool
-            .add_transaction(TransactionOrigin::Local, pool_transaction)
-            .await
-            .inspect_err(|err| tracing::warn!(?err, ?hash, ?to, from = ?signer))?;
+        // Add the transaction to the pool and wait for it to be picked up by a relayer. The
+        // transaction is external, so that the pool rate limits apply to it and to its client
+        self.spam_filter.set_submitter(hash, client);
+        let res = self.pool.add_transaction(TransactionOrigin::External, pool_transaction).await;
+        self.spam_filter.remove_submitter(&hash);
+        let hash = res.inspect_err(|err| tracing::warn!(?err, ?hash, ?to, from = ?signer))?;
+
+        // Persist the transaction so that it survives a restart of the RPC
+        if let Some(journal) = &self.journal {
+            if let Err(err) = journal.append(&transaction_signed).await {
+                tracing::error!(target: "transaction_journal", ?err, ?hash, "failed to journal transaction");
+            }
+        }
 
         Ok(hash)
     }
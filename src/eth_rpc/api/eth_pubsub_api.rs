@@ -0,0 +1,22 @@
+use alloy_rpc_types::pubsub::{Params, SubscriptionKind};
+use jsonrpsee::proc_macros::rpc;
+
+openrpc_api! {
+    /// Ethereum pub-sub RPC interface, served over WebSocket.
+    #[rpc(server, namespace = "eth")]
+    #[async_trait]
+    pub trait EthPubSubApi {
+        /// Creates a subscription for the given kind and parameters, returning its id. The items
+        /// of the subscription are notified with the `eth_subscription` method.
+        #[subscription(
+            name = "subscribe" => "subscription",
+            unsubscribe = "unsubscribe",
+            item = alloy_rpc_types::pubsub::SubscriptionResult
+        )]
+        async fn subscribe(
+            &self,
+            kind: SubscriptionKind,
+            params: Option<Params>,
+        ) -> jsonrpsee::core::SubscriptionResult;
+    }
+}
@@ -1,9 +1,18 @@
-use crate::providers::eth_provider::constant::Constant;
+use crate::providers::eth_provider::{constant::Constant, database::types::transaction::RelayedTransactionStatus};
+use alloy_primitives::B256;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 
-#[rpc(server, namespace = "kakarot")]
-#[async_trait]
-pub trait KakarotApi {
-    #[method(name = "getConfig")]
-    async fn get_config(&self) -> RpcResult<Constant>;
+openrpc_api! {
+    #[rpc(server, namespace = "kakarot")]
+    #[async_trait]
+    pub trait KakarotApi {
+        /// Returns the configuration of the Kakarot RPC.
+        #[method(name = "getConfig")]
+        async fn get_config(&self) -> RpcResult<Constant>;
+
+        /// Returns the outcome of the relaying of the Ethereum transaction on Starknet, or `None` if
+        /// the transaction was never relayed.
+        #[method(name = "getTransactionStatus")]
+        async fn get_transaction_status(&self, hash: B256) -> RpcResult<Option<RelayedTransactionStatus>>;
+    }
 }
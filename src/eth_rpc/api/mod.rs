@@ -1,8 +1,92 @@
+/// Declares an `#[rpc]` API trait, along with the `openrpc_methods` function returning the
+/// `OpenRPC` description of its methods, see [`openrpc`](crate::eth_rpc::openrpc).
+///
+/// The description is derived from the declaration of the methods: their name, documentation,
+/// parameters and result, so that it can't drift from the served API.
+macro_rules! openrpc_api {
+    (@methods $methods:ident;) => {};
+    (
+        @methods $methods:ident;
+        $(#[doc = $doc:literal])*
+        #[method(name = $name:literal $($option:tt)*)]
+        async fn $fn:ident(&self $(, $param:ident: $ty:ty)* $(,)?) -> $result:ty;
+        $($rest:tt)*
+    ) => {
+        openrpc_api!(@method $methods; $name; [$($doc),*]; [$($param: $ty),*]; $result);
+        openrpc_api!(@methods $methods; $($rest)*);
+    };
+    (
+        @methods $methods:ident;
+        $(#[doc = $doc:literal])*
+        #[method(name = $name:literal $($option:tt)*)]
+        fn $fn:ident(&self $(, $param:ident: $ty:ty)* $(,)?) -> $result:ty;
+        $($rest:tt)*
+    ) => {
+        openrpc_api!(@method $methods; $name; [$($doc),*]; [$($param: $ty),*]; $result);
+        openrpc_api!(@methods $methods; $($rest)*);
+    };
+    (
+        @methods $methods:ident;
+        $(#[doc = $doc:literal])*
+        #[subscription(
+            name = $name:literal => $notification:literal,
+            unsubscribe = $unsubscribe:literal,
+            item = $item:ty $(,)?
+        )]
+        async fn $fn:ident(&self $(, $param:ident: $ty:ty)* $(,)?) -> $result:ty;
+        $($rest:tt)*
+    ) => {
+        $methods.push($crate::eth_rpc::openrpc::RpcMethod {
+            name: $name,
+            docs: vec![$($doc.trim()),*],
+            params: vec![$(openrpc_api!(@param $param: $ty)),*],
+            result: <String as $crate::eth_rpc::openrpc::schema::RpcSchema>::rpc_schema,
+        });
+        $methods.push($crate::eth_rpc::openrpc::RpcMethod {
+            name: $unsubscribe,
+            docs: vec!["Cancels the subscription with the given id."],
+            params: vec![openrpc_api!(@param subscription_id: String)],
+            result: <bool as $crate::eth_rpc::openrpc::schema::RpcSchema>::rpc_schema,
+        });
+        openrpc_api!(@methods $methods; $($rest)*);
+    };
+    (@method $methods:ident; $name:literal; [$($doc:literal),*]; [$($param:ident: $ty:ty),*]; $result:ty) => {
+        $methods.push($crate::eth_rpc::openrpc::RpcMethod {
+            name: $name,
+            docs: vec![$($doc.trim()),*],
+            params: vec![$(openrpc_api!(@param $param: $ty)),*],
+            result: <$result as $crate::eth_rpc::openrpc::schema::RpcMethodResult>::rpc_schema,
+        });
+    };
+    (@param $param:ident: $ty:ty) => {
+        $crate::eth_rpc::openrpc::RpcParam {
+            name: stringify!($param),
+            required: <$ty as $crate::eth_rpc::openrpc::schema::RpcSchema>::REQUIRED,
+            schema: <$ty as $crate::eth_rpc::openrpc::schema::RpcSchema>::rpc_schema,
+        }
+    };
+    ($(#[$($attr:tt)*])* pub trait $name:ident { $($body:tt)* }) => {
+        $(#[$($attr)*])*
+        pub trait $name { $($body)* }
+
+        /// Returns the `OpenRPC` description of the methods of the API, without the namespace of
+        /// their module.
+        pub fn openrpc_methods() -> Vec<$crate::eth_rpc::openrpc::RpcMethod> {
+            let mut methods = Vec::new();
+            openrpc_api!(@methods methods; $($body)*);
+            methods
+        }
+    };
+}
+
 pub mod alchemy_api;
 pub mod debug_api;
 pub mod eth_api;
+pub mod eth_pubsub_api;
 pub mod kakarot_api;
 pub mod net_api;
+pub mod otterscan_api;
+pub mod rpc_api;
 pub mod trace_api;
 pub mod txpool_api;
 pub mod web3_api;
@@ -0,0 +1,72 @@
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_rpc_types::BlockId;
+use alloy_rpc_types_trace::otterscan::{
+    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, TraceEntry, TransactionsWithReceipts,
+};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+openrpc_api! {
+    /// Otterscan API, see <https://github.com/otterscan/otterscan/blob/main/docs/custom-jsonrpc.md>.
+    #[rpc(server, namespace = "ots")]
+    #[async_trait]
+    pub trait OtterscanApi {
+        /// Returns the Otterscan API level implemented by the node.
+        #[method(name = "getApiLevel")]
+        async fn get_api_level(&self) -> RpcResult<u64>;
+
+        /// Returns true if the address has code at the given block.
+        #[method(name = "hasCode")]
+        async fn has_code(&self, address: Address, block_id: Option<BlockId>) -> RpcResult<bool>;
+
+        /// Returns the value transfers and contract creations that happened inside the transaction.
+        #[method(name = "getInternalOperations")]
+        async fn get_internal_operations(&self, transaction_hash: B256) -> RpcResult<Vec<InternalOperation>>;
+
+        /// Returns the revert data of the transaction, or empty bytes if the transaction succeeded.
+        #[method(name = "getTransactionError")]
+        async fn get_transaction_error(&self, transaction_hash: B256) -> RpcResult<Option<Bytes>>;
+
+        /// Returns the call tree of the transaction.
+        #[method(name = "traceTransaction")]
+        async fn trace_transaction(&self, transaction_hash: B256) -> RpcResult<Option<Vec<TraceEntry>>>;
+
+        /// Returns the block details, without the transactions.
+        #[method(name = "getBlockDetails")]
+        async fn get_block_details(&self, block_number: u64) -> RpcResult<BlockDetails>;
+
+        /// Returns a page of the transactions of the block along with their receipts.
+        #[method(name = "getBlockTransactions")]
+        async fn get_block_transactions(
+            &self,
+            block_number: u64,
+            page_number: usize,
+            page_size: usize,
+        ) -> RpcResult<OtsBlockTransactions>;
+
+        /// Returns the transactions sent from or to the address before the given block (excluded).
+        #[method(name = "searchTransactionsBefore")]
+        async fn search_transactions_before(
+            &self,
+            address: Address,
+            block_number: u64,
+            page_size: usize,
+        ) -> RpcResult<TransactionsWithReceipts>;
+
+        /// Returns the transactions sent from or to the address after the given block (excluded).
+        #[method(name = "searchTransactionsAfter")]
+        async fn search_transactions_after(
+            &self,
+            address: Address,
+            block_number: u64,
+            page_size: usize,
+        ) -> RpcResult<TransactionsWithReceipts>;
+
+        /// Returns the hash of the transaction sent by the address with the given nonce.
+        #[method(name = "getTransactionBySenderAndNonce")]
+        async fn get_transaction_by_sender_and_nonce(&self, sender: Address, nonce: u64) -> RpcResult<Option<B256>>;
+
+        /// Returns the creator of the contract deployed at the address.
+        #[method(name = "getContractCreator")]
+        async fn get_contract_creator(&self, address: Address) -> RpcResult<Option<ContractCreator>>;
+    }
+}
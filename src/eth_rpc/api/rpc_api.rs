@@ -0,0 +1,18 @@
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+openrpc_api! {
+    /// Introspection API, describing the RPC modules and methods served by the node.
+    #[rpc(server)]
+    #[async_trait]
+    pub trait RpcApi {
+        /// Returns the enabled RPC modules, mapped to their version.
+        #[method(name = "rpc_modules")]
+        fn rpc_modules(&self) -> RpcResult<BTreeMap<String, String>>;
+
+        /// Returns the OpenRPC document of the methods of the enabled RPC modules.
+        #[method(name = "rpc.discover")]
+        fn discover(&self) -> RpcResult<Value>;
+    }
+}
@@ -1,12 +1,74 @@
-use alloy_rpc_types::BlockId;
-use alloy_rpc_types_trace::parity::LocalizedTransactionTrace;
+use alloy_primitives::B256;
+use alloy_rpc_types::{state::StateOverride, BlockId, BlockOverrides, Index, TransactionRequest};
+use alloy_rpc_types_trace::{
+    filter::TraceFilter,
+    parity::{LocalizedTransactionTrace, TraceResults, TraceResultsWithTransactionHash, TraceType},
+};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use std::collections::HashSet;
 
-/// Trace API
-#[rpc(server, namespace = "trace")]
-#[async_trait]
-pub trait TraceApi {
-    /// Returns the parity traces for the given block.
-    #[method(name = "block")]
-    async fn trace_block(&self, block_id: BlockId) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>>;
+openrpc_api! {
+    /// Trace API
+    #[rpc(server, namespace = "trace")]
+    #[async_trait]
+    pub trait TraceApi {
+        /// Returns the parity traces for the given block.
+        #[method(name = "block")]
+        async fn trace_block(&self, block_id: BlockId) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>>;
+
+        /// Returns the parity traces for the given transaction.
+        #[method(name = "transaction")]
+        async fn trace_transaction(&self, transaction_hash: B256) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>>;
+
+        /// Returns the parity trace at the given position of the traces of the transaction.
+        ///
+        /// Only a single index is supported.
+        #[method(name = "get")]
+        async fn trace_get(
+            &self,
+            transaction_hash: B256,
+            indices: Vec<Index>,
+        ) -> RpcResult<Option<LocalizedTransactionTrace>>;
+
+        /// Executes the call on top of the given block and returns the selected trace types.
+        #[method(name = "call")]
+        async fn trace_call(
+            &self,
+            request: TransactionRequest,
+            trace_types: HashSet<TraceType>,
+            block_id: Option<BlockId>,
+            state_overrides: Option<StateOverride>,
+            block_overrides: Option<Box<BlockOverrides>>,
+        ) -> RpcResult<TraceResults>;
+
+        /// Executes the calls sequentially on top of the given block and returns the selected
+        /// trace types for each call. Each call is executed on top of the state changes of the
+        /// previous calls.
+        #[method(name = "callMany")]
+        async fn trace_call_many(
+            &self,
+            calls: Vec<(TransactionRequest, HashSet<TraceType>)>,
+            block_id: Option<BlockId>,
+        ) -> RpcResult<Vec<TraceResults>>;
+
+        /// Replays the transaction and returns the selected trace types.
+        #[method(name = "replayTransaction")]
+        async fn trace_replay_transaction(
+            &self,
+            transaction_hash: B256,
+            trace_types: HashSet<TraceType>,
+        ) -> RpcResult<TraceResults>;
+
+        /// Replays all the transactions of the block and returns the selected trace types.
+        #[method(name = "replayBlockTransactions")]
+        async fn trace_replay_block_transactions(
+            &self,
+            block_id: BlockId,
+            trace_types: HashSet<TraceType>,
+        ) -> RpcResult<Option<Vec<TraceResultsWithTransactionHash>>>;
+
+        /// Returns the parity traces matching the filter.
+        #[method(name = "filter")]
+        async fn trace_filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTransactionTrace>>;
+    }
 }
@@ -1,19 +1,73 @@
+use crate::{
+    config::ConfigSource,
+    eth_rpc::{
+        middleware::{AuthConfig, RateLimitConfig},
+        rpc::KakarotRpcModule,
+    },
+};
 use eyre::{eyre, Result};
+use std::net::{IpAddr, SocketAddr};
+
+/// The default maximum number of connections of the RPC server.
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 100;
+
+/// The default port serving the Prometheus metrics.
+pub const DEFAULT_PROMETHEUS_PORT: u16 = 9615;
 
 #[derive(Debug, Clone)]
 pub struct RPCConfig {
     pub socket_addr: String,
+    /// The maximum number of connections of the RPC server.
+    pub max_connections: u32,
+    /// The port serving the Prometheus metrics.
+    pub prometheus_port: u16,
+    /// The rate limit of the clients without an API key, disabled if `None`.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// The API keys of the clients, all clients can call all methods if `None`.
+    pub auth: Option<AuthConfig>,
+    /// The IP addresses of the proxies whose `X-Forwarded-For` header identifies the clients.
+    pub trusted_proxies: Vec<IpAddr>,
+    /// The enabled RPC modules, all modules are enabled if `None`.
+    pub modules: Option<Vec<KakarotRpcModule>>,
 }
 
 impl RPCConfig {
     pub const fn new(socket_addr: String) -> Self {
-        Self { socket_addr }
+        Self {
+            socket_addr,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            prometheus_port: DEFAULT_PROMETHEUS_PORT,
+            rate_limit: None,
+            auth: None,
+            trusted_proxies: Vec::new(),
+            modules: None,
+        }
     }
 
     pub fn from_env() -> Result<Self> {
-        let socket_addr = std::env::var("KAKAROT_RPC_URL")
-            .map_err(|_| eyre!("Missing mandatory environment variable: KAKAROT_RPC_URL"))?;
-        Ok(Self::new(socket_addr))
+        Self::from_source(&ConfigSource::env())
+    }
+
+    /// `KAKAROT_RPC_URL` setting is the socket address of the server, e.g. `127.0.0.1:3030`.
+    ///
+    /// `RPC_API` setting is a comma separated list of the enabled RPC modules, e.g.
+    /// `eth,net,web3`. All modules are enabled if it is not set.
+    ///
+    /// `RPC_TRUSTED_PROXIES` setting is a comma separated list of the IP addresses of the proxies
+    /// in front of the server, e.g. `10.0.0.1,10.0.0.2`.
+    pub fn from_source(source: &ConfigSource) -> Result<Self> {
+        let socket_addr: String = source.require("KAKAROT_RPC_URL")?;
+        socket_addr.parse::<SocketAddr>().map_err(|err| eyre!("invalid KAKAROT_RPC_URL {socket_addr}: {err}"))?;
+
+        Ok(Self {
+            max_connections: source.parse("RPC_MAX_CONNECTIONS")?.unwrap_or(DEFAULT_MAX_CONNECTIONS),
+            prometheus_port: source.parse("PROMETHEUS_PORT")?.unwrap_or(DEFAULT_PROMETHEUS_PORT),
+            rate_limit: RateLimitConfig::from_source(source)?,
+            auth: AuthConfig::from_source(source)?,
+            trusted_proxies: source.parse_list::<IpAddr, _>("RPC_TRUSTED_PROXIES")?.unwrap_or_default(),
+            modules: source.parse_list::<KakarotRpcModule, _>("RPC_API")?,
+            ..Self::new(socket_addr)
+        })
     }
 
     pub fn from_port(port: u16) -> Result<Self> {
@@ -0,0 +1,265 @@
+use crate::{
+    client::EthClient,
+    providers::eth_provider::{
+        database::ethereum::EthereumBlockStore, error::EthApiError, provider::EthApiResult, BlockProvider, LogProvider,
+    },
+};
+use alloy_primitives::{B256, U64};
+use alloy_rpc_types::{Filter, FilterBlockOption, FilterChanges};
+use reth_transaction_pool::{TransactionListenerKind, TransactionPool};
+use starknet::providers::Provider;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::{mpsc::Receiver, Mutex};
+
+/// Filters that are not polled during this duration are uninstalled.
+pub const FILTER_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The kind of an installed filter, along with its polling cursor.
+#[derive(Debug)]
+enum FilterKind {
+    /// Log filter, with the last block for which logs were returned.
+    Log { filter: Box<Filter>, last_block: u64 },
+    /// Block filter, with the last block for which a hash was returned.
+    Block { last_block: u64 },
+    /// Pending transaction filter, receiving the hashes of the transactions added to the pool.
+    PendingTransaction(Receiver<B256>),
+}
+
+/// A filter installed by a client.
+#[derive(Debug)]
+struct ActiveFilter {
+    kind: FilterKind,
+    last_poll: Instant,
+}
+
+/// The set of installed filters, indexed by filter id.
+#[derive(Debug, Default)]
+struct ActiveFilters {
+    filters: HashMap<U64, ActiveFilter>,
+}
+
+impl ActiveFilters {
+    /// Inserts a filter under a fresh random id, so that the ids of the filters of other clients
+    /// can't be guessed, and returns the id.
+    fn insert(&mut self, kind: FilterKind, now: Instant) -> U64 {
+        let id = loop {
+            let id = U64::from(rand::random::<u64>());
+            if !self.filters.contains_key(&id) {
+                break id;
+            }
+        };
+        self.filters.insert(id, ActiveFilter { kind, last_poll: now });
+        id
+    }
+
+    /// Removes the filter with the given id, returning true if it existed.
+    fn remove(&mut self, id: U64) -> bool {
+        self.filters.remove(&id).is_some()
+    }
+
+    /// Removes all the filters that were not polled since `timeout`.
+    fn evict_stale(&mut self, now: Instant, timeout: Duration) {
+        self.filters.retain(|_, filter| now.saturating_duration_since(filter.last_poll) < timeout);
+    }
+
+    /// Returns a mutable reference to the filter with the given id and refreshes its last poll time.
+    fn poll(&mut self, id: U64, now: Instant) -> Option<&mut FilterKind> {
+        self.filters.get_mut(&id).map(|filter| {
+            filter.last_poll = now;
+            &mut filter.kind
+        })
+    }
+}
+
+/// Manager for the stateful polling filters of the `eth_` namespace
+/// (`eth_newFilter`, `eth_newBlockFilter`, `eth_newPendingTransactionFilter`, ...).
+#[derive(Debug)]
+pub struct EthFilters<SP>
+where
+    SP: Provider + Send + Sync,
+{
+    eth_client: Arc<EthClient<SP>>,
+    active_filters: Arc<Mutex<ActiveFilters>>,
+}
+
+impl<SP> EthFilters<SP>
+where
+    SP: Provider + Clone + Send + Sync + 'static,
+{
+    /// Creates a new filter manager, evicting filters idle for more than [`FILTER_IDLE_TIMEOUT`].
+    pub fn new(eth_client: Arc<EthClient<SP>>) -> Self {
+        Self::with_idle_timeout(eth_client, FILTER_IDLE_TIMEOUT)
+    }
+
+    /// Creates a new filter manager with a custom idle timeout.
+    ///
+    /// The idle filters are evicted by a background task, every half of the idle timeout, until the
+    /// manager is dropped.
+    pub fn with_idle_timeout(eth_client: Arc<EthClient<SP>>, idle_timeout: Duration) -> Self {
+        let active_filters = Arc::<Mutex<ActiveFilters>>::default();
+
+        let weak_filters = Arc::downgrade(&active_filters);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(idle_timeout / 2);
+            loop {
+                interval.tick().await;
+                let Some(active_filters) = weak_filters.upgrade() else {
+                    return;
+                };
+                active_filters.lock().await.evict_stale(Instant::now(), idle_timeout);
+            }
+        });
+
+        Self { eth_client, active_filters }
+    }
+
+    /// Installs a log filter, returning its id.
+    pub async fn new_filter(&self, filter: Filter) -> EthApiResult<U64> {
+        let last_block = self.current_block().await?;
+        Ok(self.install(FilterKind::Log { filter: Box::new(filter), last_block }).await)
+    }
+
+    /// Installs a block filter, returning its id.
+    pub async fn new_block_filter(&self) -> EthApiResult<U64> {
+        let last_block = self.current_block().await?;
+        Ok(self.install(FilterKind::Block { last_block }).await)
+    }
+
+    /// Installs a pending transaction filter, returning its id.
+    pub async fn new_pending_transaction_filter(&self) -> U64 {
+        let receiver = self.eth_client.mempool().pending_transactions_listener_for(TransactionListenerKind::All);
+        self.install(FilterKind::PendingTransaction(receiver)).await
+    }
+
+    /// Uninstalls the filter with the given id, returning true if the filter existed.
+    pub async fn uninstall_filter(&self, id: U64) -> bool {
+        self.active_filters.lock().await.remove(id)
+    }
+
+    /// Returns the changes of the filter with the given id since the last poll.
+    pub async fn filter_changes(&self, id: U64) -> EthApiResult<FilterChanges> {
+        let current_block = self.current_block().await?;
+
+        // Take a snapshot of the cursor and release the lock before querying the database.
+        let snapshot = {
+            let mut active_filters = self.active_filters.lock().await;
+            match active_filters.poll(id, Instant::now()).ok_or(EthApiError::FilterNotFound(id))? {
+                FilterKind::PendingTransaction(receiver) => {
+                    let mut hashes = Vec::new();
+                    while let Ok(hash) = receiver.try_recv() {
+                        hashes.push(hash);
+                    }
+                    return Ok(FilterChanges::Hashes(hashes));
+                }
+                FilterKind::Log { filter, last_block } => (Some(filter.clone()), *last_block),
+                FilterKind::Block { last_block } => (None, *last_block),
+            }
+        };
+
+        let (filter, last_block) = snapshot;
+        if current_block <= last_block {
+            return Ok(FilterChanges::Empty);
+        }
+
+        let changes = match filter {
+            Some(filter) => self.log_changes(*filter, last_block, current_block).await?,
+            None => self.block_changes(last_block, current_block).await?,
+        };
+
+        // Move the cursor forward, unless the filter was uninstalled in the meantime.
+        if let Some(FilterKind::Log { last_block, .. } | FilterKind::Block { last_block }) =
+            self.active_filters.lock().await.poll(id, Instant::now())
+        {
+            *last_block = current_block;
+        }
+
+        Ok(changes)
+    }
+
+    /// Returns all the logs matching the log filter with the given id.
+    pub async fn filter_logs(&self, id: U64) -> EthApiResult<FilterChanges> {
+        let filter = {
+            match self.active_filters.lock().await.poll(id, Instant::now()) {
+                Some(FilterKind::Log { filter, .. }) => filter.clone(),
+                _ => return Err(EthApiError::FilterNotFound(id)),
+            }
+        };
+        self.eth_client.eth_provider().get_logs(*filter).await
+    }
+
+    /// Stores the filter under a fresh id.
+    async fn install(&self, kind: FilterKind) -> U64 {
+        self.active_filters.lock().await.insert(kind, Instant::now())
+    }
+
+    /// Returns the logs matching the filter in the (`last_block`, `current_block`] range.
+    async fn log_changes(&self, filter: Filter, last_block: u64, current_block: u64) -> EthApiResult<FilterChanges> {
+        // Logs of a filter on a block hash are only returned by `eth_getFilterLogs`.
+        if matches!(filter.block_option, FilterBlockOption::AtBlockHash(_)) {
+            return Ok(FilterChanges::Empty);
+        }
+
+        let from = filter.get_from_block().unwrap_or_default().max(last_block + 1);
+        let to = filter.get_to_block().unwrap_or(current_block).min(current_block);
+        if from > to {
+            return Ok(FilterChanges::Empty);
+        }
+
+        self.eth_client.eth_provider().get_logs(filter.from_block(from).to_block(to)).await
+    }
+
+    /// Returns the hashes of the blocks in the (`last_block`, `current_block`] range.
+    async fn block_changes(&self, last_block: u64, current_block: u64) -> EthApiResult<FilterChanges> {
+        let headers = self.eth_client.eth_provider().database().headers(last_block + 1, current_block).await?;
+        Ok(FilterChanges::Hashes(headers.into_iter().map(|header| header.hash).collect()))
+    }
+
+    /// Returns the current block number.
+    async fn current_block(&self) -> EthApiResult<u64> {
+        let block_number = self.eth_client.eth_provider().block_number().await?;
+        block_number.try_into().map_err(|_| EthApiError::UnknownBlockNumber(Some(block_number.to())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evict_stale_filters() {
+        // Given
+        let mut active_filters = ActiveFilters::default();
+        let now = Instant::now();
+        let stale_id = active_filters.insert(FilterKind::Block { last_block: 0 }, now);
+        let id = active_filters.insert(FilterKind::Block { last_block: 0 }, now + Duration::from_secs(10));
+
+        // When
+        active_filters.evict_stale(now + Duration::from_secs(15), Duration::from_secs(10));
+
+        // Then
+        assert_ne!(stale_id, id);
+        assert!(!active_filters.filters.contains_key(&stale_id));
+        assert!(active_filters.filters.contains_key(&id));
+    }
+
+    #[test]
+    fn test_poll_refreshes_filter() {
+        // Given
+        let mut active_filters = ActiveFilters::default();
+        let now = Instant::now();
+        let id = active_filters.insert(FilterKind::Block { last_block: 0 }, now);
+
+        // When
+        assert!(active_filters.poll(id, now + Duration::from_secs(8)).is_some());
+        active_filters.evict_stale(now + Duration::from_secs(15), Duration::from_secs(10));
+
+        // Then
+        assert!(active_filters.poll(id, now).is_some());
+        assert!(active_filters.remove(id));
+        assert!(!active_filters.remove(id));
+    }
+}
@@ -0,0 +1,608 @@
+//! RPC middleware to authenticate the clients with API keys, and restrict the RPC modules they
+//! can call.
+
+use super::rate_limit::RateLimitConfig;
+use crate::{
+    config::ConfigSource,
+    eth_rpc::{openrpc::openrpc_document, rpc::KakarotRpcModule},
+    providers::eth_provider::error::EthRpcErrorCode,
+};
+use eyre::eyre;
+use futures::future::Either;
+use hyper::Request as HttpRequest;
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Id, Request, ResponsePayload},
+    MethodResponse,
+};
+use pin_project_lite::pin_project;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet},
+    future::{ready, Future, Ready},
+    net::{IpAddr, SocketAddr},
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// The header carrying the API key of the client.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The method listing the enabled RPC modules.
+const RPC_MODULES_METHOD: &str = "rpc_modules";
+
+/// The method returning the `OpenRPC` document of the enabled RPC modules.
+const RPC_DISCOVER_METHOD: &str = "rpc.discover";
+
+/// The modules available to the clients without an API key, if not configured.
+const DEFAULT_MODULES: [KakarotRpcModule; 3] = [KakarotRpcModule::Eth, KakarotRpcModule::Net, KakarotRpcModule::Web3];
+
+/// The address of the peer of the connection, inserted in the extensions of its HTTP requests by
+/// the [`PeerAddrService`] of the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PeerAddr(pub SocketAddr);
+
+/// The identity of the client of an RPC call.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClientId {
+    /// A client authenticated by its API key.
+    ApiKey(String),
+    /// A client identified by its IP address.
+    Ip(IpAddr),
+    /// A client which couldn't be identified. All such clients share the same rate limit.
+    Anonymous,
+}
+
+impl ClientId {
+    /// Identifies the client of the HTTP request.
+    ///
+    /// Clients with an API key of the [`AuthConfig`], given in the [`API_KEY_HEADER`] header or
+    /// as the path of the request, are identified by it. Other clients are identified by the IP
+    /// address of the peer of the connection, see [`client_ip`].
+    pub fn from_request<B>(request: &HttpRequest<B>, auth: Option<&AuthConfig>, trusted_proxies: &[IpAddr]) -> Self {
+        let headers = request.headers();
+
+        if let Some(auth) = auth {
+            let api_key = headers
+                .get(API_KEY_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+                .filter(|api_key| !api_key.is_empty())
+                .unwrap_or_else(|| request.uri().path().trim_matches('/'));
+            if auth.api_key(api_key).is_some() {
+                return Self::ApiKey(api_key.to_string());
+            }
+        }
+
+        request
+            .extensions()
+            .get::<PeerAddr>()
+            .map_or(Self::Anonymous, |peer| Self::Ip(client_ip(request, peer.0.ip(), trusted_proxies)))
+    }
+}
+
+/// Returns the IP address of the client of the HTTP request received from the peer.
+///
+/// The `X-Forwarded-For` header is only trusted when the peer is one of the trusted proxies, in
+/// which case the client is its rightmost hop which isn't a trusted proxy. The hops on the left
+/// of it are set by the client and ignored.
+fn client_ip<B>(request: &HttpRequest<B>, peer: IpAddr, trusted_proxies: &[IpAddr]) -> IpAddr {
+    let mut client = peer;
+    if !trusted_proxies.contains(&client) {
+        return client;
+    }
+
+    let hops = request
+        .headers()
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect::<Vec<_>>();
+    for hop in hops.into_iter().rev() {
+        // An invalid hop can't be trusted to identify the client, stop at the last proxy
+        let Ok(ip) = hop.trim().parse::<IpAddr>() else {
+            break;
+        };
+        client = ip;
+        if !trusted_proxies.contains(&client) {
+            break;
+        }
+    }
+    client
+}
+
+/// The rate limit of an API key. The calls are weighted as for the clients without API key.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyRateLimit {
+    /// The number of tokens refilled per second.
+    pub rate: f64,
+    /// The maximum number of tokens of the API key, defaults to the rate.
+    pub burst: Option<f64>,
+}
+
+/// The configuration of an API key.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyConfig {
+    /// The API key.
+    pub key: String,
+    /// The RPC modules the API key can call.
+    pub modules: HashSet<KakarotRpcModule>,
+    /// The rate limit of the API key, unlimited if `None`.
+    #[serde(default)]
+    pub rate_limit: Option<ApiKeyRateLimit>,
+}
+
+/// The API keys allowed to call the RPC, loaded from a TOML file such as:
+///
+/// ```toml
+/// default_modules = ["eth", "net", "web3"]
+///
+/// [[api_keys]]
+/// key = "partner-key"
+/// modules = ["eth", "net", "web3", "debug", "trace"]
+/// rate_limit = { rate = 50, burst = 100 }
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthConfig {
+    /// The RPC modules the clients without an API key can call.
+    #[serde(default = "default_modules")]
+    pub default_modules: HashSet<KakarotRpcModule>,
+    /// The API keys.
+    #[serde(default)]
+    pub api_keys: Vec<ApiKeyConfig>,
+}
+
+fn default_modules() -> HashSet<KakarotRpcModule> {
+    DEFAULT_MODULES.into()
+}
+
+impl AuthConfig {
+    /// `RPC_AUTH_CONFIG_PATH` setting enables the authentication, with the path of the TOML
+    /// file of the API keys.
+    pub fn from_source(source: &ConfigSource) -> eyre::Result<Option<Self>> {
+        source.get("RPC_AUTH_CONFIG_PATH").map(Self::from_file).transpose()
+    }
+
+    /// Loads the configuration from the TOML file.
+    pub fn from_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| eyre!("failed to read auth config {}: {err}", path.display()))?;
+        content.parse()
+    }
+
+    /// Returns the configuration of the API key, if it exists.
+    pub fn api_key(&self, api_key: &str) -> Option<&ApiKeyConfig> {
+        self.api_keys.iter().find(|config| config.key == api_key)
+    }
+
+    /// Returns the RPC modules the client can call.
+    pub fn modules(&self, client: &ClientId) -> &HashSet<KakarotRpcModule> {
+        match client {
+            ClientId::ApiKey(api_key) => self.api_key(api_key).map_or(&self.default_modules, |config| &config.modules),
+            ClientId::Ip(_) | ClientId::Anonymous => &self.default_modules,
+        }
+    }
+
+    /// Returns whether the client can call the method. Methods outside of the RPC modules, such
+    /// as `rpc_modules` and `rpc.discover`, are always allowed.
+    pub fn is_allowed(&self, client: &ClientId, method: &str) -> bool {
+        KakarotRpcModule::from_method(method).map_or(true, |module| self.modules(client).contains(&module))
+    }
+
+    /// Restricts the result of the `rpc_modules` and `rpc.discover` methods to the modules the
+    /// client can call, so that the modules of the other clients aren't disclosed. The results
+    /// of the other methods are returned as is.
+    pub fn filter_result(&self, client: &ClientId, method: &str, mut result: Value) -> Value {
+        let modules = self.modules(client);
+        match method {
+            RPC_MODULES_METHOD => {
+                if let Value::Object(namespaces) = &mut result {
+                    namespaces.retain(|namespace, _| {
+                        namespace.parse::<KakarotRpcModule>().map_or(true, |module| modules.contains(&module))
+                    });
+                }
+                result
+            }
+            RPC_DISCOVER_METHOD => {
+                // The document is generated again rather than filtered, so that it doesn't keep
+                // the schemas of the methods of the other modules
+                let served = result["methods"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|method| KakarotRpcModule::from_method(method["name"].as_str()?))
+                    .collect::<HashSet<_>>();
+                let described = KakarotRpcModule::ALL
+                    .into_iter()
+                    .filter(|module| served.contains(module) && modules.contains(module))
+                    .collect::<Vec<_>>();
+                openrpc_document(&described)
+            }
+            _ => result,
+        }
+    }
+
+    /// Returns the rate limits of the API keys, using the method weights of the rate limit of
+    /// the clients without an API key.
+    pub fn rate_limits(&self, config: Option<&RateLimitConfig>) -> HashMap<String, RateLimitConfig> {
+        self.api_keys
+            .iter()
+            .filter_map(|api_key| {
+                let rate_limit = api_key.rate_limit?;
+                let burst = rate_limit.burst.unwrap_or(rate_limit.rate);
+                Some((api_key.key.clone(), RateLimitConfig::with_rate(config, rate_limit.rate, burst)))
+            })
+            .collect()
+    }
+}
+
+impl std::str::FromStr for AuthConfig {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config: Self = toml::from_str(s)?;
+
+        let mut keys = HashSet::new();
+        for api_key in &config.api_keys {
+            if api_key.key.is_empty() || api_key.key.contains('/') {
+                return Err(eyre!("invalid API key: {:?}", api_key.key));
+            }
+            if !keys.insert(&api_key.key) {
+                return Err(eyre!("duplicate API key: {}", api_key.key));
+            }
+            if let Some(ApiKeyRateLimit { rate, burst }) = api_key.rate_limit {
+                let burst = burst.unwrap_or(rate);
+                if !(rate.is_finite() && burst.is_finite() && rate > 0. && burst >= 1.) {
+                    return Err(eyre!("invalid rate limit of API key {}: rate {rate}, burst {burst}", api_key.key));
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// HTTP layer identifying the client of the requests, see [`ClientId::from_request`].
+#[derive(Clone, Debug, Default)]
+pub struct ClientIdLayer {
+    auth: Option<Arc<AuthConfig>>,
+    trusted_proxies: Arc<[IpAddr]>,
+}
+
+impl ClientIdLayer {
+    /// Create a new [`ClientIdLayer`], authenticating the clients with the API keys of the
+    /// configuration, if any, and trusting the forwarded IP addresses of the trusted proxies.
+    pub const fn new(auth: Option<Arc<AuthConfig>>, trusted_proxies: Arc<[IpAddr]>) -> Self {
+        Self { auth, trusted_proxies }
+    }
+}
+
+impl<S> tower::Layer<S> for ClientIdLayer {
+    type Service = ClientIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClientIdService { inner, auth: self.auth.clone(), trusted_proxies: self.trusted_proxies.clone() }
+    }
+}
+
+/// HTTP middleware adding the [`ClientId`] to the extensions of the requests, from which it is
+/// propagated to the RPC calls.
+#[derive(Clone, Debug)]
+pub struct ClientIdService<S> {
+    inner: S,
+    auth: Option<Arc<AuthConfig>>,
+    trusted_proxies: Arc<[IpAddr]>,
+}
+
+impl<S, B> tower::Service<HttpRequest<B>> for ClientIdService<S>
+where
+    S: tower::Service<HttpRequest<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: HttpRequest<B>) -> Self::Future {
+        let client_id = ClientId::from_request(&request, self.auth.as_deref(), &self.trusted_proxies);
+        request.extensions_mut().insert(client_id);
+        self.inner.call(request)
+    }
+}
+
+/// HTTP service adding the [`PeerAddr`] of the connection to the extensions of its requests.
+#[derive(Clone, Debug)]
+pub struct PeerAddrService<S> {
+    inner: S,
+    peer: SocketAddr,
+}
+
+impl<S> PeerAddrService<S> {
+    /// Create a new [`PeerAddrService`] for the connection of the peer.
+    pub const fn new(inner: S, peer: SocketAddr) -> Self {
+        Self { inner, peer }
+    }
+}
+
+impl<S, B> tower::Service<HttpRequest<B>> for PeerAddrService<S>
+where
+    S: tower::Service<HttpRequest<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: HttpRequest<B>) -> Self::Future {
+        request.extensions_mut().insert(PeerAddr(self.peer));
+        self.inner.call(request)
+    }
+}
+
+/// Authorization layer.
+#[derive(Clone, Debug)]
+pub struct AuthorizationLayer {
+    auth: Arc<AuthConfig>,
+}
+
+impl AuthorizationLayer {
+    /// Create a new [`AuthorizationLayer`].
+    pub const fn new(auth: Arc<AuthConfig>) -> Self {
+        Self { auth }
+    }
+}
+
+impl<S> tower::Layer<S> for AuthorizationLayer {
+    type Service = Authorization<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        Authorization { service, auth: self.auth.clone() }
+    }
+}
+
+/// Authorization middleware, rejecting the calls to the RPC modules the client is not allowed
+/// to call as if the method didn't exist.
+///
+/// The `rpc_modules` and `rpc.discover` methods can be called by all the clients, but only
+/// describe the modules the client is allowed to call, see [`AuthConfig::filter_result`].
+#[derive(Clone, Debug)]
+pub struct Authorization<S> {
+    service: S,
+    auth: Arc<AuthConfig>,
+}
+
+impl<'a, S> RpcServiceT<'a> for Authorization<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = Either<AuthorizedResponse<S::Future>, Ready<MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let client = req.extensions().get::<ClientId>().cloned().unwrap_or(ClientId::Anonymous);
+        if self.auth.is_allowed(&client, req.method_name()) {
+            let filter = matches!(req.method_name(), RPC_MODULES_METHOD | RPC_DISCOVER_METHOD).then(|| ResultFilter {
+                auth: self.auth.clone(),
+                client,
+                method: req.method_name().to_string(),
+                id: req.id().into_owned(),
+            });
+            return Either::Left(AuthorizedResponse { fut: self.service.call(req), filter });
+        }
+
+        tracing::debug!(target: "rpc_auth", ?client, method = req.method_name(), "unauthorized");
+        Either::Right(ready(MethodResponse::error(
+            req.id(),
+            ErrorObject::owned(
+                EthRpcErrorCode::MethodNotFound as i32,
+                format!("the method {} does not exist/is not available", req.method_name()),
+                None::<()>,
+            ),
+        )))
+    }
+}
+
+/// The call whose result is filtered by [`AuthConfig::filter_result`].
+#[derive(Debug)]
+struct ResultFilter {
+    auth: Arc<AuthConfig>,
+    client: ClientId,
+    method: String,
+    id: Id<'static>,
+}
+
+impl ResultFilter {
+    /// Filters the result of the successful response.
+    fn apply(self, response: MethodResponse) -> MethodResponse {
+        if !response.is_success() {
+            return response;
+        }
+        let Ok(mut body) = serde_json::from_str::<Value>(response.as_result()) else {
+            return response;
+        };
+        let result = self.auth.filter_result(&self.client, &self.method, body["result"].take());
+        MethodResponse::response(self.id, ResponsePayload::success(result), usize::MAX)
+    }
+}
+
+pin_project! {
+    /// Response future of an authorized call.
+    pub struct AuthorizedResponse<F> {
+        #[pin]
+        fut: F,
+        filter: Option<ResultFilter>,
+    }
+}
+
+impl<F> std::fmt::Debug for AuthorizedResponse<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AuthorizedResponse")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for AuthorizedResponse<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let mut response = std::task::ready!(this.fut.poll(cx));
+        if let Some(filter) = this.filter.take() {
+            response = filter.apply(response);
+        }
+        Poll::Ready(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [[api_keys]]
+        key = "partner"
+        modules = ["eth", "debug", "ots"]
+        rate_limit = { rate = 10 }
+
+        [[api_keys]]
+        key = "internal"
+        modules = ["eth", "debug", "trace", "txpool"]
+    "#;
+
+    #[test]
+    fn test_auth_config_is_allowed() {
+        // Given
+        let config: AuthConfig = CONFIG.parse().expect("Failed to parse auth config");
+        let partner = ClientId::ApiKey("partner".to_string());
+
+        // When & Then
+        assert_eq!(config.default_modules, default_modules());
+        assert!(config.is_allowed(&ClientId::Anonymous, "eth_getLogs"));
+        assert!(config.is_allowed(&ClientId::Anonymous, "net_version"));
+        assert!(!config.is_allowed(&ClientId::Anonymous, "debug_traceTransaction"));
+        assert!(config.is_allowed(&partner, "debug_traceTransaction"));
+        assert!(config.is_allowed(&partner, "ots_getApiLevel"));
+        assert!(!config.is_allowed(&partner, "txpool_content"));
+        assert!(!config.is_allowed(&partner, "net_version"));
+    }
+
+    #[test]
+    fn test_auth_config_filter_result() {
+        // Given
+        let config: AuthConfig = CONFIG.parse().expect("Failed to parse auth config");
+        let partner = ClientId::ApiKey("partner".to_string());
+        let modules = serde_json::json!({ "eth": "1.0", "net": "1.0", "debug": "1.0", "trace": "1.0" });
+        let document = openrpc_document(&KakarotRpcModule::ALL);
+
+        // When
+        let anonymous_modules = config.filter_result(&ClientId::Anonymous, "rpc_modules", modules.clone());
+        let partner_modules = config.filter_result(&partner, "rpc_modules", modules.clone());
+        let partner_document = config.filter_result(&partner, "rpc.discover", document);
+        let other = config.filter_result(&partner, "eth_chainId", modules.clone());
+
+        // Then
+        assert_eq!(anonymous_modules, serde_json::json!({ "eth": "1.0", "net": "1.0" }));
+        assert_eq!(partner_modules, serde_json::json!({ "eth": "1.0", "debug": "1.0" }));
+        assert_eq!(
+            partner_document,
+            openrpc_document(&[KakarotRpcModule::Eth, KakarotRpcModule::Debug, KakarotRpcModule::Otterscan])
+        );
+        assert_eq!(other, modules);
+    }
+
+    #[test]
+    fn test_auth_config_rate_limits() {
+        // Given
+        let config: AuthConfig = CONFIG.parse().expect("Failed to parse auth config");
+
+        // When
+        let rate_limits = config.rate_limits(None);
+
+        // Then
+        assert_eq!(rate_limits.len(), 1);
+        assert_eq!(rate_limits["partner"], RateLimitConfig::with_rate(None, 10., 10.));
+    }
+
+    #[test]
+    fn test_auth_config_duplicate_key() {
+        // Given
+        let config = "[[api_keys]]\nkey = \"key\"\nmodules = []\n[[api_keys]]\nkey = \"key\"\nmodules = []\n";
+
+        // When
+        let result = config.parse::<AuthConfig>();
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_client_id_from_request() {
+        // Given
+        let config: AuthConfig = CONFIG.parse().expect("Failed to parse auth config");
+        let proxy: IpAddr = [10, 0, 0, 1].into();
+        let request = |path: &str, peer: IpAddr, headers: &[(&str, &str)]| {
+            let mut request = HttpRequest::builder().uri(path).extension(PeerAddr(SocketAddr::new(peer, 443)));
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            request.body(()).unwrap()
+        };
+
+        // When & Then
+        let direct = request("/", [1, 1, 1, 1].into(), &[]);
+        assert_eq!(ClientId::from_request(&direct, Some(&config), &[proxy]), ClientId::Ip([1, 1, 1, 1].into()));
+        let header = request("/", [1, 1, 1, 1].into(), &[(API_KEY_HEADER, "partner")]);
+        assert_eq!(ClientId::from_request(&header, Some(&config), &[]), ClientId::ApiKey("partner".to_string()));
+        let path = request("/partner", [1, 1, 1, 1].into(), &[]);
+        assert_eq!(ClientId::from_request(&path, Some(&config), &[]), ClientId::ApiKey("partner".to_string()));
+        // Unknown API keys, or API keys without authentication, don't identify the client
+        let unknown = request("/unknown", [1, 1, 1, 1].into(), &[(API_KEY_HEADER, "unknown")]);
+        assert_eq!(ClientId::from_request(&unknown, Some(&config), &[]), ClientId::Ip([1, 1, 1, 1].into()));
+        assert_eq!(ClientId::from_request(&header, None, &[]), ClientId::Ip([1, 1, 1, 1].into()));
+        // Requests without a peer address can't be identified
+        let no_peer = HttpRequest::builder().uri("/").body(()).unwrap();
+        assert_eq!(ClientId::from_request(&no_peer, Some(&config), &[]), ClientId::Anonymous);
+    }
+
+    #[test]
+    fn test_client_id_from_forwarded_request() {
+        // Given
+        let proxy: IpAddr = [10, 0, 0, 1].into();
+        let other_proxy: IpAddr = [10, 0, 0, 2].into();
+        let trusted_proxies = [proxy, other_proxy];
+        let request = |peer: IpAddr, forwarded_for: &[&str]| {
+            let mut request = HttpRequest::builder().uri("/").extension(PeerAddr(SocketAddr::new(peer, 443)));
+            for value in forwarded_for {
+                request = request.header("x-forwarded-for", *value);
+            }
+            request.body(()).unwrap()
+        };
+        let client_ip = |request: &HttpRequest<()>| match ClientId::from_request(request, None, &trusted_proxies) {
+            ClientId::Ip(ip) => ip,
+            client => panic!("Expected an IP client, got {client:?}"),
+        };
+
+        // When & Then
+        // The header is ignored when the peer isn't a trusted proxy
+        assert_eq!(client_ip(&request([1, 1, 1, 1].into(), &["2.2.2.2"])), IpAddr::from([1, 1, 1, 1]));
+        // The client is the rightmost hop which isn't a trusted proxy, the hops on its left are spoofable
+        assert_eq!(client_ip(&request(proxy, &["3.3.3.3, 2.2.2.2"])), IpAddr::from([2, 2, 2, 2]));
+        assert_eq!(client_ip(&request(proxy, &["3.3.3.3, 2.2.2.2", "10.0.0.2"])), IpAddr::from([2, 2, 2, 2]));
+        // The last valid hop is used when the chain of proxies is broken or only contains proxies
+        assert_eq!(client_ip(&request(proxy, &["2.2.2.2, invalid, 10.0.0.2"])), other_proxy);
+        assert_eq!(client_ip(&request(proxy, &[])), proxy);
+    }
+}
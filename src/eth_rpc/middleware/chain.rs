@@ -0,0 +1,200 @@
+//! Middlewares routing the RPC calls to the chain of the `/chain/<name>` path of the request, so
+//! that a single server can serve several Kakarot deployments.
+//!
+//! The methods of the module of each additional chain are prefixed with the name of the chain,
+//! see [`prefix_methods`], and the module is merged with the module of the default chain. The
+//! [`ChainLayer`] strips the `/chain/<name>` prefix of the path of the HTTP requests, and the
+//! [`ChainRouterLayer`] prefixes the method of the RPC calls of these requests with the name of
+//! the chain. Calls to an unknown chain are rejected as calls to a method which doesn't exist.
+
+use crate::providers::eth_provider::error::EthRpcErrorCode;
+use futures::future::Either;
+use hyper::{
+    http::uri::{PathAndQuery, Uri},
+    Request as HttpRequest,
+};
+use jsonrpsee::{
+    server::{middleware::rpc::RpcServiceT, RegisterMethodError},
+    types::{ErrorObject, Request},
+    MethodResponse, RpcModule,
+};
+use std::{
+    future::{ready, Ready},
+    task::{Context, Poll},
+};
+
+/// The prefix of the path of the requests to an additional chain.
+pub const CHAIN_PATH_PREFIX: &str = "/chain/";
+
+/// The separator between the name of the chain and the name of the method.
+const CHAIN_METHOD_SEPARATOR: char = '/';
+
+/// The additional chain the request is sent to, added to the extensions of the request by the
+/// [`ChainLayer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chain(pub String);
+
+impl Chain {
+    /// Returns the name of the method of the chain, see [`prefix_methods`].
+    pub fn method_name(&self, method: &str) -> String {
+        format!("{}{CHAIN_METHOD_SEPARATOR}{method}", self.0)
+    }
+
+    /// Strips the `/chain/<name>` prefix of the URI, returning the chain and the URI of the
+    /// request to the chain. Returns `None` if the URI isn't the URI of a chain.
+    pub fn strip_prefix(uri: &Uri) -> Option<(Self, Uri)> {
+        let rest = uri.path().strip_prefix(CHAIN_PATH_PREFIX)?;
+        let (name, path) = rest.split_once('/').unwrap_or((rest, ""));
+        if name.is_empty() {
+            return None;
+        }
+
+        let path_and_query = match uri.query() {
+            Some(query) => format!("/{path}?{query}"),
+            None => format!("/{path}"),
+        };
+        let mut parts = uri.clone().into_parts();
+        parts.path_and_query = Some(PathAndQuery::try_from(path_and_query).ok()?);
+
+        Some((Self(name.to_string()), Uri::from_parts(parts).ok()?))
+    }
+}
+
+/// Prefixes the methods of the module of an additional chain with the name of the chain, so that
+/// it can be merged with the module of the default chain.
+///
+/// The names of the methods must be `'static`, and are leaked: this must only be called once
+/// per chain, when the server starts.
+pub fn prefix_methods(mut module: RpcModule<()>, chain: &str) -> Result<RpcModule<()>, RegisterMethodError> {
+    let chain = Chain(chain.to_string());
+    let methods: Vec<_> = module.method_names().collect();
+    for method in methods {
+        let name: &'static str = Box::leak(chain.method_name(method).into_boxed_str());
+        module.register_alias(name, method)?;
+        module.remove_method(method);
+    }
+    Ok(module)
+}
+
+/// HTTP layer routing the requests to the chain of their path, see [`Chain::strip_prefix`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChainLayer;
+
+impl<S> tower::Layer<S> for ChainLayer {
+    type Service = ChainService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ChainService { inner }
+    }
+}
+
+/// HTTP middleware stripping the `/chain/<name>` prefix of the path of the requests and adding
+/// the [`Chain`] to their extensions, from which it is propagated to the RPC calls.
+#[derive(Clone, Debug)]
+pub struct ChainService<S> {
+    inner: S,
+}
+
+impl<S, B> tower::Service<HttpRequest<B>> for ChainService<S>
+where
+    S: tower::Service<HttpRequest<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: HttpRequest<B>) -> Self::Future {
+        if let Some((chain, uri)) = Chain::strip_prefix(request.uri()) {
+            *request.uri_mut() = uri;
+            request.extensions_mut().insert(chain);
+        }
+        self.inner.call(request)
+    }
+}
+
+/// Chain router layer.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChainRouterLayer;
+
+impl<S> tower::Layer<S> for ChainRouterLayer {
+    type Service = ChainRouter<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        ChainRouter { service }
+    }
+}
+
+/// Chain router middleware, calling the method of the [`Chain`] of the call, if any.
+///
+/// The methods of the additional chains can't be called directly, so that the clients can't
+/// bypass the middlewares applied to the name of the method before the router.
+#[derive(Clone, Debug)]
+pub struct ChainRouter<S> {
+    service: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for ChainRouter<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = Either<S::Future, Ready<MethodResponse>>;
+
+    fn call(&self, mut req: Request<'a>) -> Self::Future {
+        if req.method_name().contains(CHAIN_METHOD_SEPARATOR) {
+            return Either::Right(ready(MethodResponse::error(
+                req.id(),
+                ErrorObject::owned(
+                    EthRpcErrorCode::MethodNotFound as i32,
+                    format!("the method {} does not exist/is not available", req.method_name()),
+                    None::<()>,
+                ),
+            )));
+        }
+
+        if let Some(chain) = req.extensions().get::<Chain>() {
+            req.method = chain.method_name(req.method_name()).into();
+        }
+        Either::Left(self.service.call(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_strip_prefix() {
+        // Given
+        let uri = |uri: &str| uri.parse::<Uri>().unwrap();
+
+        // When & Then
+        assert_eq!(Chain::strip_prefix(&uri("/chain/sepolia")), Some((Chain("sepolia".to_string()), uri("/"))));
+        assert_eq!(
+            Chain::strip_prefix(&uri("http://localhost:3030/chain/sepolia/partner?id=1")),
+            Some((Chain("sepolia".to_string()), uri("http://localhost:3030/partner?id=1")))
+        );
+        assert_eq!(Chain::strip_prefix(&uri("/chain/")), None);
+        assert_eq!(Chain::strip_prefix(&uri("/chains/sepolia")), None);
+        assert_eq!(Chain::strip_prefix(&uri("/partner")), None);
+    }
+
+    #[test]
+    fn test_prefix_methods() {
+        // Given
+        let mut module = RpcModule::new(());
+        module.register_method("eth_chainId", |_, _, _| "0x1").unwrap();
+        module.register_method("net_version", |_, _, _| "1").unwrap();
+
+        // When
+        let module = prefix_methods(module, "sepolia").expect("Failed to prefix methods");
+
+        // Then
+        let mut methods: Vec<_> = module.method_names().collect();
+        methods.sort_unstable();
+        assert_eq!(methods, ["sepolia/eth_chainId", "sepolia/net_version"]);
+    }
+}
@@ -18,7 +18,15 @@
 
 //! JSON-RPC specific middleware.
 
+/// API key authentication middleware.
+pub mod auth;
+/// Multi-chain routing middleware.
+pub mod chain;
 /// Grafana metrics middleware.
 pub mod metrics;
 /// Rate limit middleware.
+pub mod rate_limit;
+pub use auth::*;
+pub use chain::*;
 pub use metrics::*;
+pub use rate_limit::*;
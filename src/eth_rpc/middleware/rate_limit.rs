@@ -0,0 +1,296 @@
+//! RPC middleware to rate limit the RPC calls of each client.
+
+use super::auth::ClientId;
+use crate::{
+    config::ConfigSource,
+    prometheus_handler::{register, CounterVec, Opts, PrometheusError, Registry, U64},
+    providers::eth_provider::error::EthRpcErrorCode,
+};
+use eyre::eyre;
+use futures::future::Either;
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use std::{
+    collections::HashMap,
+    future::{ready, Ready},
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+/// The weight of the methods without a configured weight.
+const DEFAULT_METHOD_WEIGHT: u32 = 1;
+
+/// The weights of the expensive methods, used if `RPC_RATE_LIMIT_WEIGHTS` is not set.
+const DEFAULT_METHOD_WEIGHTS: [(&str, u32); 4] =
+    [("eth_getLogs", 10), ("debug_trace*", 20), ("trace_*", 20), ("ots_traceTransaction", 20)];
+
+/// Past this number of tracked clients, the clients with a full bucket are dropped.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Configuration of the token bucket rate limit of each client.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitConfig {
+    /// The number of tokens refilled per second.
+    pub rate: f64,
+    /// The maximum number of tokens of a client.
+    pub burst: f64,
+    /// The number of tokens consumed by the methods, matched by name or by prefix if the
+    /// pattern ends with `*`. Other methods consume one token.
+    pub method_weights: Vec<(String, u32)>,
+}
+
+impl RateLimitConfig {
+    /// `RPC_RATE_LIMIT` setting enables the rate limit, with the number of tokens refilled per
+    /// second. `RPC_RATE_LIMIT_BURST` sets the maximum number of tokens of a client, defaulting
+    /// to the rate.
+    ///
+    /// `RPC_RATE_LIMIT_WEIGHTS` setting is a comma separated list of method weights, e.g.
+    /// `eth_getLogs=10,debug_trace*=20`.
+    pub fn from_source(source: &ConfigSource) -> eyre::Result<Option<Self>> {
+        let Some(rate) = source.parse::<f64>("RPC_RATE_LIMIT")? else {
+            return Ok(None);
+        };
+        let burst = source.parse("RPC_RATE_LIMIT_BURST")?.unwrap_or(rate);
+        if !(rate.is_finite() && burst.is_finite() && rate > 0. && burst >= 1.) {
+            return Err(eyre!("invalid RPC rate limit: rate {rate}, burst {burst}"));
+        }
+
+        let method_weights = match source.get("RPC_RATE_LIMIT_WEIGHTS") {
+            Some(weights) => weights
+                .split(',')
+                .filter(|weight| !weight.trim().is_empty())
+                .map(|weight| {
+                    let (pattern, weight) =
+                        weight.split_once('=').ok_or_else(|| eyre!("invalid RPC method weight: {weight}"))?;
+                    Ok((pattern.trim().to_string(), weight.trim().parse()?))
+                })
+                .collect::<eyre::Result<_>>()?,
+            None => return Ok(Some(Self::with_rate(None, rate, burst))),
+        };
+
+        Ok(Some(Self { rate, burst, method_weights }))
+    }
+
+    /// Returns the given rate and burst with the method weights of the configuration, or the
+    /// default method weights if there is no configuration.
+    pub fn with_rate(config: Option<&Self>, rate: f64, burst: f64) -> Self {
+        let method_weights = config.map_or_else(
+            || DEFAULT_METHOD_WEIGHTS.iter().map(|(pattern, weight)| ((*pattern).to_string(), *weight)).collect(),
+            |config| config.method_weights.clone(),
+        );
+        Self { rate, burst, method_weights }
+    }
+
+    /// Returns the number of tokens consumed by a call to the method, capped by the burst.
+    pub fn weight(&self, method: &str) -> f64 {
+        let weight = self
+            .method_weights
+            .iter()
+            .find(|(pattern, _)| {
+                pattern.strip_suffix('*').map_or(pattern == method, |prefix| method.starts_with(prefix))
+            })
+            .map_or(DEFAULT_METHOD_WEIGHT, |(_, weight)| *weight);
+        f64::from(weight).min(self.burst)
+    }
+}
+
+/// The tokens left to a client.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    /// Refills the bucket for the time elapsed since its last update.
+    fn refill(&mut self, config: &RateLimitConfig, now: Instant) {
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens = elapsed.mul_add(config.rate, self.tokens).min(config.burst);
+        self.updated_at = now;
+    }
+}
+
+/// Tracks the token buckets of the clients.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    /// The rate limit of the clients without an API key, unlimited if `None`.
+    config: Option<RateLimitConfig>,
+    /// The rate limits of the API keys, which are unlimited if they don't have one.
+    api_keys: HashMap<String, RateLimitConfig>,
+    buckets: Mutex<HashMap<ClientId, TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`], with full buckets for all clients.
+    pub fn new(config: Option<RateLimitConfig>, api_keys: HashMap<String, RateLimitConfig>) -> Self {
+        Self { config, api_keys, buckets: Mutex::default() }
+    }
+
+    /// Returns the rate limit of the client, if any.
+    fn config(&self, client: &ClientId) -> Option<&RateLimitConfig> {
+        match client {
+            ClientId::ApiKey(api_key) => self.api_keys.get(api_key),
+            ClientId::Ip(_) | ClientId::Anonymous => self.config.as_ref(),
+        }
+    }
+
+    /// Consumes the tokens of a call to the method from the bucket of the client, returning
+    /// `false` if the client doesn't have enough tokens left.
+    pub fn try_acquire(&self, client: &ClientId, method: &str) -> bool {
+        let Some(config) = self.config(client) else {
+            return true;
+        };
+        let now = Instant::now();
+        let weight = config.weight(method);
+        let mut buckets = self.buckets.lock().expect("rate limiter lock poisoned");
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            buckets.retain(|client, bucket| {
+                self.config(client).is_some_and(|config| {
+                    bucket.refill(config, now);
+                    bucket.tokens < config.burst
+                })
+            });
+        }
+
+        let bucket =
+            buckets.entry(client.clone()).or_insert_with(|| TokenBucket { tokens: config.burst, updated_at: now });
+        bucket.refill(config, now);
+        if bucket.tokens < weight {
+            return false;
+        }
+        bucket.tokens -= weight;
+        true
+    }
+}
+
+/// Metrics of the rate limit middleware.
+#[derive(Debug, Clone)]
+pub struct RateLimitMetrics {
+    /// Number of calls rejected by the rate limit.
+    calls_rate_limited: CounterVec<U64>,
+}
+
+impl RateLimitMetrics {
+    /// Create an instance of metrics
+    pub fn new(metrics_registry: Option<&Registry>) -> Result<Option<Self>, PrometheusError> {
+        if let Some(metrics_registry) = metrics_registry {
+            Ok(Some(Self {
+                calls_rate_limited: register(
+                    CounterVec::new(
+                        Opts::new("eth_rpc_calls_rate_limited", "Number of RPC calls rejected by the rate limit"),
+                        &["method"],
+                    )?,
+                    metrics_registry,
+                )?,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Rate limit layer.
+#[derive(Clone, Debug)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+    metrics: Option<RateLimitMetrics>,
+}
+
+impl RateLimitLayer {
+    /// Create a new [`RateLimitLayer`].
+    pub fn new(limiter: RateLimiter, metrics: Option<RateLimitMetrics>) -> Self {
+        Self { limiter: Arc::new(limiter), metrics }
+    }
+}
+
+impl<S> tower::Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        RateLimit { service, limiter: self.limiter.clone(), metrics: self.metrics.clone() }
+    }
+}
+
+/// Rate limit middleware, rejecting the calls of the clients which exceeded their rate limit
+/// with a [`EthRpcErrorCode::RequestLimitExceeded`] error.
+#[derive(Clone, Debug)]
+pub struct RateLimit<S> {
+    service: S,
+    limiter: Arc<RateLimiter>,
+    metrics: Option<RateLimitMetrics>,
+}
+
+impl<'a, S> RpcServiceT<'a> for RateLimit<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = Either<S::Future, Ready<MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let client = req.extensions().get::<ClientId>().cloned().unwrap_or(ClientId::Anonymous);
+        if self.limiter.try_acquire(&client, req.method_name()) {
+            return Either::Left(self.service.call(req));
+        }
+
+        tracing::debug!(target: "rpc_rate_limit", ?client, method = req.method_name(), "rate limited");
+        if let Some(metrics) = &self.metrics {
+            metrics.calls_rate_limited.with_label_values(&[req.method_name()]).inc();
+        }
+
+        Either::Right(ready(MethodResponse::error(
+            req.id(),
+            ErrorObject::owned(EthRpcErrorCode::RequestLimitExceeded as i32, "request limit exceeded", None::<()>),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RateLimitConfig {
+        RateLimitConfig {
+            rate: 1.,
+            burst: 10.,
+            method_weights: vec![("eth_getLogs".to_string(), 5), ("debug_trace*".to_string(), 20)],
+        }
+    }
+
+    #[test]
+    fn test_method_weight() {
+        // Given
+        let config = config();
+
+        // When & Then
+        assert!((config.weight("eth_getLogs") - 5.).abs() < f64::EPSILON);
+        assert!((config.weight("eth_chainId") - 1.).abs() < f64::EPSILON);
+        // Weights are capped by the burst
+        assert!((config.weight("debug_traceTransaction") - 10.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rate_limiter_per_client() {
+        // Given
+        let limiter = RateLimiter::new(
+            Some(config()),
+            HashMap::from([("limited".to_string(), RateLimitConfig { burst: 5., ..config() })]),
+        );
+        let client = ClientId::Ip([127, 0, 0, 1].into());
+        let other = ClientId::Ip([127, 0, 0, 2].into());
+        let limited = ClientId::ApiKey("limited".to_string());
+        let unlimited = ClientId::ApiKey("unlimited".to_string());
+
+        // When & Then
+        assert!(limiter.try_acquire(&client, "eth_getLogs"));
+        assert!(limiter.try_acquire(&client, "eth_getLogs"));
+        assert!(!limiter.try_acquire(&client, "eth_chainId"));
+        assert!(limiter.try_acquire(&other, "eth_getLogs"));
+        assert!(limiter.try_acquire(&limited, "eth_getLogs"));
+        assert!(!limiter.try_acquire(&limited, "eth_getLogs"));
+        assert!((0..20).all(|_| limiter.try_acquire(&unlimited, "eth_getLogs")));
+    }
+}
@@ -3,12 +3,17 @@
 
 pub mod api;
 pub mod config;
+pub mod filters;
 pub mod middleware;
+pub mod openrpc;
 pub mod rpc;
 pub mod servers;
 
 use crate::{
-    eth_rpc::middleware::{metrics::RpcMetrics, MetricsLayer},
+    eth_rpc::middleware::{
+        metrics::RpcMetrics, AuthorizationLayer, ChainLayer, ChainRouterLayer, ClientIdLayer, MetricsLayer,
+        PeerAddrService, RateLimitLayer, RateLimitMetrics, RateLimiter,
+    },
     prometheus_handler::init_prometheus,
 };
 use config::RPCConfig;
@@ -16,13 +21,17 @@ use eyre::Result;
 use jsonrpsee::{
     server::{
         middleware::http::{InvalidPath, ProxyGetRequestLayer},
-        RpcServiceBuilder, ServerBuilder, ServerHandle,
+        serve_with_graceful_shutdown, stop_channel, RpcServiceBuilder, ServerBuilder, ServerHandle,
     },
-    RpcModule,
+    Methods, RpcModule,
 };
 use prometheus::Registry;
-use std::net::{AddrParseError, Ipv4Addr, SocketAddr};
+use std::{
+    net::{AddrParseError, Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
 use thiserror::Error;
+use tokio::net::TcpListener;
 use tower_http::cors::{Any, CorsLayer};
 
 #[derive(Error, Debug)]
@@ -39,31 +48,44 @@ pub enum RpcError {
     PrometheusError(#[from] prometheus::Error),
 }
 
+/// Runs the RPC server, serving the metrics of the `registry` on the Prometheus port.
+///
+/// The requests with a `/chain/<name>` path are routed to the methods of the chain `<name>`,
+/// which must be merged in the module with [`prefix_methods`](middleware::prefix_methods).
+///
 /// # Errors
 ///
-/// Will return `Err` if an error occurs when running the `ServerBuilder` start fails.
+/// Will return `Err` if the server fails to listen on the socket address.
 pub async fn run_server(
     kakarot_rpc_module: RpcModule<()>,
     rpc_config: RPCConfig,
+    registry: Registry,
 ) -> Result<(SocketAddr, ServerHandle), RpcError> {
-    let RPCConfig { socket_addr } = rpc_config;
+    let RPCConfig { socket_addr, max_connections, prometheus_port, rate_limit, auth, trusted_proxies, .. } = rpc_config;
+    let auth = auth.map(Arc::new);
 
     let cors = CorsLayer::new().allow_methods(Any).allow_origin(Any).allow_headers(Any);
 
-    let http_middleware =
-        tower::ServiceBuilder::new().layer(ProxyGetRequestLayer::new("/health", "net_health")?).layer(cors);
+    let http_middleware = tower::ServiceBuilder::new()
+        .layer(ChainLayer)
+        .layer(ProxyGetRequestLayer::new("/health", "net_health")?)
+        .layer(cors)
+        .layer(ClientIdLayer::new(auth.clone(), trusted_proxies.into()));
 
-    // Creating the prometheus registry to register the metrics
-    let registry = Registry::new();
     // register the metrics
     let metrics = RpcMetrics::new(Some(&registry))?.map(|m| MetricsLayer::new(m, "http"));
+    let api_key_rate_limits = auth.as_ref().map(|auth| auth.rate_limits(rate_limit.as_ref())).unwrap_or_default();
+    let rate_limit = if rate_limit.is_some() || !api_key_rate_limits.is_empty() {
+        let limiter = RateLimiter::new(rate_limit, api_key_rate_limits);
+        Some(RateLimitLayer::new(limiter, RateLimitMetrics::new(Some(&registry))?))
+    } else {
+        None
+    };
+    let authorization = auth.map(AuthorizationLayer::new);
     tokio::spawn(async move {
         // serve the prometheus metrics on the given port so that it can be read
         let _ = init_prometheus(
-            SocketAddr::new(
-                std::net::IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
-                get_env_or_default("PROMETHEUS_PORT", "9615").parse().unwrap(),
-            ),
+            SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), prometheus_port),
             registry,
         )
         .await;
@@ -71,21 +93,48 @@ pub async fn run_server(
     // add the metrics as a middleware to the RPC so that every new RPC call fires prometheus metrics
     // upon start, finish etc. we don't need to manually handle each method, it should automatically
     // work for any new method.
-    let rpc_middleware = RpcServiceBuilder::new().option_layer(metrics);
+    // The authorization and rate limit are applied after the metrics, so that the rejected calls
+    // are also measured. Unauthorized calls don't consume the tokens of the client.
+    // The calls are routed to their chain last, so that the other middlewares see the name of
+    // the method called by the client.
+    let rpc_middleware = RpcServiceBuilder::new()
+        .option_layer(metrics)
+        .option_layer(authorization)
+        .option_layer(rate_limit)
+        .layer(ChainRouterLayer);
 
-    let server = ServerBuilder::default()
-        .max_connections(get_env_or_default("RPC_MAX_CONNECTIONS", "100").parse().unwrap())
+    let service_builder = ServerBuilder::default()
+        .max_connections(max_connections)
         .set_http_middleware(http_middleware)
         .set_rpc_middleware(rpc_middleware)
-        .build(socket_addr.parse::<SocketAddr>()?)
-        .await?;
+        .to_service_builder();
+    let methods: Methods = kakarot_rpc_module.into();
 
-    let addr = server.local_addr()?;
-    let handle = server.start(kakarot_rpc_module);
+    // Accept the connections ourselves, so that the address of the peer is known to the
+    // middlewares identifying the clients.
+    let listener = TcpListener::bind(socket_addr.parse::<SocketAddr>()?).await?;
+    let addr = listener.local_addr()?;
+    let (stop_handle, handle) = stop_channel();
+    tokio::spawn(async move {
+        loop {
+            let (stream, peer) = tokio::select! {
+                res = listener.accept() => match res {
+                    Ok(connection) => connection,
+                    Err(err) => {
+                        tracing::error!(?err, "failed to accept connection");
+                        continue;
+                    }
+                },
+                () = stop_handle.clone().shutdown() => break,
+            };
+            let service = service_builder.clone().build(methods.clone(), stop_handle.clone());
+            tokio::spawn(serve_with_graceful_shutdown(
+                stream,
+                PeerAddrService::new(service, peer),
+                stop_handle.clone().shutdown(),
+            ));
+        }
+    });
 
     Ok((addr, handle))
 }
-
-fn get_env_or_default(name: &str, default: &str) -> String {
-    std::env::var(name).unwrap_or_else(|_| default.to_string())
-}
@@ -0,0 +1,229 @@
+//! Generation of the [OpenRPC](https://spec.open-rpc.org) document served by `rpc.discover`.
+//!
+//! The methods of the `#[rpc]` traits of the [`api`](super::api) module are described by the
+//! `openrpc_methods` function declared along with each trait, from the Rust types of their
+//! parameters and result, whose JSON schemas are generated with [`schemars`] and shared in the
+//! components of the document.
+
+pub mod schema;
+mod types;
+
+use crate::eth_rpc::{
+    api::{
+        alchemy_api, debug_api, eth_api, eth_pubsub_api, kakarot_api, net_api, otterscan_api, rpc_api, trace_api,
+        txpool_api, web3_api,
+    },
+    rpc::KakarotRpcModule,
+};
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::Schema,
+};
+use serde_json::{json, Value};
+
+/// The version of the `OpenRPC` specification of the document.
+const OPENRPC_VERSION: &str = "1.2.6";
+
+/// The path of the schemas shared by the methods of the document.
+const SCHEMAS_PATH: &str = "#/components/schemas/";
+
+/// Returns the schema of a type, adding the definitions it references to the generator.
+pub type SchemaFn = fn(&mut SchemaGenerator) -> Schema;
+
+/// A method of an RPC API trait.
+#[derive(Debug, Clone)]
+pub struct RpcMethod {
+    /// The name of the method, without the namespace of its module.
+    pub name: &'static str,
+    /// The documentation of the method.
+    pub docs: Vec<&'static str>,
+    /// The parameters of the method.
+    pub params: Vec<RpcParam>,
+    /// The schema of the result of the method.
+    pub result: SchemaFn,
+}
+
+/// A parameter of a method of an RPC API trait.
+#[derive(Debug, Clone)]
+pub struct RpcParam {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// Whether the parameter is required, i.e. isn't an `Option`.
+    pub required: bool,
+    /// The schema of the parameter.
+    pub schema: SchemaFn,
+}
+
+impl RpcMethod {
+    /// Returns the `OpenRPC` method object of the method, prefixing its name with the
+    /// namespace if any.
+    fn to_openrpc(&self, namespace: Option<&str>, generator: &mut SchemaGenerator) -> Value {
+        let name = namespace.map_or_else(|| self.name.to_string(), |namespace| format!("{namespace}_{}", self.name));
+        let params: Vec<_> = self
+            .params
+            .iter()
+            .map(|param| {
+                let schema = (param.schema)(generator);
+                json!({ "name": param.name, "required": param.required, "schema": schema })
+            })
+            .collect();
+        let result = (self.result)(generator);
+
+        let mut method = json!({
+            "name": name,
+            "params": params,
+            "result": { "name": "result", "schema": result },
+        });
+        if let Some(summary) = self.docs.first() {
+            method["summary"] = (*summary).into();
+        }
+        if self.docs.len() > 1 {
+            method["description"] = self.docs.join("\n").into();
+        }
+        method
+    }
+}
+
+/// Returns the methods of the RPC API traits, with the module serving them. The methods
+/// without module are always served.
+fn rpc_methods() -> Vec<(Option<KakarotRpcModule>, RpcMethod)> {
+    [
+        (Some(KakarotRpcModule::Eth), eth_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Eth), eth_pubsub_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Alchemy), alchemy_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Web3), web3_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Net), net_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Debug), debug_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Trace), trace_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Txpool), txpool_api::openrpc_methods()),
+        (Some(KakarotRpcModule::KakarotRpc), kakarot_api::openrpc_methods()),
+        (Some(KakarotRpcModule::Otterscan), otterscan_api::openrpc_methods()),
+        (None, rpc_api::openrpc_methods()),
+    ]
+    .into_iter()
+    .flat_map(|(module, methods)| methods.into_iter().map(move |method| (module, method)))
+    .collect()
+}
+
+/// Returns the `OpenRPC` document of the methods of the given modules.
+pub fn openrpc_document(modules: &[KakarotRpcModule]) -> Value {
+    let mut generator = SchemaSettings::draft07()
+        .with(|settings| settings.definitions_path = SCHEMAS_PATH.to_string())
+        .into_generator();
+
+    let methods: Vec<_> = rpc_methods()
+        .into_iter()
+        .filter(|(module, _)| module.map_or(true, |module| modules.contains(&module)))
+        .map(|(module, method)| method.to_openrpc(module.map(|module| module.namespace()), &mut generator))
+        .collect();
+
+    json!({
+        "openrpc": OPENRPC_VERSION,
+        "info": { "title": "Kakarot RPC", "version": env!("CARGO_PKG_VERSION") },
+        "methods": methods,
+        "components": { "schemas": generator.take_definitions() },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the `$ref` of the schema and of its subschemas.
+    fn references(schema: &Value) -> Vec<&str> {
+        match schema {
+            Value::Object(object) => object
+                .iter()
+                .flat_map(|(key, value)| match (key.as_str(), value) {
+                    ("$ref", Value::String(reference)) => vec![reference.as_str()],
+                    _ => references(value),
+                })
+                .collect(),
+            Value::Array(array) => array.iter().flat_map(references).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_openrpc_document() {
+        // When
+        let document = openrpc_document(&[KakarotRpcModule::Net]);
+
+        // Then
+        let names: Vec<_> =
+            document["methods"].as_array().unwrap().iter().map(|method| method["name"].as_str().unwrap()).collect();
+        assert_eq!(
+            names,
+            ["net_version", "net_peerCount", "net_listening", "net_health", "rpc_modules", "rpc.discover"]
+        );
+        assert_eq!(
+            document["methods"][2]["summary"],
+            "Returns true if client is actively listening for network connections."
+        );
+        assert_eq!(document["methods"][2]["result"]["schema"], json!({ "type": "boolean" }));
+    }
+
+    #[test]
+    fn test_openrpc_document_params() {
+        // When
+        let document = openrpc_document(&[KakarotRpcModule::Eth]);
+
+        // Then
+        let method = document["methods"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|method| method["name"] == "eth_getBalance")
+            .expect("Missing method");
+        assert_eq!(method["params"][0]["name"], "address");
+        assert_eq!(method["params"][0]["required"], true);
+        assert_eq!(method["params"][0]["schema"], json!({ "$ref": "#/components/schemas/Address" }));
+        assert_eq!(method["params"][1]["name"], "block_number");
+        assert_eq!(method["params"][1]["required"], false);
+        assert_eq!(method["result"]["schema"], json!({ "$ref": "#/components/schemas/Quantity" }));
+
+        let schemas = &document["components"]["schemas"];
+        assert_eq!(schemas["Address"]["type"], "string");
+        assert_eq!(schemas["Address"]["pattern"], "^0x[0-9a-fA-F]{40}$");
+        assert_eq!(schemas["Block"]["type"], "object");
+        assert!(schemas["Block"]["required"].as_array().unwrap().contains(&json!("parentHash")));
+        assert_eq!(schemas["Block"]["properties"]["miner"], json!({ "$ref": "#/components/schemas/Address" }));
+    }
+
+    #[test]
+    fn test_openrpc_document_from_api_traits() {
+        // When
+        let document = openrpc_document(&[KakarotRpcModule::Eth]);
+        let method =
+            |name: &str| document["methods"].as_array().unwrap().iter().find(|method| method["name"] == name).cloned();
+
+        // Then
+        // The methods with extensions only describe the parameters of the client
+        let send_raw_transaction = method("eth_sendRawTransaction").expect("Missing method");
+        assert_eq!(send_raw_transaction["params"].as_array().unwrap().len(), 1);
+        assert_eq!(send_raw_transaction["params"][0]["name"], "bytes");
+        // The subscriptions are described by their subscribe and unsubscribe methods
+        let subscribe = method("eth_subscribe").expect("Missing method");
+        assert_eq!(subscribe["params"][0]["name"], "kind");
+        assert_eq!(subscribe["params"][1]["required"], false);
+        assert_eq!(subscribe["result"]["schema"], json!({ "type": "string" }));
+        let unsubscribe = method("eth_unsubscribe").expect("Missing method");
+        assert_eq!(unsubscribe["params"][0]["name"], "subscription_id");
+        assert!(method("eth_subscription").is_none());
+    }
+
+    #[test]
+    fn test_openrpc_document_references() {
+        // When
+        let document = openrpc_document(&KakarotRpcModule::ALL);
+
+        // Then
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+        let references = references(&document);
+        assert!(!references.is_empty());
+        for reference in references {
+            let name = reference.strip_prefix(SCHEMAS_PATH).expect("Reference outside of the components");
+            assert!(schemas.contains_key(name), "Missing schema {name}");
+        }
+    }
+}
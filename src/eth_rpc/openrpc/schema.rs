@@ -0,0 +1,274 @@
+//! The JSON schemas of the parameters and results of the RPC API.
+//!
+//! The schemas are generated with [`schemars`]: the types of the crate derive [`JsonSchema`]
+//! directly, while the foreign types use the schema of their mirror in the
+//! [`types`](super::types) module.
+
+use super::types;
+use crate::{
+    models::token::{TokenBalances, TokenMetadata},
+    providers::eth_provider::{
+        constant::Constant,
+        database::types::transaction::{ExtendedTransaction, RelayedTransactionStatus},
+    },
+};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{Bytes, B256, B64, U256, U64};
+use alloy_rpc_types::{
+    pubsub::{Params, SubscriptionKind},
+    serde_helpers::JsonStorageKey,
+    state::StateOverride,
+    AccessListResult, Block, BlockOverrides, EIP1186AccountProofResponse, FeeHistory, Filter, FilterChanges, Index,
+    SyncStatus, Transaction, TransactionReceipt, TransactionRequest, Work,
+};
+use alloy_rpc_types_trace::{
+    filter::TraceFilter,
+    geth::{GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, TraceResult},
+    otterscan::{
+        BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, TraceEntry, TransactionsWithReceipts,
+    },
+    parity::{LocalizedTransactionTrace, TraceResults, TraceResultsWithTransactionHash, TraceType},
+};
+use alloy_rpc_types_txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolStatus};
+use alloy_serde::WithOtherFields;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{
+        ArrayValidation, InstanceType, Metadata, ObjectValidation, Schema, SchemaObject, SingleOrVec, StringValidation,
+        SubschemaValidation,
+    },
+    JsonSchema,
+};
+use std::collections::{BTreeMap, HashSet};
+
+/// A type of the parameters or results of the RPC API.
+pub trait RpcSchema {
+    /// Whether a parameter of the type is required.
+    const REQUIRED: bool = true;
+
+    /// Returns the schema of the type, adding the definitions it references to the generator.
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema;
+}
+
+/// The return type of a method of the RPC API, whose result is the success type.
+pub trait RpcMethodResult {
+    /// Returns the schema of the result of the method.
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema;
+}
+
+impl<T: RpcSchema, E> RpcMethodResult for Result<T, E> {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::rpc_schema(generator)
+    }
+}
+
+/// Declares the hexadecimal string types, with the pattern of their values.
+macro_rules! hex_schemas {
+    ($($(#[doc = $doc:literal])* $name:ident = $pattern:literal;)*) => {$(
+        $(#[doc = $doc])*
+        #[derive(Debug)]
+        pub struct $name;
+
+        impl JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                SchemaObject {
+                    metadata: Some(Box::new(Metadata {
+                        description: Some([$($doc.trim()),*].join(" ")),
+                        ..Default::default()
+                    })),
+                    instance_type: Some(InstanceType::String.into()),
+                    string: Some(Box::new(StringValidation {
+                        pattern: Some($pattern.to_string()),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    )*};
+}
+
+hex_schemas! {
+    /// A hex encoded unsigned integer, without leading zeros.
+    Quantity = "^0x(0|[1-9a-f][0-9a-f]*)$";
+    /// Hex encoded bytes.
+    Data = "^0x([0-9a-fA-F]{2})*$";
+    /// Hex encoded 8 bytes.
+    Bytes8 = "^0x[0-9a-fA-F]{16}$";
+    /// Hex encoded 32 bytes.
+    Bytes32 = "^0x[0-9a-fA-F]{64}$";
+    /// A hex encoded 20 bytes Ethereum address.
+    Address = "^0x[0-9a-fA-F]{40}$";
+    /// A hex encoded 256 bytes logs bloom.
+    Bloom = "^0x[0-9a-fA-F]{512}$";
+    /// A hex encoded storage slot, up to 32 bytes.
+    StorageKey = "^0x[0-9a-fA-F]{1,64}$";
+    /// A hex encoded Starknet field element.
+    Felt = "^0x[0-9a-fA-F]{1,64}$";
+}
+
+/// Implements [`RpcSchema`] for types implementing [`JsonSchema`].
+macro_rules! json_schemas {
+    ($($ty:ty),* $(,)?) => {$(
+        impl RpcSchema for $ty {
+            fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+                generator.subschema_for::<Self>()
+            }
+        }
+    )*};
+}
+
+/// Implements [`RpcSchema`] for foreign types, with the [`JsonSchema`] of their mirror.
+macro_rules! mirror_schemas {
+    ($($ty:ty => $mirror:ty),* $(,)?) => {$(
+        impl RpcSchema for $ty {
+            fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+                generator.subschema_for::<$mirror>()
+            }
+        }
+    )*};
+}
+
+json_schemas!(
+    bool,
+    u64,
+    usize,
+    f64,
+    String,
+    serde_json::Value,
+    TokenBalances,
+    TokenMetadata,
+    Constant,
+    RelayedTransactionStatus,
+);
+
+mirror_schemas! {
+    alloy_primitives::Address => Address,
+    Bytes => Data,
+    B64 => Bytes8,
+    B256 => Bytes32,
+    U64 => Quantity,
+    U256 => Quantity,
+    Index => Quantity,
+    JsonStorageKey => StorageKey,
+    BlockNumberOrTag => types::BlockNumberOrTag,
+    BlockId => types::BlockId,
+    Block<WithOtherFields<Transaction>> => types::Block,
+    Transaction => types::Transaction,
+    TransactionReceipt => types::TransactionReceipt,
+    TransactionRequest => types::TransactionRequest,
+    Filter => types::Filter,
+    FilterChanges => types::FilterChanges,
+    StateOverride => types::StateOverride,
+    BlockOverrides => types::BlockOverrides,
+    AccessListResult => types::AccessListResult,
+    FeeHistory => types::FeeHistory,
+    SyncStatus => types::SyncStatus,
+    Work => types::Work,
+    EIP1186AccountProofResponse => types::EIP1186AccountProofResponse,
+    SubscriptionKind => types::SubscriptionKind,
+    Params => types::Params,
+    GethDebugTracingOptions => types::GethDebugTracingOptions,
+    GethDebugTracingCallOptions => types::GethDebugTracingCallOptions,
+    GethTrace => types::GethTrace,
+    TraceResult => types::TraceResult,
+    LocalizedTransactionTrace => types::LocalizedTransactionTrace,
+    TraceResults => types::TraceResults,
+    TraceResultsWithTransactionHash => types::TraceResultsWithTransactionHash,
+    TraceType => types::TraceType,
+    TraceFilter => types::TraceFilter,
+    InternalOperation => types::InternalOperation,
+    TraceEntry => types::TraceEntry,
+    BlockDetails => types::BlockDetails,
+    OtsBlockTransactions => types::OtsBlockTransactions,
+    TransactionsWithReceipts => types::TransactionsWithReceipts,
+    ContractCreator => types::ContractCreator,
+    TxpoolStatus => types::TxpoolStatus,
+    TxpoolInspect => types::TxpoolInspect,
+    TxpoolContentFrom<ExtendedTransaction> => types::TxpoolContentFrom,
+    TxpoolContent<ExtendedTransaction> => types::TxpoolContent,
+}
+
+impl<T: RpcSchema> RpcSchema for Option<T> {
+    const REQUIRED: bool = false;
+
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        let any_of = vec![T::rpc_schema(generator), <()>::json_schema(generator)];
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation { any_of: Some(any_of), ..Default::default() })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+impl<T: RpcSchema> RpcSchema for Box<T> {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::rpc_schema(generator)
+    }
+}
+
+impl<T: RpcSchema> RpcSchema for WithOtherFields<T> {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::rpc_schema(generator)
+    }
+}
+
+impl<T: RpcSchema> RpcSchema for Vec<T> {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema(vec![T::rpc_schema(generator)], false)
+    }
+}
+
+impl<T: RpcSchema, S> RpcSchema for HashSet<T, S> {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema(vec![T::rpc_schema(generator)], true)
+    }
+}
+
+impl<A: RpcSchema, B: RpcSchema> RpcSchema for (A, B) {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        array_schema(vec![A::rpc_schema(generator), B::rpc_schema(generator)], false)
+    }
+}
+
+impl<V: RpcSchema> RpcSchema for BTreeMap<String, V> {
+    fn rpc_schema(generator: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(ObjectValidation {
+                additional_properties: Some(Box::new(V::rpc_schema(generator))),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Returns the schema of an array of the items, or of a tuple of the items if there are several.
+fn array_schema(mut items: Vec<Schema>, unique_items: bool) -> Schema {
+    let (items, len): (SingleOrVec<Schema>, _) = if items.len() == 1 {
+        (items.remove(0).into(), None)
+    } else {
+        let len = items.len() as u32;
+        (items.into(), Some(len))
+    };
+    SchemaObject {
+        instance_type: Some(InstanceType::Array.into()),
+        array: Some(Box::new(ArrayValidation {
+            items: Some(items),
+            min_items: len,
+            max_items: len,
+            unique_items: unique_items.then_some(true),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
@@ -0,0 +1,781 @@
+//! The mirrors of the foreign types of the RPC API.
+//!
+//! [`JsonSchema`] can't be derived for the foreign types, so each of them is mirrored by a type
+//! with the same serialization, which is only used to derive the schema and never constructed.
+#![allow(dead_code, clippy::struct_field_names, clippy::zero_sized_map_values)]
+
+use super::schema::{Address, Bloom, Bytes32, Bytes8, Data, Quantity, StorageKey};
+use schemars::JsonSchema;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A block number or tag.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum BlockNumberOrTag {
+    Number(Quantity),
+    Tag(BlockTag),
+}
+
+/// A block tag.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "lowercase")]
+pub(super) enum BlockTag {
+    Latest,
+    Finalized,
+    Safe,
+    Earliest,
+    Pending,
+}
+
+/// A block number, tag or hash, or an EIP-1898 block object.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum BlockId {
+    Hash(Bytes32),
+    Number(BlockNumberOrTag),
+    HashObject(BlockHashObject),
+    NumberObject(BlockNumberObject),
+}
+
+/// An EIP-1898 block object identifying the block by hash.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct BlockHashObject {
+    block_hash: Bytes32,
+    require_canonical: Option<bool>,
+}
+
+/// An EIP-1898 block object identifying the block by number or tag.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct BlockNumberObject {
+    block_number: BlockNumberOrTag,
+}
+
+/// A single value or an array of values.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum ValueOrArray<T> {
+    Value(T),
+    Array(Vec<T>),
+}
+
+/// A block.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct Block {
+    hash: Bytes32,
+    parent_hash: Bytes32,
+    #[schemars(rename = "sha3Uncles")]
+    uncles_hash: Bytes32,
+    miner: Address,
+    state_root: Bytes32,
+    transactions_root: Bytes32,
+    receipts_root: Bytes32,
+    logs_bloom: Bloom,
+    difficulty: Quantity,
+    number: Quantity,
+    gas_limit: Quantity,
+    gas_used: Quantity,
+    timestamp: Quantity,
+    total_difficulty: Option<Quantity>,
+    extra_data: Data,
+    mix_hash: Option<Bytes32>,
+    nonce: Option<Bytes8>,
+    base_fee_per_gas: Option<Quantity>,
+    withdrawals_root: Option<Bytes32>,
+    blob_gas_used: Option<Quantity>,
+    excess_blob_gas: Option<Quantity>,
+    parent_beacon_block_root: Option<Bytes32>,
+    requests_root: Option<Bytes32>,
+    size: Option<Quantity>,
+    uncles: Vec<Bytes32>,
+    transactions: BlockTransactions,
+    withdrawals: Option<Vec<Withdrawal>>,
+}
+
+/// The transactions of a block, as hashes or full transactions.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum BlockTransactions {
+    Hashes(Vec<Bytes32>),
+    Full(Vec<Transaction>),
+}
+
+/// An EIP-4895 withdrawal.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct Withdrawal {
+    index: Quantity,
+    validator_index: Quantity,
+    address: Address,
+    amount: Quantity,
+}
+
+/// A transaction.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct Transaction {
+    hash: Bytes32,
+    nonce: Quantity,
+    block_hash: Option<Bytes32>,
+    block_number: Option<Quantity>,
+    transaction_index: Option<Quantity>,
+    from: Address,
+    to: Option<Address>,
+    value: Quantity,
+    gas_price: Option<Quantity>,
+    gas: Quantity,
+    max_fee_per_gas: Option<Quantity>,
+    max_priority_fee_per_gas: Option<Quantity>,
+    max_fee_per_blob_gas: Option<Quantity>,
+    input: Data,
+    r: Option<Quantity>,
+    s: Option<Quantity>,
+    v: Option<Quantity>,
+    y_parity: Option<Quantity>,
+    chain_id: Option<Quantity>,
+    blob_versioned_hashes: Option<Vec<Bytes32>>,
+    access_list: Option<Vec<AccessListItem>>,
+    #[schemars(rename = "type")]
+    transaction_type: Option<Quantity>,
+    authorization_list: Option<Vec<SignedAuthorization>>,
+}
+
+/// An item of an EIP-2930 access list.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct AccessListItem {
+    address: Address,
+    storage_keys: Vec<Bytes32>,
+}
+
+/// A signed EIP-7702 authorization.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct SignedAuthorization {
+    chain_id: Quantity,
+    address: Address,
+    nonce: Quantity,
+    y_parity: Quantity,
+    r: Quantity,
+    s: Quantity,
+}
+
+/// The receipt of a transaction.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TransactionReceipt {
+    transaction_hash: Bytes32,
+    transaction_index: Option<Quantity>,
+    block_hash: Option<Bytes32>,
+    block_number: Option<Quantity>,
+    from: Address,
+    to: Option<Address>,
+    contract_address: Option<Address>,
+    gas_used: Quantity,
+    cumulative_gas_used: Quantity,
+    effective_gas_price: Quantity,
+    blob_gas_used: Option<Quantity>,
+    blob_gas_price: Option<Quantity>,
+    logs: Vec<Log>,
+    logs_bloom: Bloom,
+    /// `0x1` if the transaction succeeded, `0x0` otherwise.
+    status: Option<Quantity>,
+    /// The post-transaction state root of the pre-Byzantium receipts.
+    root: Option<Bytes32>,
+    #[schemars(rename = "type")]
+    transaction_type: Quantity,
+}
+
+/// A log emitted by a transaction.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct Log {
+    address: Address,
+    topics: Vec<Bytes32>,
+    data: Data,
+    block_hash: Option<Bytes32>,
+    block_number: Option<Quantity>,
+    block_timestamp: Option<Quantity>,
+    transaction_hash: Option<Bytes32>,
+    transaction_index: Option<Quantity>,
+    log_index: Option<Quantity>,
+    removed: bool,
+}
+
+/// A filter of logs.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct Filter {
+    from_block: Option<BlockNumberOrTag>,
+    to_block: Option<BlockNumberOrTag>,
+    /// The hash of the single block to filter, exclusive with `fromBlock` and `toBlock`.
+    block_hash: Option<Bytes32>,
+    address: Option<ValueOrArray<Address>>,
+    /// The topics to match at each position, `null` matching any topic.
+    topics: Option<Vec<Option<ValueOrArray<Bytes32>>>>,
+}
+
+/// The changes of a filter: logs for the log filters, hashes for the block and pending
+/// transaction filters.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum FilterChanges {
+    Logs(Vec<Log>),
+    Hashes(Vec<Bytes32>),
+    Transactions(Vec<Transaction>),
+}
+
+/// A transaction request.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TransactionRequest {
+    from: Option<Address>,
+    to: Option<Address>,
+    gas_price: Option<Quantity>,
+    max_fee_per_gas: Option<Quantity>,
+    max_priority_fee_per_gas: Option<Quantity>,
+    max_fee_per_blob_gas: Option<Quantity>,
+    gas: Option<Quantity>,
+    value: Option<Quantity>,
+    input: Option<Data>,
+    /// The input of the transaction, if `input` isn't set.
+    data: Option<Data>,
+    nonce: Option<Quantity>,
+    chain_id: Option<Quantity>,
+    access_list: Option<Vec<AccessListItem>>,
+    #[schemars(rename = "type")]
+    transaction_type: Option<Quantity>,
+    blob_versioned_hashes: Option<Vec<Bytes32>>,
+    authorization_list: Option<Vec<SignedAuthorization>>,
+}
+
+/// The overrides of the state of the accounts, by address.
+#[derive(JsonSchema)]
+pub(super) struct StateOverride(HashMap<String, AccountOverride>);
+
+/// The overrides of the state of an account.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct AccountOverride {
+    balance: Option<Quantity>,
+    nonce: Option<Quantity>,
+    code: Option<Data>,
+    /// Replaces the whole storage of the account.
+    state: Option<HashMap<String, Bytes32>>,
+    /// Replaces the given slots of the storage of the account.
+    state_diff: Option<HashMap<String, Bytes32>>,
+    move_precompile_to_address: Option<Address>,
+}
+
+/// The overrides of the block environment.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct BlockOverrides {
+    number: Option<Quantity>,
+    difficulty: Option<Quantity>,
+    time: Option<Quantity>,
+    gas_limit: Option<Quantity>,
+    coinbase: Option<Address>,
+    random: Option<Bytes32>,
+    base_fee: Option<Quantity>,
+    /// The hashes of the blocks, by number.
+    block_hash: Option<HashMap<String, Bytes32>>,
+}
+
+/// The result of `eth_createAccessList`.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct AccessListResult {
+    access_list: Vec<AccessListItem>,
+    gas_used: Quantity,
+    error: Option<String>,
+}
+
+/// The fee history of a range of blocks.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct FeeHistory {
+    base_fee_per_gas: Vec<Quantity>,
+    gas_used_ratio: Vec<f64>,
+    base_fee_per_blob_gas: Vec<Quantity>,
+    blob_gas_used_ratio: Vec<f64>,
+    oldest_block: Quantity,
+    reward: Option<Vec<Vec<Quantity>>>,
+}
+
+/// The sync status of the node: the sync progress, or `false` if the node isn't syncing.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum SyncStatus {
+    Info(SyncInfo),
+    None(bool),
+}
+
+/// The sync progress of the node.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct SyncInfo {
+    starting_block: Quantity,
+    current_block: Quantity,
+    highest_block: Quantity,
+}
+
+/// The hash of the current block, the seed hash and the boundary condition to be met.
+#[derive(JsonSchema)]
+pub(super) struct Work(Bytes32, Bytes32, Bytes32);
+
+/// The account and storage values of an account with their EIP-1186 Merkle proofs.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct EIP1186AccountProofResponse {
+    address: Address,
+    balance: Quantity,
+    code_hash: Bytes32,
+    nonce: Quantity,
+    storage_hash: Bytes32,
+    account_proof: Vec<Data>,
+    storage_proof: Vec<StorageProof>,
+}
+
+/// The value of a storage slot with its Merkle proof.
+#[derive(JsonSchema)]
+pub(super) struct StorageProof {
+    key: StorageKey,
+    value: Quantity,
+    proof: Vec<Data>,
+}
+
+/// The kind of a subscription.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) enum SubscriptionKind {
+    NewHeads,
+    Logs,
+    NewPendingTransactions,
+    Syncing,
+}
+
+/// The parameters of a subscription: the filter of a `logs` subscription, or whether the
+/// `newPendingTransactions` subscription returns full transactions.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum Params {
+    Logs(Box<Filter>),
+    Bool(bool),
+}
+
+/// The options of a Geth debug trace.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct GethDebugTracingOptions {
+    #[schemars(flatten)]
+    config: GethDefaultTracingOptions,
+    /// The name of a built-in tracer, e.g. `callTracer`, or the code of a JavaScript tracer.
+    tracer: Option<String>,
+    tracer_config: Option<Value>,
+    timeout: Option<String>,
+}
+
+/// The options of the default Geth struct logger.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct GethDefaultTracingOptions {
+    enable_memory: Option<bool>,
+    disable_memory: Option<bool>,
+    disable_stack: Option<bool>,
+    disable_storage: Option<bool>,
+    enable_return_data: Option<bool>,
+    disable_return_data: Option<bool>,
+    debug: Option<bool>,
+    limit: Option<u64>,
+}
+
+/// The options of a Geth debug trace of a call.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct GethDebugTracingCallOptions {
+    #[schemars(flatten)]
+    tracing_options: GethDebugTracingOptions,
+    state_overrides: Option<StateOverride>,
+    block_overrides: Option<BlockOverrides>,
+}
+
+/// A Geth debug trace, in the format of the tracer.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum GethTrace {
+    Default(DefaultFrame),
+    CallTracer(CallFrame),
+    /// The traces of the other tracers.
+    Other(Value),
+}
+
+/// The trace of the default Geth struct logger.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct DefaultFrame {
+    failed: bool,
+    gas: u64,
+    return_value: Data,
+    struct_logs: Vec<StructLog>,
+}
+
+/// A step of the execution traced by the default Geth struct logger.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct StructLog {
+    pc: u64,
+    op: String,
+    gas: u64,
+    gas_cost: u64,
+    depth: u64,
+    error: Option<String>,
+    stack: Option<Vec<Quantity>>,
+    return_data: Option<Data>,
+    memory: Option<Vec<String>>,
+    memory_size: Option<u64>,
+    storage: Option<HashMap<String, Bytes32>>,
+    refund: Option<u64>,
+}
+
+/// A call traced by the Geth call tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct CallFrame {
+    from: Address,
+    gas: Quantity,
+    gas_used: Quantity,
+    to: Option<Address>,
+    input: Data,
+    output: Option<Data>,
+    error: Option<String>,
+    revert_reason: Option<String>,
+    calls: Option<Vec<CallFrame>>,
+    logs: Option<Vec<CallLogFrame>>,
+    value: Option<Quantity>,
+    /// The type of the call, e.g. `CALL` or `CREATE`.
+    #[schemars(rename = "type")]
+    call_type: String,
+}
+
+/// A log emitted by a call traced by the Geth call tracer.
+#[derive(JsonSchema)]
+pub(super) struct CallLogFrame {
+    address: Option<Address>,
+    topics: Option<Vec<Bytes32>>,
+    data: Option<Data>,
+    position: Option<Quantity>,
+}
+
+/// The Geth debug trace of a transaction of a block.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum TraceResult {
+    Success {
+        result: GethTrace,
+        #[schemars(rename = "txHash")]
+        tx_hash: Option<Bytes32>,
+    },
+    Error {
+        error: String,
+        #[schemars(rename = "txHash")]
+        tx_hash: Option<Bytes32>,
+    },
+}
+
+/// A parity trace of a transaction, with its location.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct LocalizedTransactionTrace {
+    #[schemars(flatten)]
+    trace: TransactionTrace,
+    block_hash: Option<Bytes32>,
+    block_number: Option<u64>,
+    transaction_hash: Option<Bytes32>,
+    transaction_position: Option<u64>,
+}
+
+/// A parity trace of a transaction.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TransactionTrace {
+    action: Action,
+    error: Option<String>,
+    result: Option<TraceOutput>,
+    subtraces: usize,
+    trace_address: Vec<usize>,
+    #[schemars(rename = "type")]
+    action_type: ActionType,
+}
+
+/// The type of the action of a parity trace.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "lowercase")]
+pub(super) enum ActionType {
+    Call,
+    Create,
+    Suicide,
+    Reward,
+}
+
+/// The action of a parity trace.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum Action {
+    Call(CallAction),
+    Create(CreateAction),
+    Selfdestruct(SelfdestructAction),
+    Reward(RewardAction),
+}
+
+/// A call traced by the parity tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct CallAction {
+    from: Address,
+    call_type: CallType,
+    gas: Quantity,
+    input: Data,
+    to: Address,
+    value: Quantity,
+}
+
+/// The type of a call traced by the parity tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "lowercase")]
+pub(super) enum CallType {
+    None,
+    Call,
+    CallCode,
+    DelegateCall,
+    StaticCall,
+}
+
+/// A contract creation traced by the parity tracer.
+#[derive(JsonSchema)]
+pub(super) struct CreateAction {
+    from: Address,
+    gas: Quantity,
+    init: Data,
+    value: Quantity,
+}
+
+/// A self-destruct traced by the parity tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct SelfdestructAction {
+    address: Address,
+    balance: Quantity,
+    refund_address: Address,
+}
+
+/// A reward traced by the parity tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct RewardAction {
+    author: Address,
+    /// `block` or `uncle`.
+    reward_type: String,
+    value: Quantity,
+}
+
+/// The output of an action of a parity trace.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+pub(super) enum TraceOutput {
+    Call(CallOutput),
+    Create(CreateOutput),
+}
+
+/// The output of a call traced by the parity tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct CallOutput {
+    gas_used: Quantity,
+    output: Data,
+}
+
+/// The output of a contract creation traced by the parity tracer.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct CreateOutput {
+    address: Address,
+    code: Data,
+    gas_used: Quantity,
+}
+
+/// The parity traces of the selected trace types.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TraceResults {
+    output: Data,
+    state_diff: Option<HashMap<String, AccountDiff>>,
+    trace: Vec<TransactionTrace>,
+    vm_trace: Option<Value>,
+}
+
+/// The changes of an account, each as `=` if unchanged, or an object with a `+` (born), `-`
+/// (died) or `*` (changed) key.
+#[derive(JsonSchema)]
+pub(super) struct AccountDiff {
+    balance: Value,
+    code: Value,
+    nonce: Value,
+    storage: HashMap<String, Value>,
+}
+
+/// The parity traces of the selected trace types of a transaction.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TraceResultsWithTransactionHash {
+    #[schemars(flatten)]
+    full_trace: TraceResults,
+    transaction_hash: Bytes32,
+}
+
+/// A type of parity trace.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) enum TraceType {
+    Trace,
+    VmTrace,
+    StateDiff,
+}
+
+/// A filter of parity traces.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TraceFilter {
+    from_block: Option<Quantity>,
+    to_block: Option<Quantity>,
+    from_address: Option<Vec<Address>>,
+    to_address: Option<Vec<Address>>,
+    mode: Option<TraceFilterMode>,
+    /// The number of traces to skip.
+    after: Option<u64>,
+    /// The maximum number of traces to return.
+    count: Option<u64>,
+}
+
+/// How the address filters of a parity traces filter are combined.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "lowercase")]
+pub(super) enum TraceFilterMode {
+    Union,
+    Intersection,
+}
+
+/// A value transfer or contract creation inside a transaction.
+#[derive(JsonSchema)]
+pub(super) struct InternalOperation {
+    /// `0` for a transfer, `1` for a self-destruct, `2` for a `CREATE` and `3` for a `CREATE2`.
+    #[schemars(rename = "type")]
+    operation_type: u8,
+    from: Address,
+    to: Address,
+    value: Quantity,
+}
+
+/// A call of the call tree of a transaction.
+#[derive(JsonSchema)]
+pub(super) struct TraceEntry {
+    /// The type of the call, e.g. `CALL` or `CREATE`.
+    #[schemars(rename = "type")]
+    call_type: String,
+    depth: u32,
+    from: Address,
+    to: Address,
+    value: Option<Quantity>,
+    input: Data,
+    output: Data,
+}
+
+/// A block without its transactions, with the number of transactions.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct OtsBlock {
+    #[schemars(flatten)]
+    block: Block,
+    transaction_count: usize,
+}
+
+/// The details of a block.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct BlockDetails {
+    block: OtsBlock,
+    issuance: InternalIssuance,
+    total_fees: Quantity,
+}
+
+/// The issuance of a block.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct InternalIssuance {
+    block_reward: Quantity,
+    uncle_reward: Quantity,
+    issuance: Quantity,
+}
+
+/// A page of the transactions of a block with their receipts.
+#[derive(JsonSchema)]
+pub(super) struct OtsBlockTransactions {
+    fullblock: OtsBlock,
+    receipts: Vec<OtsTransactionReceipt>,
+}
+
+/// The receipt of a transaction, with the timestamp of its block.
+#[derive(JsonSchema)]
+pub(super) struct OtsTransactionReceipt {
+    #[schemars(flatten)]
+    receipt: TransactionReceipt,
+    timestamp: Option<Quantity>,
+}
+
+/// A page of the transactions of an address with their receipts.
+#[derive(JsonSchema)]
+#[schemars(rename_all = "camelCase")]
+pub(super) struct TransactionsWithReceipts {
+    txs: Vec<Transaction>,
+    receipts: Vec<OtsTransactionReceipt>,
+    first_page: bool,
+    last_page: bool,
+}
+
+/// The creator of a contract and the hash of the creation transaction.
+#[derive(JsonSchema)]
+pub(super) struct ContractCreator {
+    hash: Bytes32,
+    creator: Address,
+}
+
+/// The number of pending and queued transactions of the pool.
+#[derive(JsonSchema)]
+pub(super) struct TxpoolStatus {
+    pending: Quantity,
+    queued: Quantity,
+}
+
+/// The summaries of the pending and queued transactions of the pool, by sender and nonce.
+#[derive(JsonSchema)]
+pub(super) struct TxpoolInspect {
+    pending: HashMap<String, HashMap<String, String>>,
+    queued: HashMap<String, HashMap<String, String>>,
+}
+
+/// The pending and queued transactions of a sender, by nonce.
+#[derive(JsonSchema)]
+pub(super) struct TxpoolContentFrom {
+    pending: HashMap<String, Transaction>,
+    queued: HashMap<String, Transaction>,
+}
+
+/// The pending and queued transactions of the pool, by sender and nonce.
+#[derive(JsonSchema)]
+pub(super) struct TxpoolContent {
+    pending: HashMap<String, HashMap<String, Transaction>>,
+    queued: HashMap<String, HashMap<String, Transaction>>,
+}
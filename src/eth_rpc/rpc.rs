@@ -3,24 +3,34 @@ use crate::{
     eth_rpc::{
         api::{
             alchemy_api::AlchemyApiServer, debug_api::DebugApiServer, eth_api::EthApiServer,
-            kakarot_api::KakarotApiServer, net_api::NetApiServer, trace_api::TraceApiServer,
+            eth_pubsub_api::EthPubSubApiServer, kakarot_api::KakarotApiServer, net_api::NetApiServer,
+            otterscan_api::OtterscanApiServer, rpc_api::RpcApiServer, trace_api::TraceApiServer,
             txpool_api::TxPoolApiServer, web3_api::Web3ApiServer,
         },
         servers::{
-            alchemy_rpc::AlchemyRpc, debug_rpc::DebugRpc, eth_rpc::EthRpc, kakarot_rpc::KakarotRpc, net_rpc::NetRpc,
+            alchemy_rpc::AlchemyRpc, debug_rpc::DebugRpc, eth_pubsub_rpc::EthPubSubRpc, eth_rpc::EthRpc,
+            kakarot_rpc::KakarotRpc, net_rpc::NetRpc, otterscan_rpc::OtterscanRpc, rpc_rpc::RpcRpc,
             trace_rpc::TraceRpc, txpool_rpc::TxpoolRpc, web3_rpc::Web3Rpc,
         },
     },
     providers::{
-        alchemy_provider::AlchemyDataProvider, debug_provider::DebugDataProvider, pool_provider::PoolDataProvider,
+        alchemy_provider::AlchemyDataProvider, debug_provider::DebugDataProvider,
+        otterscan_provider::OtterscanDataProvider, pool_provider::PoolDataProvider,
     },
 };
 use jsonrpsee::{server::RegisterMethodError, Methods, RpcModule};
+use serde::Deserialize;
 use starknet::providers::Provider;
-use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+    str::FromStr,
+    sync::Arc,
+};
 
 /// Represents RPC modules that are supported by reth
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum KakarotRpcModule {
     Eth,
     Alchemy,
@@ -29,12 +39,60 @@ pub enum KakarotRpcModule {
     Debug,
     Trace,
     Txpool,
+    #[serde(rename = "kakarot")]
     KakarotRpc,
+    #[serde(rename = "ots")]
+    Otterscan,
+}
+
+impl KakarotRpcModule {
+    /// All the RPC modules.
+    pub const ALL: [Self; 9] = [
+        Self::Eth,
+        Self::Alchemy,
+        Self::Web3,
+        Self::Net,
+        Self::Debug,
+        Self::Trace,
+        Self::Txpool,
+        Self::KakarotRpc,
+        Self::Otterscan,
+    ];
+
+    /// Returns the namespace of the methods of the module.
+    pub const fn namespace(&self) -> &'static str {
+        match self {
+            Self::Eth => "eth",
+            Self::Alchemy => "alchemy",
+            Self::Web3 => "web3",
+            Self::Net => "net",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+            Self::Txpool => "txpool",
+            Self::KakarotRpc => "kakarot",
+            Self::Otterscan => "ots",
+        }
+    }
+
+    /// Returns the module of the method, based on the namespace prefixing its name.
+    pub fn from_method(method: &str) -> Option<Self> {
+        let (namespace, _) = method.split_once('_')?;
+        namespace.parse().ok()
+    }
+}
+
+impl FromStr for KakarotRpcModule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|module| module.namespace() == s).ok_or_else(|| format!("unknown RPC module: {s}"))
+    }
 }
 
 #[derive(Debug)]
 pub struct KakarotRpcModuleBuilder<SP> {
     modules: HashMap<KakarotRpcModule, Methods>,
+    enabled: Vec<KakarotRpcModule>,
     _phantom: PhantomData<SP>,
 }
 
@@ -48,15 +106,21 @@ where
         let alchemy_provider = Arc::new(AlchemyDataProvider::new(eth_provider.clone()));
         let pool_provider = Arc::new(PoolDataProvider::new(eth_client.clone()));
         let debug_provider = Arc::new(DebugDataProvider::new(eth_provider.clone()));
+        let otterscan_provider = Arc::new(OtterscanDataProvider::new(eth_provider.clone()));
 
-        let eth_rpc_module = EthRpc::new(eth_client).into_rpc();
+        let mut eth_rpc_module = EthRpc::new(eth_client.clone()).into_rpc();
+        // The pub-sub methods are part of the eth namespace and can't conflict with the other eth methods.
+        eth_rpc_module
+            .merge(EthPubSubRpc::new(eth_client).into_rpc())
+            .expect("eth_subscribe conflicts with an existing eth method");
         let alchemy_rpc_module = AlchemyRpc::new(alchemy_provider).into_rpc();
         let web3_rpc_module = Web3Rpc::default().into_rpc();
         let net_rpc_module = NetRpc::new(eth_provider.clone()).into_rpc();
         let debug_rpc_module = DebugRpc::new(debug_provider).into_rpc();
-        let trace_rpc_module = TraceRpc::new(eth_provider).into_rpc();
-        let kakarot_rpc_module = KakarotRpc.into_rpc();
+        let trace_rpc_module = TraceRpc::new(eth_provider.clone()).into_rpc();
+        let kakarot_rpc_module = KakarotRpc::new(eth_provider).into_rpc();
         let txpool_rpc_module = TxpoolRpc::new(pool_provider).into_rpc();
+        let otterscan_rpc_module = OtterscanRpc::new(otterscan_provider).into_rpc();
 
         let mut modules = HashMap::new();
 
@@ -68,16 +132,28 @@ where
         modules.insert(KakarotRpcModule::Trace, trace_rpc_module.into());
         modules.insert(KakarotRpcModule::Txpool, txpool_rpc_module.into());
         modules.insert(KakarotRpcModule::KakarotRpc, kakarot_rpc_module.into());
+        modules.insert(KakarotRpcModule::Otterscan, otterscan_rpc_module.into());
+
+        Self { modules, enabled: KakarotRpcModule::ALL.to_vec(), _phantom: PhantomData }
+    }
 
-        Self { modules, _phantom: PhantomData }
+    /// Only serves the methods of the given modules. All the modules are enabled by default.
+    #[must_use]
+    pub fn with_enabled_modules(mut self, modules: impl IntoIterator<Item = KakarotRpcModule>) -> Self {
+        let modules: HashSet<_> = modules.into_iter().collect();
+        self.enabled = KakarotRpcModule::ALL.into_iter().filter(|module| modules.contains(module)).collect();
+        self
     }
 
+    /// Returns the [`RpcModule`] of the enabled modules, along with the `rpc_modules` and
+    /// `rpc.discover` methods describing them.
     pub fn rpc_module(&self) -> Result<RpcModule<()>, RegisterMethodError> {
         let mut rpc_module = RpcModule::new(());
 
-        for methods in self.modules.values().cloned() {
-            rpc_module.merge(methods)?;
+        for module in &self.enabled {
+            rpc_module.merge(self.modules[module].clone())?;
         }
+        rpc_module.merge(RpcRpc::new(&self.enabled).into_rpc())?;
 
         Ok(rpc_module)
     }
@@ -0,0 +1,245 @@
+use crate::{
+    client::EthClient,
+    eth_rpc::api::eth_pubsub_api::EthPubSubApiServer,
+    providers::eth_provider::{
+        database::ethereum::EthereumBlockStore,
+        error::{EthApiError, EthRpcErrorCode},
+        BlockProvider, LogProvider,
+    },
+};
+use alloy_rpc_types::{
+    pubsub::{Params, SubscriptionKind, SubscriptionResult},
+    Filter, FilterChanges, Header,
+};
+use jsonrpsee::{
+    core::{async_trait, SubscriptionResult as PubSubResult},
+    types::ErrorObject,
+    PendingSubscriptionSink, SubscriptionMessage, SubscriptionSink,
+};
+use reth_rpc::eth::EthTxBuilder;
+use reth_rpc_eth_types::TransactionSource;
+use reth_transaction_pool::{TransactionListenerKind, TransactionPool};
+use starknet::providers::Provider;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// Interval at which the headers collection is polled for new blocks.
+const NEW_HEADS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Capacity of the channel broadcasting new headers to the subscriptions.
+const NEW_HEADS_CHANNEL_CAPACITY: usize = 256;
+
+/// The RPC module for the Ethereum pub-sub API.
+#[derive(Debug)]
+pub struct EthPubSubRpc<SP>
+where
+    SP: Provider + Send + Sync,
+{
+    eth_client: Arc<EthClient<SP>>,
+    /// Sender of the headers tail, started on the first subscription and stopped once all the
+    /// subscriptions are closed.
+    new_heads: Mutex<Option<broadcast::Sender<Header>>>,
+}
+
+impl<SP> EthPubSubRpc<SP>
+where
+    SP: Provider + Clone + Send + Sync + 'static,
+{
+    pub const fn new(eth_client: Arc<EthClient<SP>>) -> Self {
+        Self { eth_client, new_heads: Mutex::new(None) }
+    }
+
+    /// Returns a receiver for the new headers, starting the headers tail if needed.
+    fn new_heads(&self) -> broadcast::Receiver<Header> {
+        let mut new_heads = self.new_heads.lock().expect("new heads lock poisoned");
+        // The tail stops once it has no receivers left, in which case a new one is started.
+        if let Some(sender) = new_heads.as_ref().filter(|sender| sender.receiver_count() > 0) {
+            return sender.subscribe();
+        }
+
+        let (sender, receiver) = broadcast::channel(NEW_HEADS_CHANNEL_CAPACITY);
+        tokio::spawn(tail_headers(self.eth_client.clone(), sender.clone()));
+        *new_heads = Some(sender);
+        receiver
+    }
+
+    /// Sends the new headers to the sink until the subscription is closed.
+    async fn pipe_new_heads(&self, sink: SubscriptionSink) -> PubSubResult {
+        let mut new_heads = self.new_heads();
+        loop {
+            let header = tokio::select! {
+                () = sink.closed() => return Ok(()),
+                header = new_heads.recv() => header,
+            };
+            match header {
+                Ok(header) => {
+                    if !send(&sink, &SubscriptionResult::Header(Box::new(header))).await? {
+                        return Ok(());
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => tracing::warn!(skipped, "newHeads subscription lagged"),
+                Err(RecvError::Closed) => return Ok(()),
+            }
+        }
+    }
+
+    /// Sends the logs matching the filter for each new block until the subscription is closed.
+    async fn pipe_logs(&self, sink: SubscriptionSink, filter: Filter) -> PubSubResult {
+        let mut new_heads = self.new_heads();
+        // The last block for which the logs were sent.
+        let mut last_block: Option<u64> = None;
+        loop {
+            let header = tokio::select! {
+                () = sink.closed() => return Ok(()),
+                header = new_heads.recv() => header,
+            };
+            let header = match header {
+                Ok(header) => header,
+                // The logs of the skipped blocks are queried along with the next received block.
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "logs subscription lagged");
+                    continue;
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            };
+
+            let from = last_block.map_or(header.number, |last_block| last_block + 1);
+            last_block = Some(header.number);
+            let filter = filter.clone().from_block(from).to_block(header.number);
+            let logs = match self.eth_client.eth_provider().get_logs(filter).await {
+                Ok(FilterChanges::Logs(logs)) => logs,
+                Ok(_) => continue,
+                // The logs of the blocks are skipped rather than retried, as the error could persist.
+                Err(err) => {
+                    tracing::warn!(?err, from, to = header.number, "failed to fetch the logs of the new blocks");
+                    continue;
+                }
+            };
+            for log in logs {
+                if !send(&sink, &SubscriptionResult::Log(Box::new(log))).await? {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Sends the pending transactions (hashes or full transactions) until the subscription is closed.
+    async fn pipe_pending_transactions(&self, sink: SubscriptionSink, full: bool) -> PubSubResult {
+        let pool = self.eth_client.mempool();
+        let mut pending = pool.pending_transactions_listener_for(TransactionListenerKind::All);
+        loop {
+            let hash = tokio::select! {
+                () = sink.closed() => return Ok(()),
+                hash = pending.recv() => hash,
+            };
+            let Some(hash) = hash else { return Ok(()) };
+
+            let item = if full {
+                // The transaction might have been picked up by a relayer in the meantime.
+                let Some(transaction) = pool.get(&hash) else { continue };
+                SubscriptionResult::FullTransaction(Box::new(
+                    TransactionSource::Pool(transaction.transaction.transaction().clone())
+                        .into_transaction(&EthTxBuilder {}),
+                ))
+            } else {
+                SubscriptionResult::TransactionHash(hash)
+            };
+
+            if !send(&sink, &item).await? {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<SP> EthPubSubApiServer for EthPubSubRpc<SP>
+where
+    SP: Provider + Clone + Send + Sync + 'static,
+{
+    #[tracing::instrument(skip(self, pending))]
+    async fn subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        kind: SubscriptionKind,
+        params: Option<Params>,
+    ) -> PubSubResult {
+        match (kind, params) {
+            (SubscriptionKind::NewHeads, None) => self.pipe_new_heads(pending.accept().await?).await,
+            (SubscriptionKind::Logs, None) => self.pipe_logs(pending.accept().await?, Filter::default()).await,
+            (SubscriptionKind::Logs, Some(Params::Logs(filter))) => {
+                self.pipe_logs(pending.accept().await?, *filter).await
+            }
+            (SubscriptionKind::NewPendingTransactions, None) => {
+                self.pipe_pending_transactions(pending.accept().await?, false).await
+            }
+            (SubscriptionKind::NewPendingTransactions, Some(Params::Bool(full))) => {
+                self.pipe_pending_transactions(pending.accept().await?, full).await
+            }
+            (SubscriptionKind::Syncing, _) => {
+                pending.reject(EthApiError::Unsupported("eth_subscribe syncing")).await;
+                Ok(())
+            }
+            (kind, _) => {
+                let message = format!("invalid params for {kind:?} subscription");
+                pending.reject(ErrorObject::owned(EthRpcErrorCode::InvalidParams as i32, message, None::<()>)).await;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Sends an item to the subscription sink, returning false if the sink is disconnected.
+async fn send(sink: &SubscriptionSink, item: &SubscriptionResult) -> Result<bool, serde_json::Error> {
+    let message = SubscriptionMessage::from_json(item)?;
+    Ok(sink.send(message).await.is_ok())
+}
+
+/// Tails the headers collection, broadcasting each new block header, until all the receivers
+/// are dropped.
+async fn tail_headers<SP>(eth_client: Arc<EthClient<SP>>, sender: broadcast::Sender<Header>)
+where
+    SP: Provider + Clone + Send + Sync + 'static,
+{
+    let mut last_block = None;
+    let mut interval = tokio::time::interval(NEW_HEADS_POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+        if sender.receiver_count() == 0 {
+            return;
+        }
+
+        let current_block = match eth_client.eth_provider().block_number().await {
+            Ok(block_number) => block_number.to::<u64>(),
+            Err(err) => {
+                tracing::warn!(?err, "failed to fetch the latest block number");
+                continue;
+            }
+        };
+
+        // Only the blocks produced after the start of the tail are broadcast.
+        let from = match last_block {
+            Some(last_block) if current_block <= last_block => continue,
+            Some(last_block) => last_block + 1,
+            None => {
+                last_block = Some(current_block);
+                continue;
+            }
+        };
+
+        match eth_client.eth_provider().database().headers(from, current_block).await {
+            Ok(headers) => {
+                for header in headers {
+                    // An error only means that all the subscriptions were closed in the meantime.
+                    let _ = sender.send(header);
+                }
+                last_block = Some(current_block);
+            }
+            Err(err) => tracing::warn!(?err, from, to = current_block, "failed to fetch the new headers"),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     client::{EthClient, TransactionHashProvider},
-    eth_rpc::api::eth_api::EthApiServer,
+    eth_rpc::{api::eth_api::EthApiServer, filters::EthFilters, middleware::ClientId},
     providers::eth_provider::{
         constant::MAX_PRIORITY_FEE_PER_GAS,
         database::types::{header::ExtendedBlock, receipt::ExtendedTxReceipt, transaction::ExtendedTransaction},
@@ -14,7 +14,10 @@ use alloy_rpc_types::{
     serde_helpers::JsonStorageKey, state::StateOverride, AccessListResult, BlockOverrides, EIP1186AccountProofResponse,
     FeeHistory, Filter, FilterChanges, Index, SyncStatus, TransactionRequest, Work,
 };
-use jsonrpsee::core::{async_trait, RpcResult};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    Extensions,
+};
 use serde_json::Value;
 use starknet::providers::Provider;
 use std::sync::Arc;
@@ -27,14 +30,16 @@ where
     SP: Provider + Send + Sync,
 {
     eth_client: Arc<EthClient<SP>>,
+    filters: EthFilters<SP>,
 }
 
 impl<SP> EthRpc<SP>
 where
-    SP: Provider + Send + Sync,
+    SP: Provider + Clone + Send + Sync + 'static,
 {
-    pub const fn new(eth_client: Arc<EthClient<SP>>) -> Self {
-        Self { eth_client }
+    pub fn new(eth_client: Arc<EthClient<SP>>) -> Self {
+        let filters = EthFilters::new(eth_client.clone());
+        Self { eth_client, filters }
     }
 }
 
@@ -176,12 +181,13 @@ where
         Ok(self.eth_client.eth_provider().call(request, block_id, state_overrides, block_overrides).await?)
     }
 
+    #[tracing::instrument(skip(self, request), err)]
     async fn create_access_list(
         &self,
-        _request: TransactionRequest,
-        _block_id: Option<BlockId>,
+        request: TransactionRequest,
+        block_id: Option<BlockId>,
     ) -> RpcResult<AccessListResult> {
-        Err(EthApiError::Unsupported("eth_createAccessList").into())
+        Ok(self.eth_client.eth_provider().create_access_list(request, block_id).await?)
     }
 
     #[tracing::instrument(skip(self, request), err)]
@@ -242,8 +248,9 @@ where
     }
 
     #[tracing::instrument(skip_all, ret, err(level = Level::WARN))]
-    async fn send_raw_transaction(&self, bytes: Bytes) -> RpcResult<B256> {
-        tracing::info!("Serving eth_sendRawTransaction");
+    async fn send_raw_transaction(&self, ext: &Extensions, bytes: Bytes) -> RpcResult<B256> {
+        let client = ext.get::<ClientId>().cloned().unwrap_or(ClientId::Anonymous);
+        tracing::info!(?client, "Serving eth_sendRawTransaction");
 
         #[cfg(feature = "forwarding")]
         {
@@ -263,7 +270,7 @@ where
         #[cfg(not(feature = "forwarding"))]
         {
             use crate::client::KakarotTransactions;
-            Ok(self.eth_client.send_raw_transaction(bytes).await?)
+            Ok(self.eth_client.send_raw_transaction_from(bytes, client).await?)
         }
     }
 
@@ -288,28 +295,34 @@ where
         Err(EthApiError::Unsupported("eth_getProof").into())
     }
 
-    async fn new_filter(&self, _filter: Filter) -> RpcResult<U64> {
-        Err(EthApiError::Unsupported("eth_newFilter").into())
+    #[tracing::instrument(skip(self), ret, err)]
+    async fn new_filter(&self, filter: Filter) -> RpcResult<U64> {
+        Ok(self.filters.new_filter(filter).await?)
     }
 
+    #[tracing::instrument(skip(self), ret, err)]
     async fn new_block_filter(&self) -> RpcResult<U64> {
-        Err(EthApiError::Unsupported("eth_newBlockFilter").into())
+        Ok(self.filters.new_block_filter().await?)
     }
 
+    #[tracing::instrument(skip(self), ret, err)]
     async fn new_pending_transaction_filter(&self) -> RpcResult<U64> {
-        Err(EthApiError::Unsupported("eth_newPendingTransactionFilter").into())
+        Ok(self.filters.new_pending_transaction_filter().await)
     }
 
-    async fn uninstall_filter(&self, _id: U64) -> RpcResult<bool> {
-        Err(EthApiError::Unsupported("eth_uninstallFilter").into())
+    #[tracing::instrument(skip(self), ret, err)]
+    async fn uninstall_filter(&self, id: U64) -> RpcResult<bool> {
+        Ok(self.filters.uninstall_filter(id).await)
     }
 
-    async fn get_filter_changes(&self, _id: U64) -> RpcResult<FilterChanges> {
-        Err(EthApiError::Unsupported("eth_getFilterChanges").into())
+    #[tracing::instrument(skip(self), err)]
+    async fn get_filter_changes(&self, id: U64) -> RpcResult<FilterChanges> {
+        Ok(self.filters.filter_changes(id).await?)
     }
 
-    async fn get_filter_logs(&self, _id: U64) -> RpcResult<FilterChanges> {
-        Err(EthApiError::Unsupported("eth_getFilterLogs").into())
+    #[tracing::instrument(skip(self), err)]
+    async fn get_filter_logs(&self, id: U64) -> RpcResult<FilterChanges> {
+        Ok(self.filters.filter_logs(id).await?)
     }
 
     async fn block_receipts(&self, block_id: Option<BlockId>) -> RpcResult<Option<Vec<ExtendedTxReceipt>>> {
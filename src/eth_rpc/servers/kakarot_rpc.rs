@@ -1,26 +1,46 @@
 use crate::{
-    config::KakarotRpcConfig,
     eth_rpc::api::kakarot_api::KakarotApiServer,
     providers::eth_provider::{
-        constant::{Constant, MAX_LOGS},
-        starknet::kakarot_core::{get_white_listed_eip_155_transaction_hashes, MAX_FELTS_IN_CALLDATA},
+        constant::Constant,
+        database::{ethereum::EthereumTransactionStore, types::transaction::RelayedTransactionStatus},
+        provider::EthDataProvider,
     },
 };
+use alloy_primitives::B256;
 use jsonrpsee::core::{async_trait, RpcResult};
+use starknet::providers::Provider;
 
 #[derive(Debug)]
-pub struct KakarotRpc;
+pub struct KakarotRpc<SP: Provider + Send + Sync> {
+    eth_provider: EthDataProvider<SP>,
+}
+
+impl<SP> KakarotRpc<SP>
+where
+    SP: Provider + Send + Sync,
+{
+    pub const fn new(eth_provider: EthDataProvider<SP>) -> Self {
+        Self { eth_provider }
+    }
+}
 
 #[async_trait]
-impl KakarotApiServer for KakarotRpc {
+impl<SP> KakarotApiServer for KakarotRpc<SP>
+where
+    SP: Provider + Send + Sync + 'static,
+{
     async fn get_config(&self) -> RpcResult<Constant> {
-        let starknet_config = KakarotRpcConfig::from_env().expect("Failed to load Kakarot RPC config");
+        let deployment = self.eth_provider.deployment();
+        let limits = self.eth_provider.limits();
         Ok(Constant {
-            max_logs: *MAX_LOGS,
-            starknet_network: String::from(starknet_config.network_url),
-            max_felts_in_calldata: *MAX_FELTS_IN_CALLDATA,
-            white_listed_eip_155_transaction_hashes: get_white_listed_eip_155_transaction_hashes(),
-            kakarot_address: starknet_config.kakarot_address,
+            max_logs: limits.max_logs,
+            starknet_network: String::from(deployment.network_url.clone()),
+            max_felts_in_calldata: limits.max_felts_in_calldata,
+            white_listed_eip_155_transaction_hashes: limits.white_listed_eip_155_transaction_hashes.clone(),
+            kakarot_address: deployment.kakarot_address,
         })
     }
+    async fn get_transaction_status(&self, hash: B256) -> RpcResult<Option<RelayedTransactionStatus>> {
+        Ok(self.eth_provider.database().transaction_status(&hash).await?)
+    }
 }
@@ -1,8 +1,11 @@
 pub mod alchemy_rpc;
 pub mod debug_rpc;
+pub mod eth_pubsub_rpc;
 pub mod eth_rpc;
 pub mod kakarot_rpc;
 pub mod net_rpc;
+pub mod otterscan_rpc;
+pub mod rpc_rpc;
 pub mod trace_rpc;
 pub mod txpool_rpc;
 pub mod web3_rpc;
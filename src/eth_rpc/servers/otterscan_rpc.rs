@@ -0,0 +1,101 @@
+use crate::{
+    eth_rpc::api::otterscan_api::OtterscanApiServer,
+    providers::otterscan_provider::{OtterscanProvider, OTTERSCAN_API_LEVEL},
+};
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_rpc_types::BlockId;
+use alloy_rpc_types_trace::otterscan::{
+    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, TraceEntry, TransactionsWithReceipts,
+};
+use jsonrpsee::core::{async_trait, RpcResult};
+
+/// The RPC module for the Otterscan API.
+#[derive(Debug)]
+pub struct OtterscanRpc<OP: OtterscanProvider> {
+    otterscan_provider: OP,
+}
+
+impl<OP> OtterscanRpc<OP>
+where
+    OP: OtterscanProvider,
+{
+    pub const fn new(otterscan_provider: OP) -> Self {
+        Self { otterscan_provider }
+    }
+}
+
+#[async_trait]
+impl<OP> OtterscanApiServer for OtterscanRpc<OP>
+where
+    OP: OtterscanProvider + Send + Sync + 'static,
+{
+    #[tracing::instrument(skip(self), ret)]
+    async fn get_api_level(&self) -> RpcResult<u64> {
+        Ok(OTTERSCAN_API_LEVEL)
+    }
+
+    #[tracing::instrument(skip(self), ret, err)]
+    async fn has_code(&self, address: Address, block_id: Option<BlockId>) -> RpcResult<bool> {
+        self.otterscan_provider.has_code(address, block_id).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_internal_operations(&self, transaction_hash: B256) -> RpcResult<Vec<InternalOperation>> {
+        self.otterscan_provider.internal_operations(transaction_hash).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), ret, err)]
+    async fn get_transaction_error(&self, transaction_hash: B256) -> RpcResult<Option<Bytes>> {
+        self.otterscan_provider.transaction_error(transaction_hash).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn trace_transaction(&self, transaction_hash: B256) -> RpcResult<Option<Vec<TraceEntry>>> {
+        self.otterscan_provider.trace_transaction(transaction_hash).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_block_details(&self, block_number: u64) -> RpcResult<BlockDetails> {
+        self.otterscan_provider.block_details(block_number).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_block_transactions(
+        &self,
+        block_number: u64,
+        page_number: usize,
+        page_size: usize,
+    ) -> RpcResult<OtsBlockTransactions> {
+        self.otterscan_provider.block_transactions(block_number, page_number, page_size).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn search_transactions_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> RpcResult<TransactionsWithReceipts> {
+        self.otterscan_provider.search_transactions_before(address, block_number, page_size).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn search_transactions_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> RpcResult<TransactionsWithReceipts> {
+        self.otterscan_provider.search_transactions_after(address, block_number, page_size).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), ret, err)]
+    async fn get_transaction_by_sender_and_nonce(&self, sender: Address, nonce: u64) -> RpcResult<Option<B256>> {
+        self.otterscan_provider.transaction_by_sender_and_nonce(sender, nonce).await.map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self), ret, err)]
+    async fn get_contract_creator(&self, address: Address) -> RpcResult<Option<ContractCreator>> {
+        self.otterscan_provider.contract_creator(address).await.map_err(Into::into)
+    }
+}
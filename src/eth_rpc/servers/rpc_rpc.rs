@@ -0,0 +1,38 @@
+use crate::eth_rpc::{api::rpc_api::RpcApiServer, openrpc::openrpc_document, rpc::KakarotRpcModule};
+use jsonrpsee::core::{async_trait, RpcResult};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The version of the RPC modules returned by `rpc_modules`.
+const RPC_MODULE_VERSION: &str = "1.0";
+
+/// The RPC module for the implementing Rpc Api { i.e rpc endpoints describing the node }
+#[derive(Debug)]
+pub struct RpcRpc {
+    modules: BTreeMap<String, String>,
+    document: Value,
+}
+
+impl RpcRpc {
+    /// Creates a new [`RpcRpc`] describing the given enabled modules.
+    pub fn new(modules: &[KakarotRpcModule]) -> Self {
+        Self {
+            modules: modules
+                .iter()
+                .map(|module| (module.namespace().to_string(), RPC_MODULE_VERSION.to_string()))
+                .collect(),
+            document: openrpc_document(modules),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcApiServer for RpcRpc {
+    fn rpc_modules(&self) -> RpcResult<BTreeMap<String, String>> {
+        Ok(self.modules.clone())
+    }
+
+    fn discover(&self) -> RpcResult<Value> {
+        Ok(self.document.clone())
+    }
+}
@@ -1,12 +1,24 @@
 use crate::{
-    eth_rpc::api::trace_api::TraceApiServer, providers::eth_provider::provider::EthereumProvider,
+    eth_rpc::api::trace_api::TraceApiServer,
+    providers::eth_provider::{error::EthApiError, provider::EthereumProvider},
     tracing::builder::TracerBuilder,
 };
-use alloy_rpc_types::BlockId;
-use alloy_rpc_types_trace::parity::LocalizedTransactionTrace;
+use alloy_primitives::B256;
+use alloy_rpc_types::{
+    state::{EvmOverrides, StateOverride},
+    BlockId, BlockOverrides, Index, TransactionRequest,
+};
+use alloy_rpc_types_trace::{
+    filter::TraceFilter,
+    parity::{LocalizedTransactionTrace, TraceResults, TraceResultsWithTransactionHash, TraceType},
+};
 use jsonrpsee::core::{async_trait, RpcResult};
+use reth_rpc_eth_types::EthApiError as RethEthApiError;
 use revm_inspectors::tracing::TracingInspectorConfig;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
+
+/// Maximum number of blocks that can be traced by a single `trace_filter` request.
+pub const MAX_TRACE_FILTER_BLOCKS: u64 = 100;
 
 /// The RPC module for implementing the Trace api
 #[derive(Debug)]
@@ -20,6 +32,25 @@ impl<P: EthereumProvider> TraceRpc<P> {
     }
 }
 
+impl<P: EthereumProvider + Send + Sync + 'static> TraceRpc<P> {
+    /// Returns the parity traces of the transaction, or `None` if the transaction is not found.
+    async fn transaction_traces(
+        &self,
+        transaction_hash: B256,
+    ) -> Result<Option<Vec<LocalizedTransactionTrace>>, EthApiError> {
+        let builder =
+            match TracerBuilder::new(Arc::new(&self.eth_provider)).await?.with_transaction_hash(transaction_hash).await
+            {
+                Ok(builder) => builder,
+                Err(EthApiError::TransactionNotFound(_)) => return Ok(None),
+                Err(err) => return Err(err),
+            };
+        let tracer = builder.with_tracing_options(TracingInspectorConfig::default_parity().into()).build()?;
+
+        Ok(Some(tracer.trace_transaction(transaction_hash)?))
+    }
+}
+
 #[async_trait]
 impl<P: EthereumProvider + Send + Sync + 'static> TraceApiServer for TraceRpc<P> {
     /// Returns the parity traces for the given block.
@@ -35,4 +66,143 @@ impl<P: EthereumProvider + Send + Sync + 'static> TraceApiServer for TraceRpc<P>
 
         Ok(tracer.trace_block()?)
     }
+
+    /// Returns the parity traces for the given transaction.
+    #[tracing::instrument(skip(self), err)]
+    async fn trace_transaction(&self, transaction_hash: B256) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>> {
+        tracing::info!("Serving trace_transaction");
+        Ok(self.transaction_traces(transaction_hash).await?)
+    }
+
+    /// Returns the parity trace at the given position of the traces of the transaction.
+    #[tracing::instrument(skip(self), err)]
+    async fn trace_get(
+        &self,
+        transaction_hash: B256,
+        indices: Vec<Index>,
+    ) -> RpcResult<Option<LocalizedTransactionTrace>> {
+        tracing::info!("Serving trace_get");
+        // Only a single index is supported, like other node implementations.
+        let [index] = indices[..] else { return Ok(None) };
+
+        let traces = self.transaction_traces(transaction_hash).await?;
+        Ok(traces.and_then(|traces| traces.into_iter().nth(index.into())))
+    }
+
+    /// Executes the call on top of the given block and returns the selected trace types.
+    #[tracing::instrument(skip(self, request, state_overrides, block_overrides), err)]
+    async fn trace_call(
+        &self,
+        request: TransactionRequest,
+        trace_types: HashSet<TraceType>,
+        block_id: Option<BlockId>,
+        state_overrides: Option<StateOverride>,
+        block_overrides: Option<Box<BlockOverrides>>,
+    ) -> RpcResult<TraceResults> {
+        tracing::info!("Serving trace_call");
+        let tracer = TracerBuilder::new(Arc::new(&self.eth_provider))
+            .await?
+            .with_block_id(block_id.unwrap_or_default())
+            .await?
+            .with_tracing_options(EvmOverrides::new(state_overrides, block_overrides).into())
+            .build()?;
+
+        Ok(tracer.trace_call(request, trace_types)?)
+    }
+
+    /// Executes the calls sequentially on top of the given block and returns the selected trace types.
+    #[tracing::instrument(skip(self, calls), err)]
+    async fn trace_call_many(
+        &self,
+        calls: Vec<(TransactionRequest, HashSet<TraceType>)>,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<Vec<TraceResults>> {
+        tracing::info!("Serving trace_callMany");
+        let tracer = TracerBuilder::new(Arc::new(&self.eth_provider))
+            .await?
+            .with_block_id(block_id.unwrap_or_default())
+            .await?
+            .with_tracing_options(EvmOverrides::default().into())
+            .build()?;
+
+        Ok(tracer.trace_call_many(calls)?)
+    }
+
+    /// Replays the transaction and returns the selected trace types.
+    #[tracing::instrument(skip(self), err)]
+    async fn trace_replay_transaction(
+        &self,
+        transaction_hash: B256,
+        trace_types: HashSet<TraceType>,
+    ) -> RpcResult<TraceResults> {
+        tracing::info!("Serving trace_replayTransaction");
+        let tracer = TracerBuilder::new(Arc::new(&self.eth_provider))
+            .await?
+            .with_transaction_hash(transaction_hash)
+            .await?
+            .with_tracing_options(trace_types.into())
+            .build()?;
+
+        Ok(tracer.replay_transaction(transaction_hash)?)
+    }
+
+    /// Replays all the transactions of the block and returns the selected trace types.
+    #[tracing::instrument(skip(self), err)]
+    async fn trace_replay_block_transactions(
+        &self,
+        block_id: BlockId,
+        trace_types: HashSet<TraceType>,
+    ) -> RpcResult<Option<Vec<TraceResultsWithTransactionHash>>> {
+        tracing::info!("Serving trace_replayBlockTransactions");
+        let tracer = TracerBuilder::new(Arc::new(&self.eth_provider))
+            .await?
+            .with_block_id(block_id)
+            .await?
+            .with_tracing_options(trace_types.into())
+            .build()?;
+
+        Ok(Some(tracer.replay_block_transactions()?))
+    }
+
+    /// Returns the parity traces matching the filter.
+    #[tracing::instrument(skip(self), err)]
+    async fn trace_filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTransactionTrace>> {
+        tracing::info!("Serving trace_filter");
+        let latest_block = self.eth_provider.block_number().await?.to::<u64>();
+        let from_block = filter.from_block.unwrap_or(latest_block);
+        let to_block = filter.to_block.unwrap_or(latest_block);
+
+        if from_block > to_block {
+            return Err(EthApiError::from(RethEthApiError::InvalidParams(
+                "invalid parameters: fromBlock cannot be greater than toBlock".to_string(),
+            ))
+            .into());
+        }
+        if to_block - from_block >= MAX_TRACE_FILTER_BLOCKS {
+            return Err(EthApiError::from(RethEthApiError::InvalidParams(format!(
+                "invalid parameters: block range exceeds the limit of {MAX_TRACE_FILTER_BLOCKS} blocks"
+            )))
+            .into());
+        }
+
+        let matcher = filter.matcher();
+        let mut traces = Vec::new();
+        for block_number in from_block..=to_block {
+            let tracer = TracerBuilder::new(Arc::new(&self.eth_provider))
+                .await?
+                .with_block_id(block_number.into())
+                .await?
+                .with_tracing_options(TracingInspectorConfig::default_parity().into())
+                .build()?;
+
+            let block_traces = tracer.trace_block()?.unwrap_or_default();
+            traces.extend(block_traces.into_iter().filter(|trace| matcher.matches(&trace.trace)));
+        }
+
+        // Apply the pagination of the filter.
+        let after = filter.after.unwrap_or_default().try_into().unwrap_or(usize::MAX);
+        let count = filter.count.map_or(usize::MAX, |count| count.try_into().unwrap_or(usize::MAX));
+
+        Ok(traces.into_iter().skip(after).take(count).collect())
+    }
 }
@@ -9,6 +9,7 @@ pub mod providers {
     pub mod alchemy_provider;
     pub mod debug_provider;
     pub mod eth_provider;
+    pub mod otterscan_provider;
     pub mod pool_provider;
     pub mod sn_provider;
 }
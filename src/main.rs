@@ -1,26 +1,30 @@
 use dotenvy::dotenv;
 use eyre::Result;
+use jsonrpsee::RpcModule;
 use kakarot_rpc::{
     client::EthClient,
-    constants::{KAKAROT_RPC_CONFIG, KKRT_BLOCK_GAS_LIMIT, RPC_CONFIG},
-    eth_rpc::{rpc::KakarotRpcModuleBuilder, run_server},
+    config::{AppConfig, ChainConfig, ConfigSource},
+    constants::KKRT_BLOCK_GAS_LIMIT,
+    eth_rpc::{
+        config::RPCConfig,
+        middleware::{prefix_methods, CHAIN_PATH_PREFIX},
+        rpc::KakarotRpcModuleBuilder,
+        run_server,
+    },
     pool::{
         constants::PRUNE_DURATION,
+        journal::TransactionJournal,
         mempool::{maintain_transaction_pool, AccountManager},
+        monitor::RelayerMetrics,
     },
-    providers::eth_provider::{
-        database::Database,
-        starknet::kakarot_core::{core::KakarotCoreReader, KAKAROT_ADDRESS},
-    },
+    prometheus_handler::Registry,
+    providers::eth_provider::{database::Database, starknet::kakarot_core::KakarotDeployment},
 };
 use mongodb::options::{DatabaseOptions, ReadConcern, WriteConcern};
 use opentelemetry_sdk::runtime::Tokio;
 use reth_transaction_pool::PoolConfig;
-use starknet::{
-    core::types::{BlockId, BlockTag, Felt},
-    providers::{jsonrpc::HttpTransport, JsonRpcClient},
-};
-use std::{env::var, str::FromStr, sync::Arc};
+use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient};
+use std::{env::var, path::PathBuf, sync::Arc};
 use tracing_opentelemetry::MetricsLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 
@@ -29,17 +33,59 @@ async fn main() -> Result<()> {
     // Environment variables are safe to use after this
     dotenv().ok();
 
+    // Load and validate the whole configuration, so that the RPC fails to start if it is invalid
+    let options = Options::parse()?;
+    let source = ConfigSource::load(options.config_path.as_deref(), options.overrides)?;
+    let config = AppConfig::from_source(&source)?;
+    if options.print_config {
+        print!("{}", source.to_toml());
+        return Ok(());
+    }
+
     setup_tracing().expect("failed to start tracing and metrics");
 
-    let starknet_provider = JsonRpcClient::new(HttpTransport::new(KAKAROT_RPC_CONFIG.network_url.clone()));
+    // Creating the prometheus registry to register the metrics
+    let registry = Registry::new();
+
+    // Register the relayer metrics, shared by the chains under their `chain` label
+    let relayer_metrics = RelayerMetrics::new(&registry)?;
+
+    // Start the default chain, and the additional chains under their `/chain/<name>` path
+    let mut kakarot_rpc_module = start_chain(&config.chain, &config.rpc, relayer_metrics.clone()).await?;
+    for (name, chain) in &config.chains {
+        let chain_rpc_module = start_chain(chain, &config.rpc, relayer_metrics.for_chain(name)).await?;
+        kakarot_rpc_module.merge(prefix_methods(chain_rpc_module, name)?)?;
+        tracing::info!("Serving chain {name} under {CHAIN_PATH_PREFIX}{name}");
+    }
+
+    // Start the RPC server
+    let (socket_addr, server_handle) = run_server(kakarot_rpc_module, config.rpc.clone(), registry).await?;
+    let url = format!("http://{socket_addr}");
+
+    tracing::info!("RPC Server running on {url}...");
+
+    server_handle.stopped().await;
+
+    Ok(())
+}
+
+/// Starts the client, the relayers and the mempool maintenance of the chain, and returns its RPC
+/// module. The relayers of the chain record their metrics in the given relayer metrics.
+async fn start_chain(
+    config: &ChainConfig,
+    rpc_config: &RPCConfig,
+    relayer_metrics: RelayerMetrics,
+) -> Result<RpcModule<()>> {
+    let starknet_provider = JsonRpcClient::new(HttpTransport::new(config.kakarot_rpc.network_url.clone()));
+
+    // Fetch the chain id of the Kakarot deployment
+    let deployment = KakarotDeployment::fetch(&starknet_provider, &config.kakarot_rpc).await?;
 
     // Setup the database
-    let db_client =
-        mongodb::Client::with_uri_str(var("MONGO_CONNECTION_STRING").expect("Missing MONGO_CONNECTION_STRING .env"))
-            .await?;
+    let db_client = mongodb::Client::with_uri_str(&config.database.connection_string).await?;
     let db = Database::new(
         db_client.database_with_options(
-            &var("MONGO_DATABASE_NAME").expect("Missing MONGO_DATABASE_NAME from .env"),
+            &config.database.name,
             DatabaseOptions::builder()
                 .read_concern(ReadConcern::majority())
                 .write_concern(WriteConcern::majority())
@@ -50,37 +96,124 @@ async fn main() -> Result<()> {
     // Setup the eth provider
     let starknet_provider = Arc::new(starknet_provider);
 
-    // Get the pool config
-    let contract_reader = KakarotCoreReader::new(*KAKAROT_ADDRESS, starknet_provider.clone());
-    let base_fee = contract_reader.get_base_fee().block_id(BlockId::Tag(BlockTag::Pending)).call().await?.base_fee;
-    let base_fee = base_fee.try_into()?;
-    let config =
-        PoolConfig { minimal_protocol_basefee: base_fee, gas_limit: KKRT_BLOCK_GAS_LIMIT, ..Default::default() };
+    // Get the pool config. The Kakarot base fee is checked by the transaction validator on each block.
+    let pool_config = PoolConfig {
+        minimal_protocol_basefee: 0,
+        gas_limit: KKRT_BLOCK_GAS_LIMIT,
+        max_account_slots: config.spam_protection.max_account_slots,
+        ..Default::default()
+    };
 
     // Init the Ethereum Client
-    let eth_client = EthClient::new(starknet_provider, config, db.clone());
+    let mut eth_client = EthClient::new(
+        starknet_provider,
+        deployment,
+        config.limits.clone(),
+        pool_config,
+        config.spam_protection.clone(),
+        db.clone(),
+    );
+    if let Some(path) = config.journal_path.clone() {
+        eth_client = eth_client.with_journal(TransactionJournal::new(path));
+    }
     let eth_client = Arc::new(eth_client);
 
+    // Restore the transactions of the mempool journal
+    let replayed = eth_client.replay_journal().await?;
+    if replayed > 0 {
+        tracing::info!("Restored {replayed} transactions from the mempool journal");
+    }
+
     // Start the relayer manager
-    let addresses =
-        var("RELAYERS_ADDRESSES")?.split(',').filter_map(|addr| Felt::from_str(addr).ok()).collect::<Vec<_>>();
-    AccountManager::new(addresses, Arc::clone(&eth_client)).start();
+    AccountManager::new(config.relayer_keys.clone(), config.relayer.clone(), Arc::clone(&eth_client))
+        .await?
+        .with_metrics(relayer_metrics)
+        .start();
 
     // Start the maintenance of the mempool
     maintain_transaction_pool(Arc::clone(&eth_client), PRUNE_DURATION);
 
-    // Setup the RPC module
-    let kakarot_rpc_module = KakarotRpcModuleBuilder::new(eth_client).rpc_module()?;
-
-    // Start the RPC server
-    let (socket_addr, server_handle) = run_server(kakarot_rpc_module, RPC_CONFIG.clone()).await?;
-    let url = format!("http://{socket_addr}");
+    // Setup the RPC module with the enabled modules
+    let mut rpc_module_builder = KakarotRpcModuleBuilder::new(eth_client);
+    if let Some(modules) = rpc_config.modules.clone() {
+        rpc_module_builder = rpc_module_builder.with_enabled_modules(modules);
+    }
+    Ok(rpc_module_builder.rpc_module()?)
+}
 
-    tracing::info!("RPC Server running on {url}...");
+/// The command line options of the binary.
+///
+/// The configuration is loaded from the TOML configuration file, overridden by the environment
+/// variables, themselves overridden by the command line flags of the settings.
+#[derive(Debug, Default)]
+struct Options {
+    /// The path of the configuration file, defaulting to `KAKAROT_RPC_CONFIG_PATH`.
+    config_path: Option<PathBuf>,
+    /// Whether to print the configuration and exit.
+    print_config: bool,
+    /// The settings of the command line flags.
+    overrides: ConfigSource,
+}
 
-    server_handle.stopped().await;
+impl Options {
+    /// Parses the command line options, with a flag for each of the [`SETTINGS`](kakarot_rpc::config::SETTINGS).
+    #[cfg(feature = "binaries")]
+    fn parse() -> Result<Self> {
+        use clap::{Arg, ArgAction, Command};
+        use kakarot_rpc::config::SETTINGS;
+
+        let matches = Command::new(env!("CARGO_PKG_NAME"))
+            .version(env!("CARGO_PKG_VERSION"))
+            .about(env!("CARGO_PKG_DESCRIPTION"))
+            .arg(
+                Arg::new("config")
+                    .short('c')
+                    .long("config")
+                    .value_name("PATH")
+                    .help("Path of the TOML configuration file [env: KAKAROT_RPC_CONFIG_PATH]"),
+            )
+            .arg(
+                Arg::new("print-config")
+                    .long("print-config")
+                    .action(ArgAction::SetTrue)
+                    .help("Print the validated configuration, with the secrets redacted, and exit"),
+            )
+            .args(SETTINGS.iter().map(|setting| {
+                Arg::new(setting.env)
+                    .long(setting.key)
+                    .value_name("VALUE")
+                    .help(format!("{} [env: {}]", setting.help, setting.env))
+            }))
+            .get_matches();
+
+        let mut overrides = ConfigSource::default();
+        for setting in SETTINGS {
+            if let Some(value) = matches.get_one::<String>(setting.env) {
+                overrides.set(setting.env, value.clone())?;
+            }
+        }
+
+        Ok(Self {
+            config_path: matches.get_one::<String>("config").map(PathBuf::from).or_else(config_path_from_env),
+            print_config: matches.get_flag("print-config"),
+            overrides,
+        })
+    }
+
+    /// Without the `binaries` feature, the binary doesn't take any command line flag and the
+    /// configuration file can only be set with `KAKAROT_RPC_CONFIG_PATH`.
+    #[cfg(not(feature = "binaries"))]
+    fn parse() -> Result<Self> {
+        if std::env::args().len() > 1 {
+            return Err(eyre::eyre!("command line flags require the `binaries` feature"));
+        }
+        Ok(Self { config_path: config_path_from_env(), ..Default::default() })
+    }
+}
 
-    Ok(())
+/// Returns the path of the configuration file set with `KAKAROT_RPC_CONFIG_PATH`, if any.
+fn config_path_from_env() -> Option<PathBuf> {
+    var("KAKAROT_RPC_CONFIG_PATH").ok().filter(|path| !path.is_empty()).map(PathBuf::from)
 }
 
 /// Set up the subscriber for tracing and metrics
@@ -1,28 +1,34 @@
+use crate::eth_rpc::openrpc::schema;
 use alloy_primitives::{Address, U256};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Represents the balance of a specific ERC20 token.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct TokenBalance {
     /// The address of the ERC20 token.
+    #[schemars(with = "schema::Address")]
     pub token_address: Address,
     /// The balance of the ERC20 token.
+    #[schemars(with = "schema::Quantity")]
     pub token_balance: U256,
 }
 
 /// Represents the balances of multiple ERC20 tokens for a specific address.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct TokenBalances {
     /// The address for which the token balances are queried.
+    #[schemars(with = "schema::Address")]
     pub address: Address,
     /// A list of token balances associated with the address.
     pub token_balances: Vec<TokenBalance>,
 }
 
 /// Represents the metadata (decimals, name, symbol) of an ERC20 token.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct TokenMetadata {
     /// The number of decimals the token uses.
+    #[schemars(with = "schema::Quantity")]
     pub decimals: U256,
     /// The name of the token.
     pub name: String,
@@ -1,17 +1,12 @@
+#[cfg(not(feature = "hive"))]
+use crate::providers::eth_provider::error::EthApiError;
 use crate::providers::eth_provider::{
-    provider::EthApiResult,
-    starknet::kakarot_core::{ETH_SEND_TRANSACTION, KAKAROT_ADDRESS},
-    utils::split_u256,
+    provider::EthApiResult, starknet::kakarot_core::ETH_SEND_TRANSACTION, utils::split_u256,
 };
 use alloy_consensus::transaction::Transaction as _;
 use alloy_rlp::Encodable;
 use reth_primitives::{transaction::legacy_parity, Transaction, TransactionSigned};
 use starknet::core::types::Felt;
-#[cfg(not(feature = "hive"))]
-use {
-    crate::providers::eth_provider::error::EthApiError,
-    crate::providers::eth_provider::starknet::kakarot_core::MAX_FELTS_IN_CALLDATA,
-};
 
 /// Returns the transaction's signature as a [`Vec<Felt>`].
 /// Fields r and s are split into two 16-bytes chunks both converted
@@ -37,12 +32,29 @@ pub(crate) fn transaction_signature_to_field_elements(transaction_signed: &Trans
     signature
 }
 
+/// Returns the number of felts of the Starknet calldata relaying the transaction, as built by
+/// [`transaction_data_to_starknet_calldata`].
+pub fn starknet_calldata_len(transaction_signed: &TransactionSigned) -> usize {
+    let mut signed_data = Vec::with_capacity(transaction_signed.transaction.length());
+    transaction_signed.transaction.encode_without_signature(&mut signed_data);
+    let signature_len = transaction_signature_to_field_elements(transaction_signed).len();
+
+    // Outside execution and call array, signed data length and 31-byte chunks, signature and its length
+    10 + 1 + signed_data.len().div_ceil(31) + signature_len + 1
+}
+
 /// Returns the transaction's data and signature combined into a
-/// [`execute_from_outside`] type transaction. The payload still needs
-/// to be signed by the relayer before broadcasting.
+/// [`execute_from_outside`] type transaction calling the Kakarot contract
+/// at `kakarot_address`. The payload still needs to be signed by the
+/// relayer before broadcasting.
+///
+/// Fails if the calldata has more felts than `max_felts_in_calldata`, if any.
+#[cfg_attr(feature = "hive", allow(unused_variables))]
 pub fn transaction_data_to_starknet_calldata(
     transaction_signed: &TransactionSigned,
     relayer_address: Felt,
+    kakarot_address: Felt,
+    max_felts_in_calldata: Option<usize>,
 ) -> EthApiResult<Vec<Felt>> {
     let mut signed_data = Vec::with_capacity(transaction_signed.transaction.length());
     transaction_signed.transaction.encode_without_signature(&mut signed_data);
@@ -60,8 +72,8 @@ pub fn transaction_data_to_starknet_calldata(
 
     // Check if call data is too large
     #[cfg(not(feature = "hive"))]
-    if capacity > *MAX_FELTS_IN_CALLDATA {
-        return Err(EthApiError::CalldataExceededLimit(*MAX_FELTS_IN_CALLDATA, capacity));
+    if let Some(max_felts_in_calldata) = max_felts_in_calldata.filter(|max| capacity > *max) {
+        return Err(EthApiError::CalldataExceededLimit(max_felts_in_calldata, capacity));
     }
 
     let mut execute_from_outside_calldata = Vec::with_capacity(capacity);
@@ -74,7 +86,7 @@ pub fn transaction_data_to_starknet_calldata(
         Felt::ZERO,               // OutsideExecution execute_after
         Felt::from(u32::MAX),     // OutsideExecution execute_before
         Felt::ONE,                // call_array_len
-        *KAKAROT_ADDRESS,         // CallArray to
+        kakarot_address,          // CallArray to
         *ETH_SEND_TRANSACTION,    // CallArray selector
         Felt::ZERO,               // CallArray data_offset
         signed_data.len().into(), // CallArray data_len
@@ -113,11 +125,13 @@ mod tests {
     );
 
         // Invoke the function to convert the transaction to Starknet format.
-        let calldata = transaction_data_to_starknet_calldata(&transaction, Felt::ZERO).unwrap();
+        let kakarot_address = Felt::from(0x1234);
+        let calldata = transaction_data_to_starknet_calldata(&transaction, Felt::ZERO, kakarot_address, None).unwrap();
 
         // Assert the length of calldata.
         // We must adapt the check as we pack the calldata in 31-byte chunks.
         assert_eq!(calldata.len(), 59);
+        assert_eq!(starknet_calldata_len(&transaction), calldata.len());
 
         // Assert the first 6 elements of calldata.
         assert_eq!(
@@ -128,7 +142,7 @@ mod tests {
                 Felt::ZERO,                                                   // OutsideExecution execute_after
                 Felt::from(u32::MAX),                                         // OutsideExecution execute_before
                 Felt::ONE,                                                    // call_array_len
-                *KAKAROT_ADDRESS,                                             // CallArray to
+                kakarot_address,                                              // CallArray to
                 *ETH_SEND_TRANSACTION,                                        // CallArray selector
                 Felt::ZERO,                                                   // CallArray data_offset
                 Felt::from((transaction.transaction.length() + 30) / 31 + 1), // CallArray data_len
@@ -156,6 +170,6 @@ mod tests {
         transaction.transaction.set_input(vec![0; 30000 * 31].into());
 
         // Attempt to convert the transaction into a Starknet transaction
-        transaction_data_to_starknet_calldata(&transaction, Felt::ZERO).unwrap();
+        transaction_data_to_starknet_calldata(&transaction, Felt::ZERO, Felt::ONE, Some(22500)).unwrap();
     }
 }
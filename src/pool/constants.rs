@@ -1,6 +1,35 @@
 use std::time::Duration;
 
+// Relayers paying the fees in ETH (v1 transactions) need at least 0.1 ETH to relay transactions
 pub(super) static ONE_TENTH_ETH: u64 = 10u64.pow(17);
 
+// Relayers paying the fees in STRK (v3 transactions) need at least 10 STRK to relay transactions
+pub(super) static TEN_STRK: u64 = 10 * 10u64.pow(18);
+
+// Pending transactions are dispatched at least every 5 seconds, even without pool events
+pub(super) const DISPATCH_FALLBACK_INTERVAL: Duration = Duration::from_secs(5);
+
+// Relayer balances are refreshed from the chain once they are older than 30 seconds
+pub(super) const RELAYER_BALANCE_CACHE_DURATION: Duration = Duration::from_secs(30);
+
+// Relayer balances and nonces are monitored every 30 seconds
+pub(super) const RELAYER_MONITOR_INTERVAL: Duration = Duration::from_secs(30);
+
+// Relayer keystore files are re-scanned every 30 seconds to add and remove relayers
+pub(super) const KEYSTORE_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
 // Transactions should be pruned after 5 minutes in the mempool
 pub const PRUNE_DURATION: Duration = Duration::from_secs(300);
+
+// The Starknet receipts of the relayed transactions are polled every 2 seconds
+pub(super) const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+// Relayed transactions without a Starknet receipt after 2 minutes are considered rejected
+pub(super) const RELAY_TIMEOUT: Duration = Duration::from_secs(120);
+
+// Transactions which failed for a retryable reason are re-queued at most 3 times
+pub(super) const MAX_RELAY_RETRIES: u32 = 3;
+
+// Starknet revert reasons caused by the relayer rather than by the relayed transaction
+pub(super) const RETRYABLE_REVERT_REASONS: [&str; 3] =
+    ["Invalid transaction nonce", "Insufficient max fee", "exceeds balance"];
@@ -0,0 +1,229 @@
+use alloy_primitives::{hex, B256};
+use alloy_rlp::{Decodable, Encodable};
+use reth_primitives::TransactionSigned;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::PathBuf,
+};
+use tokio::sync::Mutex;
+
+/// An on-disk journal of the transactions of the pool, used to restore the pool across restarts.
+///
+/// The journal contains one hex encoded RLP [`TransactionSigned`] per line. Transactions are
+/// appended as they are added to the pool, and the journal is periodically rewritten with the
+/// content of the pool in order to drop the mined and pruned transactions.
+///
+/// Transactions are removed from the pool when they are dispatched to a relayer, so the journal
+/// also tracks the transactions in flight, i.e. dispatched but not yet final on Starknet, in order
+/// to keep them across the rewrites.
+///
+/// The file is accessed on a blocking thread, so that the journal can be used from async tasks.
+#[derive(Debug)]
+pub struct TransactionJournal {
+    /// The path of the journal file.
+    path: PathBuf,
+    /// Serializes the accesses to the journal file.
+    lock: Mutex<()>,
+    /// The transactions in flight, indexed by hash.
+    in_flight: std::sync::Mutex<HashMap<B256, TransactionSigned>>,
+}
+
+impl TransactionJournal {
+    /// Creates a new journal at the given path. The file is created on the first write.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), lock: Mutex::new(()), in_flight: Default::default() }
+    }
+
+    /// Loads the transactions of the journal. Lines which can't be decoded are skipped.
+    pub async fn load(&self) -> io::Result<Vec<TransactionSigned>> {
+        let _guard = self.lock.lock().await;
+        let path = self.path.clone();
+
+        blocking(move || {
+            let file = match File::open(&path) {
+                Ok(file) => file,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+                Err(err) => return Err(err),
+            };
+
+            let mut transactions = Vec::new();
+            for (index, line) in BufReader::new(file).lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match hex::decode(line.trim())
+                    .ok()
+                    .and_then(|bytes| TransactionSigned::decode(&mut bytes.as_slice()).ok())
+                {
+                    Some(transaction) => transactions.push(transaction),
+                    None => {
+                        tracing::warn!(target: "transaction_journal", line = index + 1, "failed to decode transaction");
+                    }
+                }
+            }
+
+            Ok(transactions)
+        })
+        .await
+    }
+
+    /// Appends the transaction to the journal.
+    pub async fn append(&self, transaction: &TransactionSigned) -> io::Result<()> {
+        let line = encode(transaction);
+        let _guard = self.lock.lock().await;
+        let path = self.path.clone();
+
+        blocking(move || {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{line}")
+        })
+        .await
+    }
+
+    /// Rewrites the journal with the given transactions of the pool and the transactions in
+    /// flight.
+    ///
+    /// The transactions are written to a temporary file which then replaces the journal, so that
+    /// the journal is never left partially written.
+    pub async fn rotate(&self, transactions: impl IntoIterator<Item = TransactionSigned>) -> io::Result<()> {
+        let in_flight: Vec<_> = self.in_flight.lock().expect("journal lock poisoned").values().cloned().collect();
+        let mut hashes = HashSet::new();
+        let lines: Vec<_> = transactions
+            .into_iter()
+            .chain(in_flight)
+            .filter(|transaction| hashes.insert(transaction.hash()))
+            .map(|transaction| encode(&transaction))
+            .collect();
+
+        let _guard = self.lock.lock().await;
+        let path = self.path.clone();
+
+        blocking(move || {
+            let tmp_path = path.with_extension("new");
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            for line in lines {
+                writeln!(writer, "{line}")?;
+            }
+            writer.into_inner().map_err(io::IntoInnerError::into_error)?.sync_all()?;
+
+            fs::rename(tmp_path, path)
+        })
+        .await
+    }
+
+    /// Tracks the transaction as in flight, i.e. removed from the pool to be relayed, so that it is
+    /// kept by the next rotations of the journal.
+    pub fn add_in_flight(&self, transaction: TransactionSigned) {
+        self.in_flight.lock().expect("journal lock poisoned").insert(transaction.hash(), transaction);
+    }
+
+    /// Stops tracking the transaction as in flight, once its relay is final or once it is back in
+    /// the pool.
+    pub fn remove_in_flight(&self, hash: &B256) {
+        self.in_flight.lock().expect("journal lock poisoned").remove(hash);
+    }
+}
+
+/// Runs the file operation on a blocking thread.
+async fn blocking<T, F>(f: F) -> io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.map_err(io::Error::other)?
+}
+
+/// Hex encodes the RLP encoding of the transaction.
+fn encode(transaction: &TransactionSigned) -> String {
+    let mut buf = Vec::with_capacity(transaction.length());
+    transaction.encode(&mut buf);
+    hex::encode(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxLegacy;
+    use alloy_primitives::{Address, Signature, TxKind, U256};
+    use reth_primitives::Transaction;
+
+    fn transaction(nonce: u64) -> TransactionSigned {
+        let transaction = Transaction::Legacy(TxLegacy {
+            chain_id: Some(1),
+            nonce,
+            gas_price: 10,
+            gas_limit: 21000,
+            to: TxKind::Call(Address::random()),
+            value: U256::from(nonce),
+            ..Default::default()
+        });
+        TransactionSigned::from_transaction_and_signature(transaction, Signature::test_signature())
+    }
+
+    #[tokio::test]
+    async fn test_journal_append_load_and_rotate() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let journal = TransactionJournal::new(dir.path().join("transactions.rlp"));
+        let transactions = (0..3).map(transaction).collect::<Vec<_>>();
+
+        // When
+        for transaction in &transactions {
+            journal.append(transaction).await.expect("Failed to append transaction");
+        }
+
+        // Then
+        assert_eq!(journal.load().await.expect("Failed to load journal"), transactions);
+
+        // When
+        journal.rotate(transactions[1..].to_vec()).await.expect("Failed to rotate journal");
+
+        // Then
+        assert_eq!(journal.load().await.expect("Failed to load journal"), transactions[1..]);
+    }
+
+    #[tokio::test]
+    async fn test_journal_rotate_keeps_in_flight_transactions() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let journal = TransactionJournal::new(dir.path().join("transactions.rlp"));
+        let transactions = (0..3).map(transaction).collect::<Vec<_>>();
+        for transaction in &transactions {
+            journal.append(transaction).await.expect("Failed to append transaction");
+        }
+
+        // When
+        // The first transaction is removed from the pool to be relayed
+        journal.add_in_flight(transactions[0].clone());
+        journal.rotate(transactions[1..].to_vec()).await.expect("Failed to rotate journal");
+
+        // Then
+        let mut loaded = journal.load().await.expect("Failed to load journal");
+        loaded.sort_by_key(|transaction| transaction.nonce());
+        assert_eq!(loaded, transactions);
+
+        // When
+        // The relay of the first transaction is final
+        journal.remove_in_flight(&transactions[0].hash());
+        journal.rotate(transactions[1..].to_vec()).await.expect("Failed to rotate journal");
+
+        // Then
+        assert_eq!(journal.load().await.expect("Failed to load journal"), transactions[1..]);
+    }
+
+    #[tokio::test]
+    async fn test_journal_load_missing_file() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let journal = TransactionJournal::new(dir.path().join("transactions.rlp"));
+
+        // When
+        let transactions = journal.load().await.expect("Failed to load journal");
+
+        // Then
+        assert!(transactions.is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{config::ConfigSource, providers::eth_provider::starknet::signer::RelayerSigner};
+use eyre::{eyre, WrapErr};
+use starknet::{
+    core::types::Felt,
+    signers::{LocalWallet, SigningKey},
+};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// The address of a relayer account and the signer of its transactions.
+#[derive(Debug, Clone)]
+pub struct RelayerKey {
+    /// The address of the relayer account.
+    pub address: Felt,
+    /// The signer of the relayer transactions.
+    pub signer: RelayerSigner,
+}
+
+/// The source of the relayer keys.
+#[derive(Debug, Clone)]
+pub enum RelayerKeySource {
+    /// A fixed set of relayers.
+    Static(Vec<RelayerKey>),
+    /// Encrypted Starknet keystore files, each named after the address of its relayer account
+    /// (e.g. `0x123.json`). The path is either a single keystore file or a directory of them, in
+    /// which case relayers are added and removed as keystore files are.
+    Keystore {
+        /// The path of the keystore file or directory.
+        path: PathBuf,
+        /// The password of the keystore files.
+        password: String,
+    },
+}
+
+impl RelayerKeySource {
+    /// `RELAYER_KEYSTORE_PATH` setting should be set to a keystore file or a directory of
+    /// keystore files, decrypted with `RELAYER_KEYSTORE_PASSWORD`.
+    ///
+    /// If it is not set, the relayers are the `RELAYERS_ADDRESSES` comma separated accounts, all
+    /// signing with the `RELAYER_PRIVATE_KEY` private key.
+    pub fn from_source(source: &ConfigSource) -> eyre::Result<Self> {
+        if let Some(path) = source.parse::<PathBuf>("RELAYER_KEYSTORE_PATH")? {
+            let password = source.require("RELAYER_KEYSTORE_PASSWORD")?;
+            return Ok(Self::Keystore { path, password });
+        }
+
+        let signer: RelayerSigner =
+            LocalWallet::from_signing_key(SigningKey::from_secret_scalar(source.require("RELAYER_PRIVATE_KEY")?))
+                .into();
+        let keys = source
+            .parse_list::<Felt, Vec<_>>("RELAYERS_ADDRESSES")?
+            .ok_or_else(|| eyre!("missing setting relayer.addresses (RELAYERS_ADDRESSES)"))?
+            .into_iter()
+            .map(|address| RelayerKey { address, signer: signer.clone() })
+            .collect();
+
+        Ok(Self::Static(keys))
+    }
+
+    /// Returns `true` if the relayers of the source can change over time.
+    pub const fn is_reloadable(&self) -> bool {
+        matches!(self, Self::Keystore { .. })
+    }
+
+    /// Returns the addresses of the relayers of the source.
+    pub fn addresses(&self) -> eyre::Result<Vec<Felt>> {
+        match self {
+            Self::Static(keys) => Ok(keys.iter().map(|key| key.address).collect()),
+            Self::Keystore { path, .. } => Ok(keystore_files(path)?.into_keys().collect()),
+        }
+    }
+
+    /// Returns the version of the key of each relayer of the source, changing when the key of the
+    /// relayer changes: the modification time of the keystore files, `None` for static keys.
+    pub fn key_versions(&self) -> eyre::Result<HashMap<Felt, Option<SystemTime>>> {
+        match self {
+            Self::Static(keys) => Ok(keys.iter().map(|key| (key.address, None)).collect()),
+            Self::Keystore { path, .. } => keystore_files(path)?
+                .into_iter()
+                .map(|(address, file)| Ok((address, fs::metadata(file)?.modified().ok())))
+                .collect(),
+        }
+    }
+
+    /// Loads the key of the relayer.
+    ///
+    /// Decrypting a keystore file is expensive and blocking, so this should be called from a
+    /// blocking task.
+    pub fn load(&self, address: Felt) -> eyre::Result<RelayerKey> {
+        match self {
+            Self::Static(keys) => keys
+                .iter()
+                .find(|key| key.address == address)
+                .cloned()
+                .ok_or_else(|| eyre!("unknown relayer {address:#x}")),
+            Self::Keystore { path, password } => {
+                let file =
+                    keystore_files(path)?.remove(&address).ok_or_else(|| eyre!("unknown relayer {address:#x}"))?;
+                let signing_key = SigningKey::from_keystore(&file, password)
+                    .wrap_err_with(|| format!("failed to decrypt keystore {}", file.display()))?;
+                Ok(RelayerKey { address, signer: LocalWallet::from_signing_key(signing_key).into() })
+            }
+        }
+    }
+
+    /// Loads the keys of all the relayers of the source. Blocking, see [`RelayerKeySource::load`].
+    pub fn load_all(&self) -> eyre::Result<Vec<RelayerKey>> {
+        match self {
+            Self::Static(keys) => Ok(keys.clone()),
+            Self::Keystore { .. } => self.addresses()?.into_iter().map(|address| self.load(address)).collect(),
+        }
+    }
+}
+
+/// Returns the keystore files at the path, indexed by the relayer address found in their name.
+///
+/// Files of a directory which are not named after an address are ignored.
+fn keystore_files(path: &Path) -> eyre::Result<HashMap<Felt, PathBuf>> {
+    if !path.is_dir() {
+        let address = keystore_address(path).ok_or_else(|| eyre!("invalid keystore file name {}", path.display()))?;
+        return Ok(HashMap::from([(address, path.to_path_buf())]));
+    }
+
+    let mut files = HashMap::new();
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        match keystore_address(&path) {
+            Some(address) => {
+                files.insert(address, path);
+            }
+            None => tracing::debug!(target: "relayer_keystore", path = %path.display(), "skipping file"),
+        }
+    }
+    Ok(files)
+}
+
+/// Parses the relayer address from the name of the keystore file.
+fn keystore_address(path: &Path) -> Option<Felt> {
+    if path.extension()? != "json" {
+        return None;
+    }
+    Felt::from_hex(path.file_stem()?.to_str()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keystore_source_directory() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let key = SigningKey::from_random();
+        key.save_as_keystore(dir.path().join("0x1234.json"), "password").expect("Failed to save keystore");
+        fs::write(dir.path().join("README.md"), "relayer keys").expect("Failed to write file");
+        let source = RelayerKeySource::Keystore { path: dir.path().to_path_buf(), password: "password".into() };
+
+        // When
+        let addresses = source.addresses().expect("Failed to list relayers");
+        let relayer = source.load(Felt::from(0x1234)).expect("Failed to load relayer");
+
+        // Then
+        assert_eq!(addresses, vec![Felt::from(0x1234)]);
+        assert_eq!(relayer.address, Felt::from(0x1234));
+        assert!(source.load(Felt::from(0x5678)).is_err());
+    }
+
+    #[test]
+    fn test_keystore_source_key_versions() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let path = dir.path().join("0x1234.json");
+        SigningKey::from_random().save_as_keystore(&path, "password").expect("Failed to save keystore");
+        let source = RelayerKeySource::Keystore { path: dir.path().to_path_buf(), password: "password".into() };
+        let versions = source.key_versions().expect("Failed to list key versions");
+
+        // When
+        // The key of the relayer is replaced
+        let modified = SystemTime::now() + std::time::Duration::from_secs(60);
+        SigningKey::from_random().save_as_keystore(&path, "password").expect("Failed to save keystore");
+        fs::File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+
+        // Then
+        let new_versions = source.key_versions().expect("Failed to list key versions");
+        assert_eq!(new_versions.len(), 1);
+        assert_ne!(new_versions[&Felt::from(0x1234)], versions[&Felt::from(0x1234)]);
+        assert_eq!(new_versions[&Felt::from(0x1234)], Some(modified));
+    }
+
+    #[test]
+    fn test_keystore_source_wrong_password() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let path = dir.path().join("0x1234.json");
+        SigningKey::from_random().save_as_keystore(&path, "password").expect("Failed to save keystore");
+        let source = RelayerKeySource::Keystore { path, password: "wrong".into() };
+
+        // When
+        let res = source.load_all();
+
+        // Then
+        assert!(res.is_err());
+    }
+}
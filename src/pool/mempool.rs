@@ -1,29 +1,64 @@
 #![allow(clippy::significant_drop_tightening)]
 
-use super::validate::KakarotTransactionValidator;
+use super::{
+    keystore::{RelayerKey, RelayerKeySource},
+    monitor::RelayerMetrics,
+    validate::KakarotTransactionValidator,
+    watcher::{RelayedTransaction, TransactionWatcher},
+};
 use crate::{
     client::EthClient,
-    constants::{KAKAROT_RPC_CONFIG, KKRT_BLOCK_GAS_LIMIT},
+    config::RelayerConfig,
+    constants::KKRT_BLOCK_GAS_LIMIT,
     into_via_try_wrapper,
-    pool::constants::ONE_TENTH_ETH,
-    providers::eth_provider::{database::state::EthDatabase, starknet::relayer::Relayer, BlockProvider},
+    pool::constants::{
+        DISPATCH_FALLBACK_INTERVAL, KEYSTORE_RELOAD_INTERVAL, ONE_TENTH_ETH, RELAYER_BALANCE_CACHE_DURATION,
+        RELAYER_MONITOR_INTERVAL, TEN_STRK,
+    },
+    providers::eth_provider::{
+        database::{
+            ethereum::EthereumTransactionStore,
+            state::EthDatabase,
+            types::transaction::{RelayStatus, RelayedTransactionStatus},
+        },
+        error::{EthApiError, TransactionError},
+        provider::EthApiResult,
+        starknet::{
+            relayer::{FeeStrategy, Relayer},
+            signer::RelayerSigner,
+        },
+        BlockProvider,
+    },
 };
 use alloy_eips::BlockNumberOrTag;
 use alloy_primitives::{Address, U256};
+use num_traits::ToPrimitive;
 use rand::{seq::SliceRandom, SeedableRng};
 use reth_chainspec::ChainSpec;
 use reth_execution_types::ChangedAccount;
+use reth_primitives::TransactionSigned;
 use reth_revm::DatabaseRef;
 use reth_transaction_pool::{
-    blobstore::NoopBlobStore, BlockInfo, CanonicalStateUpdate, CoinbaseTipOrdering, EthPooledTransaction, Pool,
-    TransactionOrigin, TransactionPool, TransactionPoolExt,
+    blobstore::NoopBlobStore, AllPoolTransactions, BlockInfo, CanonicalStateUpdate, CoinbaseTipOrdering,
+    EthPooledTransaction, Pool, TransactionListenerKind, TransactionOrigin, TransactionPool, TransactionPoolExt,
+    ValidPoolTransaction,
 };
 use starknet::{
     core::types::{BlockTag, Felt},
-    providers::{jsonrpc::HttpTransport, JsonRpcClient},
+    providers::{jsonrpc::HttpTransport, JsonRpcClient, Provider},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+use tokio::{
+    sync::{
+        mpsc::{self, UnboundedSender},
+        Mutex, Notify, OwnedMutexGuard,
+    },
+    time::Instant,
 };
-use std::{collections::HashMap, sync::Arc, time::Duration};
-use tokio::time::Instant;
 use tracing::instrument;
 
 /// A type alias for the Kakarot Transaction Validator.
@@ -43,136 +78,535 @@ pub type KakarotPool<Client> = Pool<Validator<Client>, TransactionOrdering, Noop
 /// and process transactions for accounts with sufficient balance.
 #[derive(Debug)]
 pub struct AccountManager<SP: starknet::providers::Provider + Send + Sync + Clone + 'static> {
-    /// A collection of account addresses.
-    accounts: Vec<Felt>,
+    /// The relayers managed by the account manager, indexed by address, each behind a lock held
+    /// for the duration of a relay.
+    relayers: RwLock<HashMap<Felt, Arc<Mutex<RelayerState>>>>,
+    /// The source of the relayer keys, reloaded periodically if it supports it.
+    key_source: RelayerKeySource,
+    /// The versions of the relayer keys last loaded from the key source, including the keys
+    /// which failed to load, so that a key is only loaded again once it changes.
+    key_versions: std::sync::Mutex<HashMap<Felt, Option<SystemTime>>>,
+    /// The configuration of the relayers.
+    relayer_config: RelayerConfig,
+    /// Notifies the dispatcher when a relayer is released.
+    relayer_released: Notify,
+    /// The senders which currently have a transaction being relayed.
+    in_flight_senders: std::sync::Mutex<HashSet<Address>>,
+    /// The sender of the relayed transactions to the watcher.
+    relayed_transactions: UnboundedSender<RelayedTransaction>,
+    /// The watcher of the relayed transactions, spawned when the manager starts.
+    watcher: Option<TransactionWatcher<SP>>,
+    /// The Prometheus metrics of the relayers, if enabled.
+    metrics: Option<RelayerMetrics>,
     /// The Ethereum client used to interact with the blockchain.
     eth_client: Arc<EthClient<SP>>,
 }
 
 impl<SP: starknet::providers::Provider + Send + Sync + Clone + 'static> AccountManager<SP> {
-    /// Initialize the account manager with a set of passed accounts.
-    pub const fn new(accounts: Vec<Felt>, eth_client: Arc<EthClient<SP>>) -> Self {
-        Self { accounts, eth_client }
+    /// Initialize the account manager with the relayers of the key source.
+    pub async fn new(
+        key_source: RelayerKeySource,
+        relayer_config: RelayerConfig,
+        eth_client: Arc<EthClient<SP>>,
+    ) -> eyre::Result<Self> {
+        // Decrypting the keys is expensive, do it on a blocking thread
+        let source = key_source.clone();
+        let (key_versions, keys) =
+            tokio::task::spawn_blocking(move || eyre::Ok((source.key_versions()?, source.load_all()?))).await??;
+        let relayers =
+            keys.into_iter().map(|key| (key.address, Arc::new(Mutex::new(RelayerState::new(key))))).collect();
+
+        let (relayed_transactions, receiver) = mpsc::unbounded_channel();
+        let watcher = Some(TransactionWatcher::new(eth_client.clone(), receiver));
+        Ok(Self {
+            relayers: RwLock::new(relayers),
+            key_source,
+            key_versions: std::sync::Mutex::new(key_versions),
+            relayer_config,
+            relayer_released: Notify::new(),
+            in_flight_senders: Default::default(),
+            relayed_transactions,
+            watcher,
+            metrics: None,
+            eth_client,
+        })
+    }
+
+    /// Exports the metrics of the relayers, see [`RelayerMetrics::for_chain`] to share them
+    /// between the chains served by the RPC.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: RelayerMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
     }
 
-    /// Starts the account manager task that periodically checks account balances and processes transactions.
+    /// Starts the account manager task, which relays the best transactions of the pool as soon as
+    /// they become pending, using as many relayers as are available.
     #[instrument(skip_all, name = "mempool")]
-    pub fn start(self) {
+    pub fn start(mut self) {
+        // Start watching the relayed transactions
+        if let Some(watcher) = self.watcher.take() {
+            watcher.start();
+        }
+
         let this = Arc::new(self);
 
+        // Monitor the balances and nonces of the relayers
+        let manager = this.clone();
         tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RELAYER_MONITOR_INTERVAL);
             loop {
-                // TODO: add a listener on the pool and only try to call [`best_transaction`]
-                // TODO: when we are sure there is a transaction in the pool. This avoids an
-                // TODO: constant loop which rarely yields to the executor combined with a
-                // TODO: sleep which could sleep for a while before handling transactions.
-                let best_hashes =
-                    this.eth_client.mempool().as_ref().best_transactions().map(|x| *x.hash()).collect::<Vec<_>>();
-                if let Some(best_hash) = best_hashes.first() {
-                    let transaction = this.eth_client.mempool().get(best_hash);
-                    if transaction.is_none() {
-                        // Probably a race condition here
-                        continue;
+                interval.tick().await;
+                manager.monitor_relayers().await;
+            }
+        });
+
+        // Add and remove relayers as their keys change
+        if this.key_source.is_reloadable() {
+            let manager = this.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(KEYSTORE_RELOAD_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    if let Err(err) = manager.reload_relayers().await {
+                        tracing::error!(target: "account_manager", ?err, "failed to reload relayers");
                     }
-                    let transaction = transaction.expect("not None");
-
-                    // We remove the transaction to avoid another relayer from picking it up.
-                    this.eth_client.mempool().as_ref().remove_transactions(vec![*best_hash]);
-
-                    // Spawn a task for the transaction to be sent
-                    let manager = this.clone();
-                    tokio::spawn(async move {
-                        // Lock the relayer account
-                        let hash = transaction.hash();
-                        let maybe_relayer = manager.get_relayer().await;
-                        if maybe_relayer.is_err() {
-                            // If we fail to fetch a relayer, we need to re-insert the transaction in the pool
-                            tracing::error!(target: "account_manager", err = ?maybe_relayer.unwrap_err(), ?hash, "failed to fetch relayer");
-                            let _ = manager
-                                .eth_client
-                                .mempool()
-                                .add_transaction(TransactionOrigin::Local, transaction.transaction.clone())
-                                .await;
-                            return;
-                        }
-                        let relayer = maybe_relayer.expect("not error");
-
-                        // Send the Ethereum transaction using the relayer
-                        let transaction_signed = transaction.to_recovered_transaction().into_signed();
-
-                        let res = relayer.relay_transaction(&transaction_signed).await;
-                        if res.is_err() {
-                            // If the relayer failed to relay the transaction, we need to reposition it in the mempool
-                            tracing::error!(target: "account_manager", err = ?res.unwrap_err(), ?hash, "failed to relay transaction");
-                            let _ = manager
-                                .eth_client
-                                .mempool()
-                                .add_transaction(TransactionOrigin::Local, transaction.transaction.clone())
-                                .await;
+                }
+            });
+        }
+
+        tokio::spawn(async move {
+            let mut pending_transactions =
+                this.eth_client.mempool().pending_transactions_listener_for(TransactionListenerKind::All);
+            // Periodically dispatch in order to pick up transactions which could not be relayed
+            // earlier, e.g. because no relayer was funded at the time.
+            let mut fallback = tokio::time::interval(DISPATCH_FALLBACK_INTERVAL);
+
+            loop {
+                // Wait for a new pending transaction or a relayer to be released
+                tokio::select! {
+                    hash = pending_transactions.recv() => {
+                        if hash.is_none() {
+                            tracing::error!(target: "account_manager", "pending transactions listener closed");
                             return;
                         }
-
-                        tracing::info!(target: "account_manager", starknet_hash = ?res.expect("not error"), ethereum_hash = ?transaction_signed.hash());
-                    });
+                    }
+                    () = this.relayer_released.notified() => {}
+                    _ = fallback.tick() => {}
                 }
 
-                tokio::time::sleep(Duration::from_secs(1)).await;
+                this.clone().dispatch().await;
             }
         });
     }
 
-    /// Returns the next available account from the manager.
-    pub async fn get_relayer(&self) -> eyre::Result<Relayer<JsonRpcClient<HttpTransport>>>
+    /// Relays the best transactions of the pool, as long as funded relayers are available.
+    async fn dispatch(self: Arc<Self>) {
+        let best_transactions = self.eth_client.mempool().best_transactions();
+        self.dispatch_transactions(best_transactions).await;
+    }
+
+    /// Relays the transactions in order, as long as funded relayers are available.
+    ///
+    /// At most one transaction per sender is relayed at a time, so that the transactions of a
+    /// sender are relayed in nonce order.
+    async fn dispatch_transactions<I>(self: Arc<Self>, transactions: I)
     where
-        SP: starknet::providers::Provider + Send + Sync + Clone + 'static,
+        I: IntoIterator<Item = Arc<ValidPoolTransaction<EthPooledTransaction>>>,
     {
+        let mempool = self.eth_client.mempool();
+
+        for transaction in transactions {
+            // Skip senders with a transaction being relayed, their next transaction will be
+            // dispatched once the current one is relayed.
+            let sender = transaction.sender();
+            if !self.in_flight_senders.lock().expect("in flight senders lock poisoned").insert(sender) {
+                continue;
+            }
+
+            let relayer = match self.get_relayer().await {
+                Ok(relayer) => relayer,
+                Err(err) => {
+                    // No relayer is available, the remaining transactions wait for the next dispatch
+                    tracing::warn!(target: "account_manager", ?err, "failed to fetch relayer");
+                    self.release_sender(&sender);
+                    return;
+                }
+            };
+
+            // We remove the transaction to avoid another dispatch from picking it up. The journal
+            // keeps it until its relay is final.
+            mempool.remove_transactions(vec![*transaction.hash()]);
+            if let Some(journal) = self.eth_client.journal() {
+                journal.add_in_flight(transaction.to_recovered_transaction().into_signed());
+            }
+
+            // Spawn a task for the transaction to be sent
+            if let Some(metrics) = &self.metrics {
+                metrics.relay_started();
+            }
+            let manager = self.clone();
+            tokio::spawn(async move { manager.relay(relayer, transaction).await });
+        }
+    }
+
+    /// Relays the transaction with the leased relayer, re-inserting it in the pool on failure.
+    ///
+    /// Successfully relayed transactions are handed over to the [`TransactionWatcher`].
+    async fn relay(&self, relayer: LeasedRelayer, transaction: Arc<ValidPoolTransaction<EthPooledTransaction>>) {
+        let hash = transaction.hash();
+        let relayer_address = relayer.address();
+
+        // Send the Ethereum transaction using the relayer
+        let transaction_signed = transaction.to_recovered_transaction().into_signed();
+        let res = relayer.relay_transaction(&transaction_signed).await;
+        if let Some(metrics) = &self.metrics {
+            metrics.relay_finished(relayer_address, res.is_err());
+        }
+
+        match res {
+            Ok(starknet_hash) => {
+                tracing::info!(target: "account_manager", ?starknet_hash, ethereum_hash = ?hash);
+                let _ = self.relayed_transactions.send(RelayedTransaction::new(transaction.clone(), starknet_hash));
+            }
+            Err(EthApiError::Transaction(TransactionError::Simulation(err))) if err.is_doomed() => {
+                // The transaction would fail whichever relayer relays it, it is dropped from the pool
+                tracing::warn!(target: "account_manager", %err, ?hash, "rejecting transaction");
+                let status = RelayedTransactionStatus {
+                    eth_hash: *hash,
+                    starknet_hash: Felt::ZERO,
+                    status: RelayStatus::Rejected,
+                    reason: Some(err.to_string()),
+                    retries: 0,
+                };
+                if let Err(err) = self.eth_client.eth_provider().database().upsert_transaction_status(status).await {
+                    tracing::error!(target: "account_manager", ?err, ?hash, "failed to record transaction status");
+                }
+                if let Some(journal) = self.eth_client.journal() {
+                    journal.remove_in_flight(hash);
+                }
+            }
+            Err(err) => {
+                // If the relayer failed to relay the transaction, we need to reposition it in the mempool
+                tracing::error!(target: "account_manager", ?err, ?hash, "failed to relay transaction");
+                let _ = self
+                    .eth_client
+                    .mempool()
+                    .add_transaction(TransactionOrigin::Local, transaction.transaction.clone())
+                    .await;
+                if let Some(journal) = self.eth_client.journal() {
+                    journal.remove_in_flight(hash);
+                }
+            }
+        }
+
+        // The relayer lease was released by the relay, wake up the dispatcher.
+        self.release_sender(&transaction.sender());
+        self.relayer_released.notify_one();
+    }
+
+    /// Refreshes the balances and nonces of the relayers from the chain, exports them as metrics,
+    /// and reports to the [`EthClient`] whether any relayer can pay for new transactions.
+    ///
+    /// The relayers whose balance can't be fetched keep their previous state, see
+    /// [`RelayerFunds::update`](super::monitor::RelayerFunds::update).
+    pub async fn monitor_relayers(&self) {
+        let relayers: Vec<_> = self
+            .relayers
+            .read()
+            .expect("relayers lock poisoned")
+            .iter()
+            .map(|(address, state)| (*address, state.clone()))
+            .collect();
+
+        let min_balance = self.min_balance();
+        let (mut funded_relayers, mut unread_relayers) = (0, 0);
+        for (address, state) in relayers {
+            let balance = match self.get_balance(address).await {
+                Ok(balance) => balance,
+                Err(err) => {
+                    tracing::warn!(target: "account_manager", ?err, ?address, "failed to fetch relayer balance");
+                    unread_relayers += 1;
+                    continue;
+                }
+            };
+            let nonce = self.get_nonce(address).await.ok();
+
+            if balance >= min_balance {
+                funded_relayers += 1;
+            } else {
+                tracing::warn!(target: "account_manager", ?address, %balance, "relayer balance too low to relay transactions");
+            }
+
+            if let Some(metrics) = &self.metrics {
+                metrics.set_balance(address, balance.saturating_to());
+                if let Some(nonce) = nonce.as_ref().and_then(ToPrimitive::to_u64) {
+                    metrics.set_nonce(address, nonce);
+                }
+            }
+
+            // Leased relayers keep their locally tracked state, which is ahead of the chain
+            if let Ok(mut state) = state.try_lock() {
+                state.set_balance(balance);
+                state.nonce = state.nonce.or(nonce);
+            }
+        }
+
+        if funded_relayers == 0 && unread_relayers == 0 {
+            tracing::error!(target: "account_manager", "no relayer has enough funds to relay transactions");
+        }
+        self.eth_client.relayer_funds().update(funded_relayers, unread_relayers);
+        if let Some(metrics) = &self.metrics {
+            metrics.set_funded_relayers(funded_relayers);
+        }
+    }
+
+    /// Reloads the relayers from the key source, adding the new relayers, replacing the ones whose
+    /// key changed and removing the ones which are no longer present.
+    ///
+    /// Keys which fail to load are only loaded again once they change. Removed or replaced relayers
+    /// which are currently leased finish their relay before being dropped.
+    async fn reload_relayers(&self) -> eyre::Result<()> {
+        // Listing and decrypting the keys is blocking, do it on a blocking thread
+        let key_source = self.key_source.clone();
+        let key_versions = self.key_versions.lock().expect("key versions lock poisoned").clone();
+        let (versions, loaded_keys) = tokio::task::spawn_blocking(move || {
+            let versions = key_source.key_versions()?;
+            let loaded_keys = versions
+                .iter()
+                .filter(|(address, version)| key_versions.get(*address) != Some(*version))
+                .map(|(address, _)| (*address, key_source.load(*address)))
+                .collect::<Vec<_>>();
+            eyre::Ok((versions, loaded_keys))
+        })
+        .await??;
+
+        let mut relayers = self.relayers.write().expect("relayers lock poisoned");
+        relayers.retain(|address, _| {
+            let keep = versions.contains_key(address);
+            if !keep {
+                tracing::info!(target: "account_manager", ?address, "removing relayer");
+                if let Some(metrics) = &self.metrics {
+                    metrics.remove_relayer(*address);
+                }
+            }
+            keep
+        });
+        for (address, key) in loaded_keys {
+            match key {
+                Ok(key) => {
+                    let action = if relayers.contains_key(&address) { "replacing" } else { "adding" };
+                    tracing::info!(target: "account_manager", ?address, "{action} relayer");
+                    relayers.insert(address, Arc::new(Mutex::new(RelayerState::new(key))));
+                }
+                Err(err) => {
+                    tracing::error!(target: "account_manager", ?err, ?address, "failed to load relayer");
+                }
+            }
+        }
+        *self.key_versions.lock().expect("key versions lock poisoned") = versions;
+
+        Ok(())
+    }
+
+    /// Allows the next transaction of the sender to be dispatched.
+    fn release_sender(&self, sender: &Address) {
+        self.in_flight_senders.lock().expect("in flight senders lock poisoned").remove(sender);
+    }
+
+    /// Leases the next available funded relayer from the manager.
+    ///
+    /// Relayers currently leased by another task are skipped. The relayer stays leased until the
+    /// returned [`LeasedRelayer`] is dropped.
+    pub async fn get_relayer(&self) -> eyre::Result<LeasedRelayer> {
         // Use `StdRng` instead of `ThreadRng` as it is `Send`
         let mut rng = rand::rngs::StdRng::from_entropy();
 
-        // Shuffle indices of accounts randomly
-        let mut account_indices: Vec<_> = (0..self.accounts.len()).collect();
-        account_indices.shuffle(&mut rng);
+        // Shuffle the accounts randomly
+        let mut relayers: Vec<_> = self.relayers.read().expect("relayers lock poisoned").values().cloned().collect();
+        relayers.shuffle(&mut rng);
 
-        for index in account_indices {
-            let account_address = self.accounts[index];
+        for relayer in relayers {
+            // Skip relayers which are already leased
+            let Ok(mut state) = relayer.try_lock_owned() else {
+                continue;
+            };
 
-            // Retrieve the balance of the selected account
-            let balance = self.get_balance(account_address).await?;
+            // Retrieve the balance of the selected account, from the cache if it is still fresh
+            let balance = match state.balance() {
+                Some(balance) => balance,
+                None => match self.get_balance(state.address).await {
+                    Ok(balance) => {
+                        state.set_balance(balance);
+                        balance
+                    }
+                    Err(err) => {
+                        tracing::warn!(target: "account_manager", ?err, address = ?state.address, "failed to fetch relayer balance");
+                        continue;
+                    }
+                },
+            };
 
             // Skip accounts with insufficient balance
-            if balance < U256::from(ONE_TENTH_ETH) {
+            if balance < self.min_balance() {
                 continue;
             }
 
+            // Retrieve the nonce of the account from the chain if it is not tracked yet
+            let nonce = match state.nonce {
+                Some(nonce) => nonce,
+                None => match self.get_nonce(state.address).await {
+                    Ok(nonce) => {
+                        state.nonce = Some(nonce);
+                        nonce
+                    }
+                    Err(err) => {
+                        tracing::warn!(target: "account_manager", ?err, address = ?state.address, "failed to fetch relayer nonce");
+                        continue;
+                    }
+                },
+            };
+
             // Convert the balance to `Felt`
             let balance = into_via_try_wrapper!(balance)?;
 
             // Construct the `Relayer` with the account address and other relevant data
-            let account = Relayer::new(
-                account_address,
+            let deployment = self.eth_client.eth_provider().deployment();
+            let relayer = Relayer::new(
+                state.address,
                 balance,
-                JsonRpcClient::new(HttpTransport::new(KAKAROT_RPC_CONFIG.network_url.clone())),
+                JsonRpcClient::new(HttpTransport::new(deployment.network_url.clone())),
+                state.signer.clone(),
+                deployment.clone(),
                 Some(Arc::new(self.eth_client.eth_provider().database().clone())),
-            );
+            )
+            .with_nonce(nonce)
+            .with_preflight_simulation(self.relayer_config.preflight_simulation)
+            .with_fee_strategy(self.relayer_config.fee_strategy.clone())
+            .with_max_felts_in_calldata(self.eth_client.eth_provider().limits().max_felts_in_calldata);
 
-            // Return the locked relayer instance
-            return Ok(account);
+            // Return the leased relayer instance
+            return Ok(LeasedRelayer { relayer, state });
         }
 
         Err(eyre::eyre!("failed to fetch funded account"))
     }
 
-    /// Retrieves the balance of the specified account address for the [`BlockTag::Pending`]
+    /// Returns the minimum balance of a relayer to relay transactions, in the token used to pay the
+    /// fees of the relayed transactions.
+    fn min_balance(&self) -> U256 {
+        match self.relayer_config.fee_strategy {
+            FeeStrategy::V1 { .. } => U256::from(ONE_TENTH_ETH),
+            FeeStrategy::V3 { .. } => U256::from(TEN_STRK),
+        }
+    }
+
+    /// Retrieves the balance of the specified account address for the [`BlockTag::Pending`], in
+    /// the token used to pay the fees of the relayed transactions.
     async fn get_balance(&self, account_address: Felt) -> eyre::Result<U256> {
         // Get the balance of the address for the Pending block.
         self.eth_client
             .starknet_provider()
-            .balance_at(account_address, starknet::core::types::BlockId::Tag(BlockTag::Pending))
+            .token_balance_at(
+                self.relayer_config.fee_strategy.fee_token(),
+                account_address,
+                starknet::core::types::BlockId::Tag(BlockTag::Pending),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Retrieves the nonce of the specified account address for the [`BlockTag::Pending`]
+    async fn get_nonce(&self, account_address: Felt) -> eyre::Result<Felt> {
+        self.eth_client
+            .eth_provider()
+            .starknet_provider_inner()
+            .get_nonce(starknet::core::types::BlockId::Tag(BlockTag::Pending), account_address)
             .await
             .map_err(Into::into)
     }
 }
 
+/// The locally tracked state of a relayer account.
+#[derive(Debug)]
+struct RelayerState {
+    /// The address of the relayer account.
+    address: Felt,
+    /// The signer of the relayer transactions.
+    signer: RelayerSigner,
+    /// The next nonce of the relayer, `None` if it needs to be fetched from the chain.
+    nonce: Option<Felt>,
+    /// The last fetched balance of the relayer and the instant it was fetched at.
+    balance: Option<(U256, Instant)>,
+}
+
+impl RelayerState {
+    fn new(key: RelayerKey) -> Self {
+        Self { address: key.address, signer: key.signer, nonce: None, balance: None }
+    }
+
+    /// Returns the cached balance of the relayer if it is still fresh.
+    fn balance(&self) -> Option<U256> {
+        self.balance.filter(|(_, fetched_at)| fetched_at.elapsed() < RELAYER_BALANCE_CACHE_DURATION).map(|(b, _)| b)
+    }
+
+    fn set_balance(&mut self, balance: U256) {
+        self.balance = Some((balance, Instant::now()));
+    }
+
+    /// Deducts the fee of a relayed transaction from the cached balance, keeping the instant it
+    /// was fetched at so that it is refreshed on schedule.
+    fn deduct_fee(&mut self, fee: Felt) {
+        let fee = U256::from_be_bytes(fee.to_bytes_be());
+        if let Some((balance, _)) = &mut self.balance {
+            *balance = balance.saturating_sub(fee);
+        }
+    }
+
+    /// Drops the locally tracked nonce and balance, forcing a resync from the chain.
+    fn invalidate(&mut self) {
+        self.nonce = None;
+        self.balance = None;
+    }
+}
+
+/// A relayer exclusively leased from the [`AccountManager`].
+///
+/// The relayer is released back to the manager when dropped.
+#[derive(Debug)]
+pub struct LeasedRelayer {
+    /// The relayer used to relay the transaction.
+    relayer: Relayer<JsonRpcClient<HttpTransport>>,
+    /// The lock over the relayer state, held for the duration of the lease.
+    state: OwnedMutexGuard<RelayerState>,
+}
+
+impl LeasedRelayer {
+    /// Returns the address of the leased relayer.
+    pub fn address(&self) -> Felt {
+        self.relayer.address()
+    }
+
+    /// Relays the transaction using the leased relayer, consuming the lease.
+    ///
+    /// On success, the locally tracked nonce is incremented and the max fee of the transaction is
+    /// deducted from the cached balance. On failure, the relayer's state is resynced from the chain
+    /// on its next lease.
+    pub async fn relay_transaction(mut self, transaction: &TransactionSigned) -> EthApiResult<Felt> {
+        match self.relayer.relay_transaction_with_max_fee(transaction).await {
+            Ok((transaction_hash, max_fee)) => {
+                self.state.nonce = self.state.nonce.map(|nonce| nonce + Felt::ONE);
+                self.state.deduct_fee(max_fee);
+                Ok(transaction_hash)
+            }
+            Err(err) => {
+                self.state.invalidate();
+                Err(err)
+            }
+        }
+    }
+}
+
 #[derive(Default)]
 struct LoadedAccounts {
     /// All accounts that were loaded
@@ -252,7 +686,7 @@ where
 
                         // Update the block information in the pool
                         let chain_spec = ChainSpec {
-                            chain: eth_client.eth_provider().chain_id.into(),
+                            chain: eth_client.eth_provider().deployment().chain_id.into(),
                             max_gas_limit: KKRT_BLOCK_GAS_LIMIT,
                             ..Default::default()
                         };
@@ -320,6 +754,17 @@ where
                         };
                         eth_client.mempool().on_canonical_state_change(update);
 
+                        // Compact the journal, dropping the mined and pruned transactions. The
+                        // transactions in flight are kept by the journal.
+                        if let Some(journal) = eth_client.journal() {
+                            let AllPoolTransactions { pending, queued } = eth_client.mempool().all_transactions();
+                            let transactions =
+                                pending.iter().chain(&queued).map(|tx| tx.to_recovered_transaction().into_signed());
+                            if let Err(err) = journal.rotate(transactions.collect::<Vec<_>>()).await {
+                                tracing::error!(target: "maintain_transaction_pool", ?err, "failed to compact journal");
+                            }
+                        }
+
                         block_number = current_block_number.to();
                     } else {
                         tracing::error!(target: "maintain_transaction_pool", "failed to convert block");
@@ -332,3 +777,275 @@ where
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{LimitsConfig, SpamProtectionConfig},
+        providers::eth_provider::starknet::kakarot_core::KakarotDeployment,
+        test_utils::{
+            mock_starknet::{eth_send_transaction_invocation, fee_estimate, simulated_transaction, MockStarknet},
+            mongo::{MongoFuzzer, RANDOM_BYTES_SIZE},
+        },
+    };
+    use alloy_consensus::TxEip1559;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{TxKind, B256};
+    use reth_primitives::{sign_message, Transaction, TransactionSignedEcRecovered};
+    use reth_transaction_pool::{
+        identifier::{SenderId, TransactionId},
+        PoolConfig,
+    };
+    use serde_json::json;
+    use starknet::signers::{LocalWallet, Signer, SigningKey};
+
+    /// Returns an account manager relaying to the mocked Starknet node with funded relayers,
+    /// simulating the transactions before relaying them.
+    async fn mock_account_manager(
+        starknet: &mut MockStarknet,
+        relayer_count: u64,
+    ) -> AccountManager<JsonRpcClient<HttpTransport>> {
+        let keys = (0..relayer_count)
+            .map(|i| RelayerKey {
+                address: Felt::from(0x1234 + i),
+                signer: LocalWallet::from(SigningKey::from_random()).into(),
+            })
+            .collect();
+        mock_account_manager_with_keys(starknet, RelayerKeySource::Static(keys)).await
+    }
+
+    /// Returns an account manager relaying to the mocked Starknet node with the funded relayers
+    /// of the key source, simulating the transactions before relaying them.
+    async fn mock_account_manager_with_keys(
+        starknet: &mut MockStarknet,
+        key_source: RelayerKeySource,
+    ) -> AccountManager<JsonRpcClient<HttpTransport>> {
+        let mut mongo_fuzzer = MongoFuzzer::new(RANDOM_BYTES_SIZE).await;
+        let database = mongo_fuzzer.mock_database(1).await;
+        let deployment = KakarotDeployment::new(starknet.url(), Felt::ONE, Felt::TWO, Felt::from(0x4b4b_5254_u64));
+        let eth_client = Arc::new(EthClient::new(
+            starknet.provider(),
+            deployment,
+            LimitsConfig::default(),
+            PoolConfig::default(),
+            SpamProtectionConfig::default(),
+            database,
+        ));
+
+        // The relayers hold 1 ETH and have not sent any transaction
+        starknet.mock_result("starknet_call", json!(["0xde0b6b3a7640000", "0x0"])).await;
+        starknet.mock_result("starknet_getNonce", json!("0x0")).await;
+
+        let relayer_config = RelayerConfig { preflight_simulation: true, ..Default::default() };
+        AccountManager::new(key_source, relayer_config, eth_client).await.expect("Failed to create account manager")
+    }
+
+    /// Returns a pool transaction signed by a random key.
+    fn pool_transaction() -> Arc<ValidPoolTransaction<EthPooledTransaction>> {
+        pool_transaction_with_nonce(B256::random(), 0)
+    }
+
+    /// Returns a pool transaction signed by the key, with the nonce.
+    fn pool_transaction_with_nonce(private_key: B256, nonce: u64) -> Arc<ValidPoolTransaction<EthPooledTransaction>> {
+        let transaction = Transaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce,
+            gas_limit: 21000,
+            to: TxKind::Call(Address::random()),
+            value: U256::from(1000),
+            max_fee_per_gas: 875_000_000,
+            ..Default::default()
+        });
+        let signature = sign_message(private_key, transaction.signature_hash()).expect("Failed to sign transaction");
+        let transaction_signed = TransactionSigned::from_transaction_and_signature(transaction, signature);
+        let sender = transaction_signed.recover_signer().expect("Failed to recover signer");
+        let recovered = TransactionSignedEcRecovered::from_signed_transaction(transaction_signed, sender);
+        let encoded_length = recovered.encode_2718_len();
+
+        Arc::new(ValidPoolTransaction {
+            transaction: EthPooledTransaction::new(recovered, encoded_length),
+            transaction_id: TransactionId::new(SenderId::from(0), nonce),
+            propagate: true,
+            timestamp: std::time::Instant::now(),
+            origin: TransactionOrigin::External,
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_relay_rejects_doomed_transaction() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let manager = mock_account_manager(&mut starknet, 1).await;
+        let execution_error = json!({ "transaction_index": 0, "execution_error": "Kakarot: entrypoint failed" });
+        starknet.mock_error("starknet_estimateFee", 41, "Transaction execution error", Some(execution_error)).await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+        let relayer = manager.get_relayer().await.expect("Failed to get relayer");
+        let transaction = pool_transaction();
+
+        // When
+        manager.relay(relayer, transaction.clone()).await;
+
+        // Then
+        // The transaction is recorded as rejected and is not re-queued
+        let status = manager
+            .eth_client
+            .eth_provider()
+            .database()
+            .transaction_status(transaction.hash())
+            .await
+            .expect("Failed to get transaction status")
+            .expect("Missing transaction status");
+        assert_eq!(status.status, RelayStatus::Rejected);
+        assert!(status.reason.is_some_and(|reason| reason.contains("Kakarot: entrypoint failed")));
+        assert!(!manager.eth_client.mempool().contains(transaction.hash()));
+        assert!(manager.in_flight_senders.lock().unwrap().is_empty());
+        send.assert_async().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_relay_requeues_transaction_on_fee_error() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let manager = mock_account_manager(&mut starknet, 1).await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate(1_000)])).await;
+        starknet
+            .mock_error(
+                "starknet_simulateTransactions",
+                53,
+                "Max fee is smaller than the minimal transaction cost",
+                None,
+            )
+            .await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+        let relayer = manager.get_relayer().await.expect("Failed to get relayer");
+        let transaction = pool_transaction();
+
+        // When
+        manager.relay(relayer, transaction.clone()).await;
+
+        // Then
+        // The fee depends on the relayer, the transaction is not rejected
+        let status = manager
+            .eth_client
+            .eth_provider()
+            .database()
+            .transaction_status(transaction.hash())
+            .await
+            .expect("Failed to get transaction status");
+        assert!(status.is_none());
+        send.assert_async().await;
+    }
+
+    /// Returns the public key of the signer of the next leased relayer.
+    async fn leased_public_key(manager: &AccountManager<JsonRpcClient<HttpTransport>>) -> Felt {
+        let relayer = manager.get_relayer().await.expect("Failed to get relayer");
+        relayer.state.signer.get_public_key().await.expect("Failed to get public key").scalar()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_reload_relayers_picks_up_new_key_versions() {
+        // Given
+        let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let path = dir.path().join("0x1234.json");
+        let first_key = SigningKey::from_random();
+        first_key.save_as_keystore(&path, "password").expect("Failed to save keystore");
+        let key_source = RelayerKeySource::Keystore { path: dir.path().to_path_buf(), password: "password".into() };
+        let mut starknet = MockStarknet::new().await;
+        let manager = mock_account_manager_with_keys(&mut starknet, key_source).await;
+        assert_eq!(leased_public_key(&manager).await, first_key.verifying_key().scalar());
+
+        // When
+        // A second version of the key is written, and a key encrypted with another password is added
+        let second_key = SigningKey::from_random();
+        second_key.save_as_keystore(&path, "password").expect("Failed to save keystore");
+        let modified = SystemTime::now() + Duration::from_secs(60);
+        std::fs::File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+        SigningKey::from_random()
+            .save_as_keystore(dir.path().join("0x5678.json"), "other password")
+            .expect("Failed to save keystore");
+        manager.reload_relayers().await.expect("Failed to reload relayers");
+
+        // Then
+        // The relayer signs with the new version of its key, the key which fails to load is left
+        // out and is only loaded again once it changes
+        let addresses: Vec<_> = manager.relayers.read().unwrap().keys().copied().collect();
+        assert_eq!(addresses, vec![Felt::from(0x1234)]);
+        assert_eq!(leased_public_key(&manager).await, second_key.verifying_key().scalar());
+        assert!(manager.key_versions.lock().unwrap().contains_key(&Felt::from(0x5678)));
+
+        // When
+        // The key which failed to load is encrypted with the right password
+        SigningKey::from_random()
+            .save_as_keystore(dir.path().join("0x5678.json"), "password")
+            .expect("Failed to save keystore");
+        std::fs::File::options()
+            .write(true)
+            .open(dir.path().join("0x5678.json"))
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        std::fs::remove_file(&path).expect("Failed to remove keystore");
+        manager.reload_relayers().await.expect("Failed to reload relayers");
+
+        // Then
+        // The removed relayer is dropped and the fixed one is added
+        let addresses: Vec<_> = manager.relayers.read().unwrap().keys().copied().collect();
+        assert_eq!(addresses, vec![Felt::from(0x5678)]);
+    }
+
+    /// Returns the hash of the next relayed transaction.
+    async fn next_relayed(relayed: &mut mpsc::UnboundedReceiver<RelayedTransaction>) -> B256 {
+        let next = tokio::time::timeout(Duration::from_secs(10), relayed.recv()).await;
+        *next.expect("No transaction relayed").expect("Relayed transactions channel closed").transaction.hash()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_dispatch_relays_one_transaction_per_sender_at_a_time() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let mut manager = mock_account_manager(&mut starknet, 2).await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate(1_000)])).await;
+        let success = eth_send_transaction_invocation(&[Felt::ZERO, Felt::ONE, Felt::from(21000)]);
+        starknet.mock_result("starknet_simulateTransactions", json!([simulated_transaction(success, 1_000)])).await;
+        let send = starknet
+            .mock_result("starknet_addInvokeTransaction", json!({ "transaction_hash": Felt::from(0xabc_u64) }))
+            .await
+            .expect(3);
+        let (relayed_transactions, mut relayed) = mpsc::unbounded_channel();
+        manager.relayed_transactions = relayed_transactions;
+        let manager = Arc::new(manager);
+
+        // The first sender has two transactions, the second sender has one
+        let private_key = B256::random();
+        let first = pool_transaction_with_nonce(private_key, 0);
+        let second = pool_transaction_with_nonce(private_key, 1);
+        let other = pool_transaction();
+
+        // When
+        manager.clone().dispatch_transactions([first.clone(), second.clone(), other.clone()]).await;
+
+        // Then
+        // The first transactions of both senders are relayed in parallel, the second transaction
+        // of the first sender waits for the first one to be relayed
+        let relayed_hashes: HashSet<_> =
+            [next_relayed(&mut relayed).await, next_relayed(&mut relayed).await].into_iter().collect();
+        assert_eq!(relayed_hashes, HashSet::from([*first.hash(), *other.hash()]));
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !manager.in_flight_senders.lock().unwrap().is_empty() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Senders not released");
+        assert!(relayed.try_recv().is_err());
+
+        // When
+        manager.clone().dispatch_transactions([second.clone()]).await;
+
+        // Then
+        // The second transaction of the first sender is relayed once the first one is
+        assert_eq!(next_relayed(&mut relayed).await, *second.hash());
+        send.assert_async().await;
+    }
+}
@@ -1,3 +1,8 @@
 pub mod constants;
+pub mod journal;
+pub mod keystore;
 pub mod mempool;
+pub mod monitor;
+pub mod spam;
 pub mod validate;
+pub mod watcher;
@@ -0,0 +1,236 @@
+use crate::{
+    config::DEFAULT_CHAIN_NAME,
+    prometheus_handler::{register, CounterVec, GaugeVec, Opts, PrometheusError, Registry, F64, I64, U64},
+};
+use starknet::core::types::Felt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the relayers can pay for new transactions, shared between the relayers monitor and
+/// the [`EthClient`](crate::client::EthClient).
+///
+/// Relayers are assumed funded until the monitor reports otherwise.
+#[derive(Debug)]
+pub struct RelayerFunds {
+    /// Whether at least one relayer has enough funds to relay a transaction.
+    funded: AtomicBool,
+}
+
+impl Default for RelayerFunds {
+    fn default() -> Self {
+        Self { funded: AtomicBool::new(true) }
+    }
+}
+
+impl RelayerFunds {
+    /// Returns `true` if at least one relayer has enough funds to relay a transaction.
+    pub fn is_funded(&self) -> bool {
+        self.funded.load(Ordering::Relaxed)
+    }
+
+    /// Sets whether at least one relayer has enough funds to relay a transaction.
+    pub fn set_funded(&self, funded: bool) {
+        self.funded.store(funded, Ordering::Relaxed);
+    }
+
+    /// Updates the funds from a round of the relayers monitor, given the number of funded
+    /// relayers and the number of relayers whose balance couldn't be read.
+    ///
+    /// The relayers are reported unfunded only if all balances were read, otherwise a failure to
+    /// reach the Starknet provider would reject all the transactions until the next round.
+    pub fn update(&self, funded_relayers: usize, unread_relayers: usize) {
+        if funded_relayers > 0 {
+            self.set_funded(true);
+        } else if unread_relayers == 0 {
+            self.set_funded(false);
+        }
+    }
+}
+
+/// Prometheus metrics of the relayers.
+///
+/// The metrics are registered once and shared by the chains served by the RPC, each chain
+/// recording its metrics under its `chain` label, see [`RelayerMetrics::for_chain`].
+#[derive(Debug, Clone)]
+pub struct RelayerMetrics {
+    /// The name of the chain of the relayers.
+    chain: String,
+    /// Balance of each relayer, in the fee token.
+    balance: GaugeVec<F64>,
+    /// Nonce of each relayer on the pending block.
+    nonce: GaugeVec<U64>,
+    /// Number of relayers with enough funds to relay a transaction.
+    funded_relayers: GaugeVec<I64>,
+    /// Number of transactions being relayed.
+    in_flight: GaugeVec<I64>,
+    /// Number of failed relays of each relayer.
+    failures: CounterVec<U64>,
+}
+
+impl RelayerMetrics {
+    /// Creates the relayer metrics and registers them in the registry. The metrics are recorded
+    /// for the default chain.
+    pub fn new(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            chain: DEFAULT_CHAIN_NAME.to_string(),
+            balance: register(
+                GaugeVec::new(
+                    Opts::new("relayer_balance", "Balance of the relayer in the fee token"),
+                    &["chain", "relayer"],
+                )?,
+                registry,
+            )?,
+            nonce: register(
+                GaugeVec::new(
+                    Opts::new("relayer_nonce", "Nonce of the relayer on the pending block"),
+                    &["chain", "relayer"],
+                )?,
+                registry,
+            )?,
+            funded_relayers: register(
+                GaugeVec::new(
+                    Opts::new("relayer_funded_count", "Number of relayers with enough funds to relay a transaction"),
+                    &["chain"],
+                )?,
+                registry,
+            )?,
+            in_flight: register(
+                GaugeVec::new(Opts::new("relayer_in_flight", "Number of transactions being relayed"), &["chain"])?,
+                registry,
+            )?,
+            failures: register(
+                CounterVec::new(Opts::new("relayer_failures", "Number of failed relays"), &["chain", "relayer"])?,
+                registry,
+            )?,
+        })
+    }
+
+    /// Returns the metrics recorded for the given chain, sharing the registered metrics.
+    #[must_use]
+    pub fn for_chain(&self, chain: &str) -> Self {
+        Self { chain: chain.to_string(), ..self.clone() }
+    }
+
+    /// Records the balance of the relayer, in the smallest unit of the fee token.
+    pub fn set_balance(&self, relayer: Felt, balance: u128) {
+        self.balance.with_label_values(&[&self.chain, &format!("{relayer:#x}")]).set(balance as f64 / 1e18);
+    }
+
+    /// Records the nonce of the relayer.
+    pub fn set_nonce(&self, relayer: Felt, nonce: u64) {
+        self.nonce.with_label_values(&[&self.chain, &format!("{relayer:#x}")]).set(nonce);
+    }
+
+    /// Records the number of funded relayers.
+    pub fn set_funded_relayers(&self, count: usize) {
+        self.funded_relayers.with_label_values(&[&self.chain]).set(i64::try_from(count).unwrap_or(i64::MAX));
+    }
+
+    /// Records the start of a relay.
+    pub fn relay_started(&self) {
+        self.in_flight.with_label_values(&[&self.chain]).inc();
+    }
+
+    /// Records the end of a relay, and its failure if it failed.
+    pub fn relay_finished(&self, relayer: Felt, failed: bool) {
+        self.in_flight.with_label_values(&[&self.chain]).dec();
+        if failed {
+            self.failures.with_label_values(&[&self.chain, &format!("{relayer:#x}")]).inc();
+        }
+    }
+
+    /// Removes the metrics of a relayer which is no longer managed.
+    pub fn remove_relayer(&self, relayer: Felt) {
+        let labels = [self.chain.as_str(), &format!("{relayer:#x}")];
+        let _ = self.balance.remove_label_values(&labels);
+        let _ = self.nonce.remove_label_values(&labels);
+        let _ = self.failures.remove_label_values(&labels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the value of the metric with the given name and chain and relayer labels, if any.
+    fn metric_value(registry: &Registry, name: &str, chain: &str, relayer: Option<Felt>) -> Option<f64> {
+        let family = registry.gather().into_iter().find(|family| family.get_name() == name)?;
+        let relayer = relayer.map(|relayer| format!("{relayer:#x}"));
+        let metric = family.get_metric().iter().find(|metric| {
+            let label =
+                |name: &str| metric.get_label().iter().find(|pair| pair.get_name() == name).map(|p| p.get_value());
+            label("chain") == Some(chain)
+                && relayer.as_deref().map_or(true, |relayer| label("relayer") == Some(relayer))
+        })?;
+        Some(if metric.has_counter() { metric.get_counter().get_value() } else { metric.get_gauge().get_value() })
+    }
+
+    #[test]
+    fn test_relayer_funds_update() {
+        // Given
+        let funds = RelayerFunds::default();
+
+        // When & Then
+        // Balances which couldn't be read don't change the state
+        funds.update(0, 2);
+        assert!(funds.is_funded());
+        funds.update(0, 0);
+        assert!(!funds.is_funded());
+        funds.update(0, 1);
+        assert!(!funds.is_funded());
+        funds.update(1, 1);
+        assert!(funds.is_funded());
+    }
+
+    #[test]
+    fn test_relayer_metrics() {
+        // Given
+        let registry = Registry::new();
+        let metrics = RelayerMetrics::new(&registry).expect("Failed to register metrics");
+        let (relayer, other) = (Felt::ONE, Felt::TWO);
+
+        // When
+        metrics.set_balance(relayer, 5 * 10u128.pow(17));
+        metrics.set_nonce(relayer, 3);
+        metrics.set_funded_relayers(1);
+        metrics.relay_started();
+        metrics.relay_started();
+        metrics.relay_finished(relayer, true);
+        metrics.relay_finished(other, false);
+
+        // Then
+        let value = |name: &str, relayer: Option<Felt>| metric_value(&registry, name, DEFAULT_CHAIN_NAME, relayer);
+        assert_eq!(value("relayer_balance", Some(relayer)), Some(0.5));
+        assert_eq!(value("relayer_nonce", Some(relayer)), Some(3.));
+        assert_eq!(value("relayer_funded_count", None), Some(1.));
+        assert_eq!(value("relayer_in_flight", None), Some(0.));
+        assert_eq!(value("relayer_failures", Some(relayer)), Some(1.));
+        assert_eq!(value("relayer_failures", Some(other)), None);
+
+        // When
+        metrics.remove_relayer(relayer);
+
+        // Then
+        assert_eq!(value("relayer_balance", Some(relayer)), None);
+        assert_eq!(value("relayer_failures", Some(relayer)), None);
+    }
+
+    #[test]
+    fn test_relayer_metrics_for_chain() {
+        // Given
+        let registry = Registry::new();
+        let metrics = RelayerMetrics::new(&registry).expect("Failed to register metrics");
+        let sepolia = metrics.for_chain("sepolia");
+
+        // When
+        metrics.set_balance(Felt::ONE, 10u128.pow(18));
+        sepolia.set_balance(Felt::ONE, 2 * 10u128.pow(18));
+        sepolia.set_funded_relayers(1);
+
+        // Then
+        // The chains share the registered metrics under their own label
+        assert_eq!(metric_value(&registry, "relayer_balance", DEFAULT_CHAIN_NAME, Some(Felt::ONE)), Some(1.));
+        assert_eq!(metric_value(&registry, "relayer_balance", "sepolia", Some(Felt::ONE)), Some(2.));
+        assert_eq!(metric_value(&registry, "relayer_funded_count", "sepolia", None), Some(1.));
+        assert_eq!(metric_value(&registry, "relayer_funded_count", DEFAULT_CHAIN_NAME, None), None);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    config::SpamProtectionConfig, eth_rpc::middleware::ClientId, pool::validate::InvalidKakarotTransactionError,
+};
+use alloy_primitives::{Address, B256};
+use reth_transaction_pool::TransactionOrigin;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// A limit on the number of transactions submitted within a time window. Both the maximum and
+/// the window are non-zero, as checked when loading the [`SpamProtectionConfig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of transactions submitted within the window.
+    pub max_transactions: u32,
+    /// The duration of the window.
+    pub window: Duration,
+}
+
+/// Counts the transactions submitted within the current window of a [`RateLimit`].
+#[derive(Debug)]
+struct Window {
+    started_at: Instant,
+    count: u32,
+}
+
+impl Window {
+    const fn new(now: Instant) -> Self {
+        Self { started_at: now, count: 0 }
+    }
+
+    /// Counts a submission, returning `false` if the limit is already reached for the window.
+    fn try_acquire(&mut self, limit: RateLimit, now: Instant) -> bool {
+        if now.duration_since(self.started_at) >= limit.window {
+            *self = Self::new(now);
+        }
+        if self.count >= limit.max_transactions {
+            return false;
+        }
+        self.count += 1;
+        true
+    }
+}
+
+/// Protects the pool against spam, enforcing the [`SpamProtectionConfig`] on the transactions
+/// submitted to the pool.
+///
+/// Rate limits only apply to transactions which are not [local](TransactionOrigin::Local), as
+/// local transactions are submitted by the RPC itself, e.g. when re-queuing a relayed transaction.
+/// The origin rate limit applies to the [`ClientId`] which submitted the transaction, recorded
+/// with [`SpamFilter::set_submitter`] before the transaction is added to the pool. Only the
+/// transactions passing all the other validity checks count against the rate limits.
+#[derive(Debug, Default)]
+pub struct SpamFilter {
+    config: SpamProtectionConfig,
+    senders: Mutex<HashMap<Address, Window>>,
+    origins: Mutex<HashMap<ClientId, Window>>,
+    submitters: Mutex<HashMap<B256, ClientId>>,
+}
+
+impl SpamFilter {
+    /// Creates a new [`SpamFilter`] enforcing the given configuration.
+    pub fn new(config: SpamProtectionConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    /// Returns the configuration enforced by the filter.
+    pub const fn config(&self) -> &SpamProtectionConfig {
+        &self.config
+    }
+
+    /// Checks the sender and the target of the transaction against the allow and deny lists.
+    /// Contract creations are rejected if an allowlist of targets is configured.
+    pub fn check_lists(&self, sender: Address, to: Option<Address>) -> Result<(), InvalidKakarotTransactionError> {
+        let config = &self.config;
+
+        if config.denied_senders.contains(&sender)
+            || config.allowed_senders.as_ref().is_some_and(|allowed| !allowed.contains(&sender))
+        {
+            return Err(InvalidKakarotTransactionError::SenderNotAllowed(sender));
+        }
+
+        let Some(to) = to else {
+            if config.allowed_targets.is_some() {
+                return Err(InvalidKakarotTransactionError::ContractCreationNotAllowed);
+            }
+            return Ok(());
+        };
+        if config.denied_targets.contains(&to)
+            || config.allowed_targets.as_ref().is_some_and(|allowed| !allowed.contains(&to))
+        {
+            return Err(InvalidKakarotTransactionError::TargetNotAllowed(to));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the effective gas price of the transaction against the configured minimum.
+    pub const fn check_gas_price(&self, effective_gas_price: u128) -> Result<(), InvalidKakarotTransactionError> {
+        if effective_gas_price < self.config.min_gas_price {
+            return Err(InvalidKakarotTransactionError::GasPriceBelowMinimum(
+                self.config.min_gas_price,
+                effective_gas_price,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the sender doesn't hold more than the configured number of slots, i.e.
+    /// that the transaction nonce is not too far ahead of the account nonce.
+    pub fn check_slots(
+        &self,
+        sender: Address,
+        nonce: u64,
+        account_nonce: u64,
+    ) -> Result<(), InvalidKakarotTransactionError> {
+        if nonce.saturating_sub(account_nonce) >= self.config.max_account_slots as u64 {
+            return Err(InvalidKakarotTransactionError::SenderSlotsExceeded(sender, self.config.max_account_slots));
+        }
+        Ok(())
+    }
+
+    /// Records the client submitting the transaction, against whose origin rate limit the
+    /// transaction is counted when validated.
+    pub fn set_submitter(&self, hash: B256, client: ClientId) {
+        self.submitters.lock().expect("spam filter lock poisoned").insert(hash, client);
+    }
+
+    /// Forgets the client submitting the transaction, if the transaction was rejected before
+    /// being counted against the rate limits.
+    pub fn remove_submitter(&self, hash: &B256) {
+        self.submitters.lock().expect("spam filter lock poisoned").remove(hash);
+    }
+
+    /// Counts the submission against the sender and origin rate limits. Transactions without a
+    /// recorded submitter count against the origin rate limit of the [`ClientId::Anonymous`]
+    /// clients.
+    pub fn check_rate_limits(
+        &self,
+        origin: TransactionOrigin,
+        hash: B256,
+        sender: Address,
+    ) -> Result<(), InvalidKakarotTransactionError> {
+        let client = self.submitters.lock().expect("spam filter lock poisoned").remove(&hash);
+        if origin.is_local() {
+            return Ok(());
+        }
+        let now = Instant::now();
+
+        if let Some(limit) = self.config.sender_rate_limit {
+            let mut senders = self.senders.lock().expect("spam filter lock poisoned");
+            if !senders.entry(sender).or_insert_with(|| Window::new(now)).try_acquire(limit, now) {
+                return Err(InvalidKakarotTransactionError::SenderRateLimited(sender));
+            }
+        }
+
+        if let Some(limit) = self.config.origin_rate_limit {
+            let mut origins = self.origins.lock().expect("spam filter lock poisoned");
+            let client = client.unwrap_or(ClientId::Anonymous);
+            if !origins.entry(client).or_insert_with(|| Window::new(now)).try_acquire(limit, now) {
+                return Err(InvalidKakarotTransactionError::OriginRateLimited);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops the sender and origin windows which are over, so that the filter doesn't grow
+    /// unbounded.
+    pub fn prune(&self) {
+        let now = Instant::now();
+        if let Some(limit) = self.config.sender_rate_limit {
+            self.senders
+                .lock()
+                .expect("spam filter lock poisoned")
+                .retain(|_, window| now.duration_since(window.started_at) < limit.window);
+        }
+        if let Some(limit) = self.config.origin_rate_limit {
+            self.origins
+                .lock()
+                .expect("spam filter lock poisoned")
+                .retain(|_, window| now.duration_since(window.started_at) < limit.window);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_check_lists() {
+        // Given
+        let (allowed, denied, target) = (Address::random(), Address::random(), Address::random());
+        let filter = SpamFilter::new(SpamProtectionConfig {
+            allowed_senders: Some(HashSet::from([allowed, denied])),
+            denied_senders: HashSet::from([denied]),
+            allowed_targets: Some(HashSet::from([target])),
+            ..Default::default()
+        });
+
+        // When & Then
+        assert!(filter.check_lists(allowed, Some(target)).is_ok());
+        assert!(filter.check_lists(denied, Some(target)).is_err());
+        assert!(filter.check_lists(Address::random(), Some(target)).is_err());
+        assert!(filter.check_lists(allowed, Some(Address::random())).is_err());
+        assert!(filter.check_lists(allowed, None).is_err());
+    }
+
+    #[test]
+    fn test_check_slots() {
+        // Given
+        let filter = SpamFilter::new(SpamProtectionConfig { max_account_slots: 2, ..Default::default() });
+        let sender = Address::random();
+
+        // When & Then
+        assert!(filter.check_slots(sender, 5, 4).is_ok());
+        assert!(filter.check_slots(sender, 6, 4).is_err());
+    }
+
+    #[test]
+    fn test_check_rate_limits() {
+        // Given
+        let limit = RateLimit { max_transactions: 2, window: Duration::from_secs(60) };
+        let filter = SpamFilter::new(SpamProtectionConfig {
+            sender_rate_limit: Some(limit),
+            origin_rate_limit: Some(RateLimit { max_transactions: 3, ..limit }),
+            ..Default::default()
+        });
+        let (sender, other) = (Address::random(), Address::random());
+        let check = |sender: Address| filter.check_rate_limits(TransactionOrigin::External, B256::random(), sender);
+
+        // When & Then
+        assert!(check(sender).is_ok());
+        assert!(check(sender).is_ok());
+        assert!(matches!(check(sender), Err(InvalidKakarotTransactionError::SenderRateLimited(_))));
+        assert!(check(other).is_ok());
+        assert!(matches!(check(other), Err(InvalidKakarotTransactionError::OriginRateLimited)));
+        // Local transactions are never rate limited
+        assert!(filter.check_rate_limits(TransactionOrigin::Local, B256::random(), sender).is_ok());
+    }
+
+    #[test]
+    fn test_check_rate_limits_per_client() {
+        // Given
+        let filter = SpamFilter::new(SpamProtectionConfig {
+            origin_rate_limit: Some(RateLimit { max_transactions: 1, window: Duration::from_secs(60) }),
+            ..Default::default()
+        });
+        let (client, other) = (ClientId::Ip([1, 1, 1, 1].into()), ClientId::ApiKey("partner".to_string()));
+        let check = |client: Option<ClientId>| {
+            let hash = B256::random();
+            if let Some(client) = client {
+                filter.set_submitter(hash, client);
+            }
+            filter.check_rate_limits(TransactionOrigin::External, hash, Address::random())
+        };
+
+        // When & Then
+        // Each client has its own window, whatever the sender of the transactions
+        assert!(check(Some(client.clone())).is_ok());
+        assert!(matches!(check(Some(client)), Err(InvalidKakarotTransactionError::OriginRateLimited)));
+        assert!(check(Some(other)).is_ok());
+        // The transactions without a submitter share the window of the anonymous clients
+        assert!(check(None).is_ok());
+        assert!(check(Some(ClientId::Anonymous)).is_err());
+        assert!(filter.submitters.lock().unwrap().is_empty());
+    }
+}
@@ -1,23 +1,71 @@
 #![allow(unused_variables, clippy::struct_excessive_bools)]
 
-use crate::providers::eth_provider::{
-    database::state::EthDatabase, provider::EthereumProvider,
-    starknet::kakarot_core::get_white_listed_eip_155_transaction_hashes,
+use super::spam::SpamFilter;
+use crate::{
+    config::{LimitsConfig, SpamProtectionConfig},
+    models::transaction::starknet_calldata_len,
+    providers::eth_provider::{database::state::EthDatabase, error::EthApiError, provider::EthereumProvider},
 };
 use alloy_consensus::constants::{EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID, EIP4844_TX_TYPE_ID, LEGACY_TX_TYPE_ID};
+use alloy_primitives::Address;
 use alloy_rpc_types::BlockNumberOrTag;
 use reth_chainspec::ChainSpec;
-use reth_primitives::{GotExpected, InvalidTransactionError, SealedBlock};
+use reth_primitives::{GotExpected, InvalidTransactionError, SealedBlock, TransactionSignedEcRecovered};
 use reth_revm::DatabaseRef;
 use reth_transaction_pool::{
-    error::InvalidPoolTransactionError,
+    error::{InvalidPoolTransactionError, PoolTransactionError},
     validate::{ensure_intrinsic_gas, ForkTracker, ValidTransaction, DEFAULT_MAX_TX_INPUT_BYTES},
     EthPoolTransaction, TransactionOrigin, TransactionValidationOutcome, TransactionValidator,
 };
 use std::{
     marker::PhantomData,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{atomic::AtomicBool, Arc, RwLock},
 };
+use tokio::runtime::Handle;
+
+/// Kakarot specific reasons for a transaction to be rejected from the pool.
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidKakarotTransactionError {
+    /// Thrown when the Starknet calldata relaying the transaction exceeds the limit.
+    #[error("calldata exceeded limit of {0}: {1}")]
+    CalldataExceededLimit(usize, usize),
+    /// Thrown when the Kakarot account of the sender is not deployed.
+    #[error("sender account {0} is not deployed")]
+    AccountNotDeployed(Address),
+    /// Thrown when the sender is denied or not in the allowlist.
+    #[error("sender {0} is not allowed")]
+    SenderNotAllowed(Address),
+    /// Thrown when the target is denied or not in the allowlist.
+    #[error("target {0} is not allowed")]
+    TargetNotAllowed(Address),
+    /// Thrown when contract creations are not allowed by the target allowlist.
+    #[error("contract creation is not allowed")]
+    ContractCreationNotAllowed,
+    /// Thrown when the effective gas price is below the minimum.
+    #[error("effective gas price below minimum of {0}: {1}")]
+    GasPriceBelowMinimum(u128, u128),
+    /// Thrown when the sender already holds the maximum number of transactions in the pool.
+    #[error("sender {0} exceeded the limit of {1} transactions in the pool")]
+    SenderSlotsExceeded(Address, usize),
+    /// Thrown when the sender submitted too many transactions.
+    #[error("sender {0} is rate limited")]
+    SenderRateLimited(Address),
+    /// Thrown when the client submitting the transaction submitted too many transactions.
+    #[error("transaction origin is rate limited")]
+    OriginRateLimited,
+}
+
+impl PoolTransactionError for InvalidKakarotTransactionError {
+    fn is_bad_transaction(&self) -> bool {
+        false
+    }
+}
+
+impl From<InvalidKakarotTransactionError> for InvalidPoolTransactionError {
+    fn from(err: InvalidKakarotTransactionError) -> Self {
+        Self::Other(Box::new(err))
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct KakarotTransactionValidatorBuilder {
@@ -38,6 +86,10 @@ pub struct KakarotTransactionValidatorBuilder {
     pub block_gas_limit: u64,
     /// Max size in bytes of a single transaction allowed
     pub max_tx_input_bytes: usize,
+    /// The spam protection of the pool
+    pub spam_protection: SpamProtectionConfig,
+    /// The limits of the transactions accepted by the pool
+    pub limits: LimitsConfig,
 }
 
 impl KakarotTransactionValidatorBuilder {
@@ -67,9 +119,29 @@ impl KakarotTransactionValidatorBuilder {
 
             // prague not yet activated
             prague: false,
+
+            // no spam protection by default
+            spam_protection: SpamProtectionConfig::default(),
+
+            // no limits by default
+            limits: LimitsConfig::default(),
         }
     }
 
+    /// Sets the spam protection of the pool.
+    #[must_use]
+    pub fn with_spam_protection(mut self, spam_protection: SpamProtectionConfig) -> Self {
+        self.spam_protection = spam_protection;
+        self
+    }
+
+    /// Sets the limits of the transactions accepted by the pool.
+    #[must_use]
+    pub fn with_limits(mut self, limits: LimitsConfig) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Builds the [`EthTransactionValidator`] without spawning validator tasks.
     pub fn build<P, Tx>(self, provider: P) -> KakarotTransactionValidator<P, Tx>
     where
@@ -85,7 +157,8 @@ impl KakarotTransactionValidatorBuilder {
             eip4844,
             block_gas_limit,
             max_tx_input_bytes,
-            ..
+            spam_protection,
+            limits,
         } = self;
 
         let fork_tracker = ForkTracker {
@@ -103,6 +176,9 @@ impl KakarotTransactionValidatorBuilder {
             block_gas_limit,
             max_tx_input_bytes,
             fork_tracker,
+            base_fee: RwLock::new(None),
+            spam_filter: Arc::new(SpamFilter::new(spam_protection)),
+            limits,
             _marker: Default::default(),
         };
 
@@ -133,18 +209,24 @@ where
     pub fn provider(&self) -> &P {
         &self.inner.provider
     }
+
+    /// Returns the spam filter enforcing the spam protection of the pool
+    pub fn spam_filter(&self) -> Arc<SpamFilter> {
+        self.inner.spam_filter.clone()
+    }
 }
 
 impl<P, Tx> KakarotTransactionValidator<P, Tx>
 where
     P: EthereumProvider + Send + Sync,
     Tx: EthPoolTransaction,
+    Tx::Consensus: Into<TransactionSignedEcRecovered>,
 {
     /// Validates a single transaction.
     ///
     /// See also [`TransactionValidator::validate_transaction`]
-    pub fn validate_one(&self, transaction: Tx) -> TransactionValidationOutcome<Tx> {
-        self.inner.validate_one(transaction)
+    pub fn validate_one(&self, origin: TransactionOrigin, transaction: Tx) -> TransactionValidationOutcome<Tx> {
+        self.inner.validate_one(origin, transaction)
     }
 
     /// Validates all given transactions.
@@ -153,7 +235,7 @@ where
     ///
     /// See also [`Self::validate_one`]
     pub fn validate_all(&self, transactions: Vec<(TransactionOrigin, Tx)>) -> Vec<TransactionValidationOutcome<Tx>> {
-        transactions.into_iter().map(|(_origin, tx)| self.validate_one(tx)).collect()
+        transactions.into_iter().map(|(origin, tx)| self.validate_one(origin, tx)).collect()
     }
 }
 
@@ -161,15 +243,16 @@ impl<P, Tx> TransactionValidator for KakarotTransactionValidator<P, Tx>
 where
     P: EthereumProvider + Send + Sync,
     Tx: EthPoolTransaction,
+    Tx::Consensus: Into<TransactionSignedEcRecovered>,
 {
     type Transaction = Tx;
 
     async fn validate_transaction(
         &self,
-        _origin: TransactionOrigin,
+        origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> TransactionValidationOutcome<Self::Transaction> {
-        self.validate_one(transaction)
+        self.validate_one(origin, transaction)
     }
 
     async fn validate_transactions(
@@ -179,7 +262,11 @@ where
         self.validate_all(transactions)
     }
 
-    fn on_new_head_block(&self, _new_tip_block: &SealedBlock) {}
+    fn on_new_head_block(&self, _new_tip_block: &SealedBlock) {
+        // The Kakarot base fee is refreshed on the next validation
+        self.inner.invalidate_base_fee();
+        self.inner.spam_filter.prune();
+    }
 }
 
 /// A [`TransactionValidator`] implementation that validates ethereum transaction.
@@ -204,6 +291,12 @@ where
     max_tx_input_bytes: usize,
     /// tracks activated forks relevant for transaction validation
     fork_tracker: ForkTracker,
+    /// The current Kakarot base fee, fetched at most once per block.
+    base_fee: RwLock<Option<u128>>,
+    /// Enforces the spam protection of the pool
+    spam_filter: Arc<SpamFilter>,
+    /// The limits of the transactions accepted by the pool
+    limits: LimitsConfig,
     /// Marker for the transaction type
     _marker: PhantomData<T>,
 }
@@ -216,21 +309,48 @@ where
     pub(crate) fn chain_id(&self) -> u64 {
         self.chain_spec.chain().id()
     }
+
+    /// Returns the current Kakarot base fee, fetching it from Kakarot if it is not known yet.
+    fn base_fee(&self) -> Result<u128, EthApiError> {
+        if let Some(base_fee) = *self.base_fee.read().expect("base fee lock poisoned") {
+            return Ok(base_fee);
+        }
+
+        let base_fee = tokio::task::block_in_place(|| Handle::current().block_on(self.provider.gas_price()))?;
+        let base_fee = base_fee.saturating_to();
+        *self.base_fee.write().expect("base fee lock poisoned") = Some(base_fee);
+
+        Ok(base_fee)
+    }
+
+    /// Drops the known Kakarot base fee, forcing it to be fetched on the next validation.
+    fn invalidate_base_fee(&self) {
+        *self.base_fee.write().expect("base fee lock poisoned") = None;
+    }
+
+    /// Returns whether the Kakarot account of the address is deployed on the pending block.
+    fn is_account_deployed(&self, address: Address) -> Result<bool, EthApiError> {
+        tokio::task::block_in_place(|| {
+            Handle::current()
+                .block_on(self.provider.is_account_deployed(address, Some(BlockNumberOrTag::Pending.into())))
+        })
+    }
 }
 
 impl<P, Tx> KakarotTransactionValidatorInner<P, Tx>
 where
     P: EthereumProvider + Send + Sync,
     Tx: EthPoolTransaction,
+    Tx::Consensus: Into<TransactionSignedEcRecovered>,
 {
     /// Validates a single transaction.
     #[allow(clippy::too_many_lines)]
-    fn validate_one(&self, transaction: Tx) -> TransactionValidationOutcome<Tx> {
+    fn validate_one(&self, origin: TransactionOrigin, transaction: Tx) -> TransactionValidationOutcome<Tx> {
         // Checks for tx_type
         match transaction.tx_type() {
             LEGACY_TX_TYPE_ID => {
                 if transaction.chain_id().is_none()
-                    && !get_white_listed_eip_155_transaction_hashes().contains(transaction.hash())
+                    && !self.limits.white_listed_eip_155_transaction_hashes.contains(transaction.hash())
                 {
                     return TransactionValidationOutcome::Invalid(
                         transaction,
@@ -296,6 +416,11 @@ where
             return TransactionValidationOutcome::Invalid(transaction, InvalidTransactionError::TipAboveFeeCap.into());
         }
 
+        // Checks for the sender and target allow and deny lists
+        if let Err(err) = self.spam_filter.check_lists(transaction.sender(), transaction.to()) {
+            return TransactionValidationOutcome::Invalid(transaction, err.into());
+        }
+
         // Checks for chainid
         if let Some(chain_id) = transaction.chain_id() {
             if chain_id != self.chain_id() {
@@ -311,6 +436,36 @@ where
             return TransactionValidationOutcome::Invalid(transaction, err);
         }
 
+        // Checks for the size of the Starknet calldata relaying the transaction
+        #[cfg(not(feature = "hive"))]
+        if let Some(max_felts_in_calldata) = self.limits.max_felts_in_calldata {
+            let transaction_signed: TransactionSignedEcRecovered = transaction.clone().into_consensus().into();
+            let calldata_len = starknet_calldata_len(&transaction_signed);
+            if calldata_len > max_felts_in_calldata {
+                return TransactionValidationOutcome::Invalid(
+                    transaction,
+                    InvalidKakarotTransactionError::CalldataExceededLimit(max_felts_in_calldata, calldata_len).into(),
+                );
+            }
+        }
+
+        // Checks for the Kakarot base fee
+        let base_fee = match self.base_fee() {
+            Ok(base_fee) => base_fee,
+            Err(err) => return TransactionValidationOutcome::Error(*transaction.hash(), Box::new(err)),
+        };
+        if transaction.max_fee_per_gas() < base_fee {
+            return TransactionValidationOutcome::Invalid(transaction, InvalidTransactionError::FeeCapTooLow.into());
+        }
+
+        // Checks for the minimum effective gas price
+        let effective_gas_price = transaction.max_priority_fee_per_gas().map_or(transaction.max_fee_per_gas(), |tip| {
+            transaction.max_fee_per_gas().min(base_fee.saturating_add(tip))
+        });
+        if let Err(err) = self.spam_filter.check_gas_price(effective_gas_price) {
+            return TransactionValidationOutcome::Invalid(transaction, err.into());
+        }
+
         // Fetch the account state for the Pending block
         let db = EthDatabase::new(Arc::new(&self.provider), BlockNumberOrTag::Pending.into());
         let account = match db.basic_ref(transaction.sender()) {
@@ -318,6 +473,21 @@ where
             Err(err) => return TransactionValidationOutcome::Error(*transaction.hash(), Box::new(err)),
         };
 
+        // Signer account should be deployed on Kakarot for the transaction to be relayed. With the
+        // Hive feature, the account is deployed when the transaction is sent.
+        #[cfg(not(feature = "hive"))]
+        match self.is_account_deployed(transaction.sender()) {
+            Ok(true) => {}
+            Ok(false) => {
+                let sender = transaction.sender();
+                return TransactionValidationOutcome::Invalid(
+                    transaction,
+                    InvalidKakarotTransactionError::AccountNotDeployed(sender).into(),
+                );
+            }
+            Err(err) => return TransactionValidationOutcome::Error(*transaction.hash(), Box::new(err)),
+        }
+
         // Signer account shouldn't have bytecode. Presence of bytecode means this is a
         // smartcontract.
         if !account.is_empty_code_hash() {
@@ -335,6 +505,11 @@ where
             );
         }
 
+        // Checks for the number of transactions of the sender in the pool
+        if let Err(err) = self.spam_filter.check_slots(transaction.sender(), transaction.nonce(), account.nonce) {
+            return TransactionValidationOutcome::Invalid(transaction, err.into());
+        }
+
         let cost = transaction.cost();
 
         // Checks for max cost
@@ -346,6 +521,12 @@ where
             );
         }
 
+        // Checks for the submission rate limits, last so that invalid transactions don't use up
+        // the quota of their sender and client
+        if let Err(err) = self.spam_filter.check_rate_limits(origin, *transaction.hash(), transaction.sender()) {
+            return TransactionValidationOutcome::Invalid(transaction, err.into());
+        }
+
         let maybe_blob_sidecar = None;
 
         // Return the valid transaction
@@ -0,0 +1,397 @@
+use super::constants::{MAX_RELAY_RETRIES, RELAY_TIMEOUT, RETRYABLE_REVERT_REASONS, WATCH_INTERVAL};
+use crate::{
+    client::EthClient,
+    providers::eth_provider::database::{
+        ethereum::EthereumTransactionStore,
+        types::transaction::{RelayStatus, RelayedTransactionStatus},
+    },
+};
+use alloy_primitives::B256;
+use reth_transaction_pool::{EthPooledTransaction, TransactionOrigin, TransactionPool, ValidPoolTransaction};
+use starknet::{
+    core::types::{ExecutionResult, Felt, StarknetError},
+    providers::{Provider, ProviderError},
+};
+use std::{collections::HashMap, sync::Arc};
+use tokio::{sync::mpsc::UnboundedReceiver, time::Instant};
+use tracing::instrument;
+
+/// A transaction relayed on Starknet, watched until its Starknet transaction is final.
+#[derive(Debug)]
+pub struct RelayedTransaction {
+    /// The pooled Ethereum transaction, used to re-queue it on failure.
+    pub transaction: Arc<ValidPoolTransaction<EthPooledTransaction>>,
+    /// The hash of the Starknet transaction relaying the Ethereum transaction.
+    pub starknet_hash: Felt,
+    /// The instant the transaction was relayed at.
+    pub relayed_at: Instant,
+}
+
+impl RelayedTransaction {
+    /// Creates a new [`RelayedTransaction`], relayed now.
+    pub fn new(transaction: Arc<ValidPoolTransaction<EthPooledTransaction>>, starknet_hash: Felt) -> Self {
+        Self { transaction, starknet_hash, relayed_at: Instant::now() }
+    }
+}
+
+/// Watches the Starknet transactions of the relayed Ethereum transactions.
+///
+/// The final status of each relayed transaction is recorded in the database. Transactions which
+/// failed for a retryable reason are put back in the pool, up to [`MAX_RELAY_RETRIES`] times.
+#[derive(Debug)]
+pub struct TransactionWatcher<SP: Provider + Send + Sync> {
+    /// The Ethereum client used to poll the receipts and re-queue the transactions.
+    eth_client: Arc<EthClient<SP>>,
+    /// The receiver of the relayed transactions to watch.
+    relayed_transactions: UnboundedReceiver<RelayedTransaction>,
+    /// The number of times each Ethereum transaction was re-queued.
+    retries: HashMap<B256, u32>,
+}
+
+impl<SP> TransactionWatcher<SP>
+where
+    SP: Provider + Send + Sync + Clone + 'static,
+{
+    /// Creates a new [`TransactionWatcher`] watching the transactions sent on the channel.
+    pub fn new(eth_client: Arc<EthClient<SP>>, relayed_transactions: UnboundedReceiver<RelayedTransaction>) -> Self {
+        Self { eth_client, relayed_transactions, retries: HashMap::new() }
+    }
+
+    /// Starts the watcher task.
+    #[instrument(skip_all, name = "watcher")]
+    pub fn start(mut self) {
+        tokio::spawn(async move {
+            let mut watched = Vec::new();
+            let mut interval = tokio::time::interval(WATCH_INTERVAL);
+
+            loop {
+                tokio::select! {
+                    relayed = self.relayed_transactions.recv() => {
+                        let Some(relayed) = relayed else {
+                            tracing::error!(target: "transaction_watcher", "relayed transactions channel closed");
+                            return;
+                        };
+                        self.record(&relayed, RelayStatus::Pending, None).await;
+                        watched.push(relayed);
+                    }
+                    _ = interval.tick() => {
+                        let mut still_pending = Vec::with_capacity(watched.len());
+                        for relayed in watched {
+                            if !self.poll(&relayed).await {
+                                still_pending.push(relayed);
+                            }
+                        }
+                        watched = still_pending;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Polls the Starknet receipt of the relayed transaction, and handles its final status.
+    ///
+    /// Returns `true` if the Starknet transaction is final.
+    async fn poll(&mut self, relayed: &RelayedTransaction) -> bool {
+        let receipt = self
+            .eth_client
+            .eth_provider()
+            .starknet_provider_inner()
+            .get_transaction_receipt(relayed.starknet_hash)
+            .await;
+
+        let reason = match receipt {
+            Ok(receipt) => match receipt.receipt.execution_result() {
+                ExecutionResult::Succeeded => {
+                    self.record(relayed, RelayStatus::Succeeded, None).await;
+                    self.retries.remove(relayed.transaction.hash());
+                    self.finish(relayed);
+                    return true;
+                }
+                ExecutionResult::Reverted { reason } => {
+                    let retryable = is_retryable(reason);
+                    self.handle_failure(relayed, RelayStatus::Reverted, reason.clone(), retryable).await;
+                    return true;
+                }
+            },
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {
+                if relayed.relayed_at.elapsed() < RELAY_TIMEOUT {
+                    return false;
+                }
+                format!("transaction not found after {}s", RELAY_TIMEOUT.as_secs())
+            }
+            Err(err) => {
+                tracing::warn!(target: "transaction_watcher", ?err, starknet_hash = ?relayed.starknet_hash, "failed to fetch receipt");
+                return false;
+            }
+        };
+
+        // The Starknet transaction was dropped, the Ethereum transaction can always be relayed again.
+        self.handle_failure(relayed, RelayStatus::Rejected, reason, true).await;
+        true
+    }
+
+    /// Records the failure of the relayed transaction, and re-queues it in the pool if it is
+    /// retryable and was not retried too many times.
+    async fn handle_failure(
+        &mut self,
+        relayed: &RelayedTransaction,
+        status: RelayStatus,
+        reason: String,
+        retryable: bool,
+    ) {
+        let hash = *relayed.transaction.hash();
+        tracing::warn!(target: "transaction_watcher", ?hash, starknet_hash = ?relayed.starknet_hash, ?status, %reason, "relayed transaction failed");
+
+        let retries = self.retries.get(&hash).copied().unwrap_or_default();
+        if !retryable || retries >= MAX_RELAY_RETRIES {
+            self.record(relayed, status, Some(reason)).await;
+            self.retries.remove(&hash);
+            self.finish(relayed);
+            return;
+        }
+
+        self.retries.insert(hash, retries + 1);
+        self.record(relayed, RelayStatus::Requeued, Some(reason)).await;
+
+        if let Err(err) = self
+            .eth_client
+            .mempool()
+            .add_transaction(TransactionOrigin::Local, relayed.transaction.transaction.clone())
+            .await
+        {
+            tracing::error!(target: "transaction_watcher", ?err, ?hash, "failed to re-queue transaction");
+        }
+        self.finish(relayed);
+    }
+
+    /// Stops tracking the relayed transaction as in flight in the journal, once its relay is final
+    /// or once it is back in the pool.
+    fn finish(&self, relayed: &RelayedTransaction) {
+        if let Some(journal) = self.eth_client.journal() {
+            journal.remove_in_flight(relayed.transaction.hash());
+        }
+    }
+
+    /// Records the status of the relayed transaction in the database.
+    async fn record(&self, relayed: &RelayedTransaction, status: RelayStatus, reason: Option<String>) {
+        let hash = *relayed.transaction.hash();
+        let status = RelayedTransactionStatus {
+            eth_hash: hash,
+            starknet_hash: relayed.starknet_hash,
+            status,
+            reason,
+            retries: self.retries.get(&hash).copied().unwrap_or_default(),
+        };
+
+        if let Err(err) = self.eth_client.eth_provider().database().upsert_transaction_status(status).await {
+            tracing::error!(target: "transaction_watcher", ?err, ?hash, "failed to record transaction status");
+        }
+    }
+}
+
+/// Returns `true` if the Starknet revert reason is due to the relayer rather than the
+/// Ethereum transaction, in which case relaying the transaction again can succeed.
+fn is_retryable(reason: &str) -> bool {
+    RETRYABLE_REVERT_REASONS.iter().any(|retryable| reason.contains(retryable))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{LimitsConfig, SpamProtectionConfig},
+        providers::eth_provider::starknet::kakarot_core::KakarotDeployment,
+        test_utils::{
+            mock_starknet::{invoke_receipt, MockStarknet},
+            mongo::{MongoFuzzer, RANDOM_BYTES_SIZE},
+        },
+    };
+    use alloy_consensus::TxEip1559;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Address, TxKind};
+    use reth_primitives::{sign_message, Transaction, TransactionSigned, TransactionSignedEcRecovered};
+    use reth_transaction_pool::{
+        identifier::{SenderId, TransactionId},
+        PoolConfig,
+    };
+    use serde_json::json;
+    use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient};
+    use tokio::sync::mpsc;
+
+    /// Returns a watcher of the transactions relayed to the mocked Starknet node.
+    ///
+    /// All the calls answer `[0, 1]`: the Kakarot base fee, the nonces and the code sizes of the
+    /// accounts are zero, and their balance is `2^128`, so that the pool accepts the
+    /// transactions re-queued by the watcher.
+    async fn mock_watcher(starknet: &mut MockStarknet) -> TransactionWatcher<JsonRpcClient<HttpTransport>> {
+        let mut mongo_fuzzer = MongoFuzzer::new(RANDOM_BYTES_SIZE).await;
+        let database = mongo_fuzzer.mock_database(1).await;
+        let deployment = KakarotDeployment::new(starknet.url(), Felt::ONE, Felt::TWO, Felt::from(0x4b4b_5254_u64));
+        let eth_client = Arc::new(EthClient::new(
+            starknet.provider(),
+            deployment,
+            LimitsConfig::default(),
+            PoolConfig::default(),
+            SpamProtectionConfig::default(),
+            database,
+        ));
+
+        starknet.mock_result("starknet_call", json!(["0x0", "0x1"])).await;
+        starknet.mock_result("starknet_getClassHashAt", json!(Felt::ONE)).await;
+
+        let (_, relayed_transactions) = mpsc::unbounded_channel();
+        TransactionWatcher::new(eth_client, relayed_transactions)
+    }
+
+    /// Returns a transaction of the chain of the watcher, relayed in the Starknet transaction.
+    fn relayed_transaction(
+        watcher: &TransactionWatcher<JsonRpcClient<HttpTransport>>,
+        starknet_hash: Felt,
+    ) -> RelayedTransaction {
+        let transaction = Transaction::Eip1559(TxEip1559 {
+            chain_id: watcher.eth_client.eth_provider().deployment().chain_id,
+            gas_limit: 21000,
+            to: TxKind::Call(Address::random()),
+            max_fee_per_gas: 875_000_000,
+            ..Default::default()
+        });
+        let signature = sign_message(B256::random(), transaction.signature_hash()).expect("Failed to sign transaction");
+        let transaction_signed = TransactionSigned::from_transaction_and_signature(transaction, signature);
+        let sender = transaction_signed.recover_signer().expect("Failed to recover signer");
+        let recovered = TransactionSignedEcRecovered::from_signed_transaction(transaction_signed, sender);
+        let encoded_length = recovered.encode_2718_len();
+
+        let transaction = Arc::new(ValidPoolTransaction {
+            transaction: EthPooledTransaction::new(recovered, encoded_length),
+            transaction_id: TransactionId::new(SenderId::from(0), 0),
+            propagate: true,
+            timestamp: std::time::Instant::now(),
+            origin: TransactionOrigin::External,
+        });
+        RelayedTransaction::new(transaction, starknet_hash)
+    }
+
+    /// Returns the status recorded for the relayed transaction.
+    async fn recorded_status(
+        watcher: &TransactionWatcher<JsonRpcClient<HttpTransport>>,
+        relayed: &RelayedTransaction,
+    ) -> RelayedTransactionStatus {
+        watcher
+            .eth_client
+            .eth_provider()
+            .database()
+            .transaction_status(relayed.transaction.hash())
+            .await
+            .expect("Failed to get transaction status")
+            .expect("Missing transaction status")
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable("Invalid transaction nonce of contract at address 0x1"));
+        assert!(is_retryable("Execution failed: Insufficient max fee"));
+        assert!(!is_retryable("RunResources has no remaining steps."));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_poll_requeues_retryable_revert() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let mut watcher = mock_watcher(&mut starknet).await;
+        let starknet_hash = Felt::from(0xabc_u64);
+        let receipt = invoke_receipt(starknet_hash, Some("Invalid transaction nonce of contract at address 0x1"));
+        starknet.mock_result("starknet_getTransactionReceipt", receipt).await;
+        let relayed = relayed_transaction(&watcher, starknet_hash);
+
+        // When
+        let is_final = watcher.poll(&relayed).await;
+
+        // Then
+        // The transaction is put back in the pool to be relayed again
+        assert!(is_final);
+        let status = recorded_status(&watcher, &relayed).await;
+        assert_eq!(status.status, RelayStatus::Requeued);
+        assert_eq!(status.starknet_hash, starknet_hash);
+        assert_eq!(status.retries, 1);
+        assert!(status.reason.is_some_and(|reason| reason.contains("Invalid transaction nonce")));
+        assert!(watcher.eth_client.mempool().contains(relayed.transaction.hash()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_poll_stops_requeuing_after_max_retries() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let mut watcher = mock_watcher(&mut starknet).await;
+        let starknet_hash = Felt::from(0xabc_u64);
+        let receipt = invoke_receipt(starknet_hash, Some("Invalid transaction nonce of contract at address 0x1"));
+        starknet.mock_result("starknet_getTransactionReceipt", receipt).await;
+        let relayed = relayed_transaction(&watcher, starknet_hash);
+        watcher.retries.insert(*relayed.transaction.hash(), MAX_RELAY_RETRIES);
+
+        // When
+        let is_final = watcher.poll(&relayed).await;
+
+        // Then
+        // The transaction was already re-queued too many times, its revert is final
+        assert!(is_final);
+        let status = recorded_status(&watcher, &relayed).await;
+        assert_eq!(status.status, RelayStatus::Reverted);
+        assert_eq!(status.retries, MAX_RELAY_RETRIES);
+        assert!(!watcher.retries.contains_key(relayed.transaction.hash()));
+        assert!(!watcher.eth_client.mempool().contains(relayed.transaction.hash()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_poll_records_final_revert() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let mut watcher = mock_watcher(&mut starknet).await;
+        let starknet_hash = Felt::from(0xabc_u64);
+        let receipt = invoke_receipt(starknet_hash, Some("RunResources has no remaining steps."));
+        starknet.mock_result("starknet_getTransactionReceipt", receipt).await;
+        let relayed = relayed_transaction(&watcher, starknet_hash);
+
+        // When
+        let is_final = watcher.poll(&relayed).await;
+
+        // Then
+        // The revert is caused by the transaction itself, it is not re-queued
+        assert!(is_final);
+        let status = recorded_status(&watcher, &relayed).await;
+        assert_eq!(status.status, RelayStatus::Reverted);
+        assert_eq!(status.retries, 0);
+        assert_eq!(status.reason.as_deref(), Some("RunResources has no remaining steps."));
+        assert!(!watcher.eth_client.mempool().contains(relayed.transaction.hash()));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_poll_requeues_timed_out_transaction() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let mut watcher = mock_watcher(&mut starknet).await;
+        starknet.mock_error("starknet_getTransactionReceipt", 29, "Transaction hash not found", None).await;
+        let starknet_hash = Felt::from(0xabc_u64);
+        let mut relayed = relayed_transaction(&watcher, starknet_hash);
+
+        // When
+        let is_final = watcher.poll(&relayed).await;
+
+        // Then
+        // The Starknet transaction can still be received before the timeout
+        assert!(!is_final);
+        assert!(!watcher.eth_client.mempool().contains(relayed.transaction.hash()));
+
+        // When
+        relayed.relayed_at = Instant::now() - RELAY_TIMEOUT;
+        let is_final = watcher.poll(&relayed).await;
+
+        // Then
+        // The Starknet transaction was dropped, the transaction is relayed again
+        assert!(is_final);
+        let status = recorded_status(&watcher, &relayed).await;
+        assert_eq!(status.status, RelayStatus::Requeued);
+        assert_eq!(status.retries, 1);
+        assert!(status.reason.is_some_and(|reason| reason.contains("not found after")));
+        assert!(watcher.eth_client.mempool().contains(relayed.transaction.hash()));
+    }
+}
@@ -38,6 +38,6 @@ where
     }
 
     async fn chain_id(&self) -> EthApiResult<Option<U64>> {
-        Ok(Some(U64::from(self.chain_id)))
+        Ok(Some(U64::from(self.deployment().chain_id)))
     }
 }
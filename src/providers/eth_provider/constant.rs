@@ -1,17 +1,17 @@
+use crate::eth_rpc::openrpc::schema;
 use alloy_primitives::{B256, U256};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use starknet::core::types::Felt;
-use std::{str::FromStr, sync::LazyLock};
+use std::sync::LazyLock;
 
 /// Maximum priority fee per gas
 pub static MAX_PRIORITY_FEE_PER_GAS: LazyLock<u64> = LazyLock::new(|| 0);
 
-/// Maximum number of logs that can be fetched in a single request
-pub static MAX_LOGS: LazyLock<Option<u64>> =
-    LazyLock::new(|| std::env::var("MAX_LOGS").ok().and_then(|val| u64::from_str(&val).ok()));
-
 /// Gas limit for estimate gas and call
 pub const CALL_REQUEST_GAS_LIMIT: u64 = 50_000_000;
+/// Maximum number of executions used to converge on the access list in `eth_createAccessList`
+pub const MAX_ACCESS_LIST_ITERATIONS: usize = 10;
 /// Number of characters for representing a U256 in a hex string form. Used for padding hashes
 pub const HASH_HEX_STRING_LEN: usize = 64;
 /// Number of characters for representing logs topics in a hex string form. Used for padding logs topics
@@ -26,59 +26,56 @@ pub const ADDRESS_HEX_STRING_LEN: usize = 40;
 pub const STARKNET_MODULUS: U256 = U256::from_limbs([0x1, 0, 0, 0x0800_0000_0000_0011]);
 
 /// Struct used to return the constant values from the `kakarot_getConfig` endpoint
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct Constant {
     /// Maximum number of logs to output for `eth_getLogs` RPC Method
     pub max_logs: Option<u64>,
     /// Name of the `StarkNet` network.
     pub starknet_network: String,
     /// Maximum number of Felts in calldata.
-    pub max_felts_in_calldata: usize,
+    pub max_felts_in_calldata: Option<usize>,
     /// List of whitelisted hashes allow to submit pre EIP-155 transactions.
+    #[schemars(with = "Vec<schema::Bytes32>")]
     pub white_listed_eip_155_transaction_hashes: Vec<B256>,
     /// Kakarot address the RPC points to.
+    #[schemars(with = "schema::Felt")]
     pub kakarot_address: Felt,
 }
 
 #[cfg(feature = "hive")]
 pub mod hive {
-    use std::{
-        env::var,
-        str::FromStr,
-        sync::{Arc, LazyLock},
-    };
+    use std::{env::var, str::FromStr, sync::LazyLock};
 
     use starknet::{
-        accounts::{ConnectedAccount, ExecutionEncoding, SingleOwnerAccount},
+        accounts::{ExecutionEncoding, SingleOwnerAccount},
         core::types::Felt,
         providers::{jsonrpc::HttpTransport, JsonRpcClient},
         signers::{LocalWallet, SigningKey},
     };
     use tokio::sync::Mutex;
 
-    use crate::constants::{KAKAROT_RPC_CONFIG, STARKNET_CHAIN_ID};
+    use crate::providers::eth_provider::starknet::kakarot_core::KakarotDeployment;
 
-    pub static DEPLOY_WALLET: LazyLock<SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>> =
-        LazyLock::new(|| {
-            SingleOwnerAccount::new(
-                JsonRpcClient::new(HttpTransport::new(KAKAROT_RPC_CONFIG.network_url.clone())),
-                LocalWallet::from_signing_key(SigningKey::from_secret_scalar(
-                    Felt::from_str(&var("KATANA_PRIVATE_KEY").expect("Missing deployer private key"))
-                        .expect("Failed to parse deployer private key"),
-                )),
-                Felt::from_str(&var("KATANA_ACCOUNT_ADDRESS").expect("Missing deployer address"))
-                    .expect("Failed to parse deployer address"),
-                *STARKNET_CHAIN_ID,
-                ExecutionEncoding::New,
-            )
-        });
-    pub static DEPLOY_WALLET_NONCE: LazyLock<Arc<Mutex<Felt>>> = LazyLock::new(|| {
-        tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(async {
-                Arc::new(Mutex::new(DEPLOY_WALLET.get_nonce().await.expect("failed to fetch deploy wallet nonce")))
-            })
-        })
-    });
+    /// Returns the deploy wallet of the Kakarot deployment, the account of the
+    /// `KATANA_ACCOUNT_ADDRESS` and `KATANA_PRIVATE_KEY` environment variables.
+    pub fn deploy_wallet(
+        deployment: &KakarotDeployment,
+    ) -> SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet> {
+        SingleOwnerAccount::new(
+            JsonRpcClient::new(HttpTransport::new(deployment.network_url.clone())),
+            LocalWallet::from_signing_key(SigningKey::from_secret_scalar(
+                Felt::from_str(&var("KATANA_PRIVATE_KEY").expect("Missing deployer private key"))
+                    .expect("Failed to parse deployer private key"),
+            )),
+            Felt::from_str(&var("KATANA_ACCOUNT_ADDRESS").expect("Missing deployer address"))
+                .expect("Failed to parse deployer address"),
+            deployment.starknet_chain_id,
+            ExecutionEncoding::New,
+        )
+    }
+
+    /// The next nonce of the deploy wallet, fetched from the chain on first use.
+    pub static DEPLOY_WALLET_NONCE: LazyLock<Mutex<Option<Felt>>> = LazyLock::new(|| Mutex::new(None));
 }
 
 #[cfg(feature = "forwarding")]
@@ -5,14 +5,16 @@ use super::{
         header::{ExtendedBlock, StoredHeader},
         transaction::{ExtendedTransaction, StoredTransaction},
     },
-    Database,
+    Database, FindOpts,
 };
 use crate::providers::eth_provider::{
-    database::types::transaction::{EthStarknetHashes, StoredEthStarknetTransactionHash},
+    database::types::transaction::{
+        EthStarknetHashes, RelayedTransactionStatus, StoredEthStarknetTransactionHash, StoredRelayedTransactionStatus,
+    },
     error::EthApiError,
 };
 use alloy_consensus::constants::EMPTY_ROOT_HASH;
-use alloy_primitives::{B256, U256};
+use alloy_primitives::{Address, B256, U256};
 use alloy_rlp::Encodable;
 use alloy_rpc_types::{Block, BlockHashOrNumber, BlockTransactions, Header};
 use alloy_serde::WithOtherFields;
@@ -33,10 +35,32 @@ pub trait EthereumTransactionStore {
         &self,
         block_hash_or_number: BlockHashOrNumber,
     ) -> Result<Vec<ExtendedTransaction>, EthApiError>;
+    /// Returns the transaction sent by the given address with the given nonce. Returns None if the
+    /// transaction is not found.
+    async fn transaction_by_sender_and_nonce(
+        &self,
+        sender: &Address,
+        nonce: u64,
+    ) -> Result<Option<ExtendedTransaction>, EthApiError>;
+    /// Returns the transactions sent from or to the given address in the inclusive block range,
+    /// sorted by block number and transaction index. The sorting is descending if `descending`
+    /// is set, and at most `limit` transactions are returned.
+    async fn transactions_by_address(
+        &self,
+        address: &Address,
+        from: u64,
+        to: u64,
+        descending: bool,
+        limit: Option<u64>,
+    ) -> Result<Vec<ExtendedTransaction>, EthApiError>;
     /// Upserts the given transaction.
     async fn upsert_transaction(&self, transaction: ExtendedTransaction) -> Result<(), EthApiError>;
     /// Upserts the given transaction hash mapping (Ethereum -> Starknet).
     async fn upsert_transaction_hashes(&self, transaction_hashes: EthStarknetHashes) -> Result<(), EthApiError>;
+    /// Returns the relaying status of the Ethereum transaction with the given hash.
+    async fn transaction_status(&self, hash: &B256) -> Result<Option<RelayedTransactionStatus>, EthApiError>;
+    /// Upserts the given relaying status.
+    async fn upsert_transaction_status(&self, status: RelayedTransactionStatus) -> Result<(), EthApiError>;
 }
 
 #[async_trait]
@@ -59,6 +83,42 @@ impl EthereumTransactionStore for Database {
         Ok(self.get::<StoredTransaction>(filter, None).await?.into_iter().map(Into::into).collect())
     }
 
+    #[instrument(skip_all, name = "db::transaction_by_sender_and_nonce", err)]
+    async fn transaction_by_sender_and_nonce(
+        &self,
+        sender: &Address,
+        nonce: u64,
+    ) -> Result<Option<ExtendedTransaction>, EthApiError> {
+        let filter =
+            EthDatabaseFilterBuilder::<filter::Transaction>::default().with_from(sender).with_nonce(nonce).build();
+        Ok(self.get_one::<StoredTransaction>(filter, None).await?.map(Into::into))
+    }
+
+    #[instrument(skip_all, name = "db::transactions_by_address", err)]
+    async fn transactions_by_address(
+        &self,
+        address: &Address,
+        from: u64,
+        to: u64,
+        descending: bool,
+        limit: Option<u64>,
+    ) -> Result<Vec<ExtendedTransaction>, EthApiError> {
+        let filter = EthDatabaseFilterBuilder::<filter::Transaction>::default()
+            .with_block_number_range(from, to)
+            .with_from_or_to(address)
+            .build();
+
+        // The block numbers and transaction indexes are padded, so they can be sorted as strings.
+        let direction = if descending { -1 } else { 1 };
+        let mut find_options =
+            FindOpts::default().with_sort(doc! { "tx.blockNumber": direction, "tx.transactionIndex": direction });
+        if let Some(limit) = limit {
+            find_options = find_options.with_limit(limit);
+        }
+
+        Ok(self.get_and_map_to::<_, StoredTransaction>(filter, Some(find_options)).await?)
+    }
+
     #[instrument(skip_all, name = "db::upsert_transaction", err)]
     async fn upsert_transaction(&self, transaction: ExtendedTransaction) -> Result<(), EthApiError> {
         let filter = EthDatabaseFilterBuilder::<filter::Transaction>::default().with_tx_hash(&transaction.hash).build();
@@ -72,6 +132,20 @@ impl EthereumTransactionStore for Database {
             .build();
         Ok(self.update_one(StoredEthStarknetTransactionHash::from(transaction_hashes), filter, true).await?)
     }
+
+    #[instrument(skip_all, name = "db::transaction_status", err)]
+    async fn transaction_status(&self, hash: &B256) -> Result<Option<RelayedTransactionStatus>, EthApiError> {
+        let filter = EthDatabaseFilterBuilder::<filter::RelayedTransactionStatus>::default().with_tx_hash(hash).build();
+        Ok(self.get_one::<StoredRelayedTransactionStatus>(filter, None).await?.map(|stored| stored.status))
+    }
+
+    #[instrument(skip_all, name = "db::upsert_transaction_status", err)]
+    async fn upsert_transaction_status(&self, status: RelayedTransactionStatus) -> Result<(), EthApiError> {
+        let filter = EthDatabaseFilterBuilder::<filter::RelayedTransactionStatus>::default()
+            .with_tx_hash(&status.eth_hash)
+            .build();
+        Ok(self.update_one(StoredRelayedTransactionStatus::from(status), filter, true).await?)
+    }
 }
 
 /// Trait for interacting with a database that stores Ethereum typed
@@ -83,6 +157,9 @@ pub trait EthereumBlockStore {
     /// Returns the header for the given hash or number. Returns None if the
     /// header is not found.
     async fn header(&self, block_hash_or_number: BlockHashOrNumber) -> Result<Option<Header>, EthApiError>;
+    /// Returns the headers of the blocks in the given inclusive range,
+    /// sorted by block number.
+    async fn headers(&self, from: u64, to: u64) -> Result<Vec<Header>, EthApiError>;
     /// Returns the block for the given hash or number. Returns None if the
     /// block is not found.
     async fn block(
@@ -122,6 +199,14 @@ impl EthereumBlockStore for Database {
             .map(Into::into))
     }
 
+    #[instrument(skip_all, name = "db::headers", err)]
+    async fn headers(&self, from: u64, to: u64) -> Result<Vec<Header>, EthApiError> {
+        let filter = EthDatabaseFilterBuilder::<filter::Header>::default().with_block_number_range(from, to).build();
+        let mut headers: Vec<Header> = self.get_and_map_to::<_, StoredHeader>(filter, None).await?;
+        headers.sort_unstable_by_key(|header| header.number);
+        Ok(headers)
+    }
+
     #[instrument(skip_all, name = "db::block", err)]
     async fn block(
         &self,
@@ -188,7 +273,10 @@ impl EthereumBlockStore for Database {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::mongo::{MongoFuzzer, RANDOM_BYTES_SIZE};
+    use crate::{
+        providers::eth_provider::database::types::transaction::RelayStatus,
+        test_utils::mongo::{MongoFuzzer, RANDOM_BYTES_SIZE},
+    };
     use arbitrary::Arbitrary;
     use rand::{self, Rng};
     use starknet::core::types::Felt;
@@ -214,6 +302,9 @@ mod tests {
         // Test fetching transactions by their block number
         test_get_transactions_by_block_number(&database, &mongo_fuzzer).await;
 
+        // Test fetching transactions by their sender and nonce
+        test_get_transaction_by_sender_and_nonce(&database, &mongo_fuzzer).await;
+
         // Test upserting transactions into the database
         test_upsert_transactions(&mut unstructured, &database).await;
     }
@@ -268,6 +359,22 @@ mod tests {
         assert_eq!(database.transactions(first_block_number.into()).await.unwrap(), transactions_first_block_number);
     }
 
+    async fn test_get_transaction_by_sender_and_nonce(database: &Database, mongo_fuzzer: &MongoFuzzer) {
+        // Fetch the first transaction from the mock database
+        let first_transaction = mongo_fuzzer.transactions.first().unwrap();
+
+        // Test retrieving an existing transaction by its sender and nonce
+        let transaction = database
+            .transaction_by_sender_and_nonce(&first_transaction.from, first_transaction.nonce)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((transaction.from, transaction.nonce), (first_transaction.from, first_transaction.nonce));
+
+        // Test retrieving a non-existent transaction by its sender and nonce
+        assert_eq!(database.transaction_by_sender_and_nonce(&Address::ZERO, u64::MAX).await.unwrap(), None);
+    }
+
     async fn test_upsert_transactions(unstructured: &mut arbitrary::Unstructured<'_>, database: &Database) {
         // Generate and upsert a mock transaction into the database
         let mock_transaction = StoredTransaction::arbitrary(unstructured).unwrap();
@@ -462,4 +569,31 @@ mod tests {
             "The transaction hash mapping was not updated correctly"
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_upsert_transaction_status() {
+        // Given
+        let mut mongo_fuzzer = MongoFuzzer::new(RANDOM_BYTES_SIZE).await;
+        let database = mongo_fuzzer.mock_database(1).await;
+
+        let eth_hash = B256::random();
+        let status = RelayedTransactionStatus {
+            eth_hash,
+            starknet_hash: Felt::from_hex("0x03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb")
+                .unwrap(),
+            status: RelayStatus::Pending,
+            reason: None,
+            retries: 0,
+        };
+
+        // When
+        database.upsert_transaction_status(status.clone()).await.expect("Failed to insert transaction status");
+        let updated_status =
+            RelayedTransactionStatus { status: RelayStatus::Reverted, reason: Some("reverted".to_string()), ..status };
+        database.upsert_transaction_status(updated_status.clone()).await.expect("Failed to update transaction status");
+
+        // Then
+        assert_eq!(database.transaction_status(&eth_hash).await.unwrap(), Some(updated_status));
+        assert_eq!(database.transaction_status(&B256::random()).await.unwrap(), None);
+    }
 }
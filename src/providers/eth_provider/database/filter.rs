@@ -54,6 +54,26 @@ impl TransactionFiltering for EthStarknetTransactionHash {
     }
 }
 
+/// A type used for the status of a relayed transaction.
+#[derive(Debug, Default)]
+pub struct RelayedTransactionStatus;
+
+impl Display for RelayedTransactionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "status")
+    }
+}
+
+impl TransactionFiltering for RelayedTransactionStatus {
+    fn transaction_hash(&self) -> &'static str {
+        "eth_hash"
+    }
+
+    fn transaction_index(&self) -> &'static str {
+        ""
+    }
+}
+
 /// A transaction type used as a target for the filter.
 #[derive(Debug, Default)]
 pub struct Transaction;
@@ -194,6 +214,17 @@ impl<T: BlockFiltering + Display + Default> EthDatabaseFilterBuilder<T> {
             BlockHashOrNumber::Number(number) => self.with_block_number(number),
         }
     }
+
+    /// Adds a filter on the block number range.
+    #[must_use]
+    pub fn with_block_number_range(mut self, from: u64, to: u64) -> Self {
+        let key = format!("{}.{}", self.target, self.target.block_number());
+        self.filter.insert(
+            key,
+            doc! {"$gte": format_hex(from, BLOCK_NUMBER_HEX_STRING_LEN), "$lte": format_hex(to, BLOCK_NUMBER_HEX_STRING_LEN)},
+        );
+        self
+    }
 }
 
 impl<T: TransactionFiltering + Display + Default> EthDatabaseFilterBuilder<T> {
@@ -215,6 +246,45 @@ impl<T: TransactionFiltering + Display + Default> EthDatabaseFilterBuilder<T> {
     }
 }
 
+impl EthDatabaseFilterBuilder<Transaction> {
+    /// Adds a filter on the sender of the transaction.
+    #[must_use]
+    pub fn with_from(mut self, address: &Address) -> Self {
+        let key = format!("{}.from", self.target);
+        self.filter.insert(key, format_hex(address, ADDRESS_HEX_STRING_LEN));
+        self
+    }
+
+    /// Adds a filter on the sender or the recipient of the transaction.
+    #[must_use]
+    pub fn with_from_or_to(mut self, address: &Address) -> Self {
+        let address = format_hex(address, ADDRESS_HEX_STRING_LEN);
+        let (from, to) = (format!("{}.from", self.target), format!("{}.to", self.target));
+        self.filter.insert("$or", vec![doc! {from: &address}, doc! {to: &address}]);
+        self
+    }
+
+    /// Adds a filter on the nonce of the transaction.
+    ///
+    /// The nonce isn't padded in the database.
+    #[must_use]
+    pub fn with_nonce(mut self, nonce: u64) -> Self {
+        let key = format!("{}.nonce", self.target);
+        self.filter.insert(key, format_hex(nonce, 0));
+        self
+    }
+}
+
+impl EthDatabaseFilterBuilder<Receipt> {
+    /// Adds a filter on the address of the contract created by the transaction.
+    #[must_use]
+    pub fn with_contract_address(mut self, address: &Address) -> Self {
+        let key = format!("{}.contractAddress", self.target);
+        self.filter.insert(key, format_hex(address, ADDRESS_HEX_STRING_LEN));
+        self
+    }
+}
+
 impl<T: LogFiltering + BlockFiltering + Display + Default> EthDatabaseFilterBuilder<T> {
     /// Adds a filter on the log address.
     #[must_use]
@@ -230,17 +300,6 @@ impl<T: LogFiltering + BlockFiltering + Display + Default> EthDatabaseFilterBuil
         self
     }
 
-    /// Adds a filter on the block number range.
-    #[must_use]
-    pub fn with_block_number_range(mut self, from: u64, to: u64) -> Self {
-        let key = format!("{}.{}", self.target, self.target.block_number());
-        self.filter.insert(
-            key,
-            doc! {"$gte": format_hex(from, BLOCK_NUMBER_HEX_STRING_LEN), "$lte": format_hex(to, BLOCK_NUMBER_HEX_STRING_LEN)},
-        );
-        self
-    }
-
     /// Adds a filter on the topics.
     #[must_use]
     pub fn with_topics(mut self, topics: &[Topic; 4]) -> Self {
@@ -380,6 +439,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_transaction_sender_and_nonce_filter() {
+        // Given
+        let builder = EthDatabaseFilterBuilder::<Transaction>::default();
+
+        // When
+        let filter = builder.with_from(&Address::left_padding_from(&[1])).with_nonce(10).build();
+
+        // Then
+        assert_eq!(filter, doc! {"tx.from": "0x0000000000000000000000000000000000000001", "tx.nonce": "0xa"});
+    }
+
+    #[test]
+    fn test_transaction_from_or_to_filter() {
+        // Given
+        let builder = EthDatabaseFilterBuilder::<Transaction>::default();
+
+        // When
+        let filter = builder.with_from_or_to(&Address::left_padding_from(&[1])).build();
+
+        // Then
+        assert_eq!(
+            filter,
+            doc! {"$or": [
+                {"tx.from": "0x0000000000000000000000000000000000000001"},
+                {"tx.to": "0x0000000000000000000000000000000000000001"}
+            ]}
+        );
+    }
+
     #[test]
     fn test_receipt_transaction_hash_filter() {
         // Given
@@ -8,7 +8,7 @@ use crate::providers::eth_provider::database::types::{
     header::StoredHeader,
     log::StoredLog,
     receipt::StoredTransactionReceipt,
-    transaction::{StoredEthStarknetTransactionHash, StoredTransaction},
+    transaction::{StoredEthStarknetTransactionHash, StoredRelayedTransactionStatus, StoredTransaction},
 };
 use futures::TryStreamExt;
 use itertools::Itertools;
@@ -40,6 +40,13 @@ impl FindOpts {
         self
     }
 
+    /// Sets the sorting of the documents to retrieve.
+    #[must_use]
+    pub fn with_sort(mut self, sort: Document) -> Self {
+        self.0.sort = Some(sort);
+        self
+    }
+
     /// Builds and returns the `FindOptions`.
     pub fn build(self) -> FindOptions {
         self.0
@@ -245,3 +252,10 @@ impl CollectionName for StoredEthStarknetTransactionHash {
         "transaction_hashes"
     }
 }
+
+/// Implement [`CollectionName`] for [`StoredRelayedTransactionStatus`]
+impl CollectionName for StoredRelayedTransactionStatus {
+    fn collection_name() -> &'static str {
+        "transaction_statuses"
+    }
+}
@@ -1,16 +1,78 @@
-use crate::providers::eth_provider::{error::EthApiError, provider::EthereumProvider};
-use alloy_primitives::{Address, B256, U256};
-use alloy_rpc_types::{serde_helpers::JsonStorageKey, BlockId};
+use crate::providers::eth_provider::{
+    error::EthApiError,
+    provider::{EthApiResult, EthereumProvider},
+};
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_rpc_types::{
+    serde_helpers::JsonStorageKey,
+    state::{AccountOverride, StateOverride},
+    BlockId,
+};
 use reth_revm::{
     db::CacheDB,
     primitives::{AccountInfo, Bytecode},
     DatabaseRef,
 };
+use reth_rpc_eth_types::EthApiError as RethEthApiError;
 use tokio::runtime::Handle;
 
 #[derive(Debug, Clone)]
 pub struct EthCacheDatabase<P: EthereumProvider + Send + Sync>(pub CacheDB<EthDatabase<P>>);
 
+impl<P: EthereumProvider + Send + Sync> EthCacheDatabase<P> {
+    /// Applies the [`StateOverride`] to the cached state.
+    ///
+    /// For each account, the balance, nonce and code are overridden if provided. The storage is
+    /// either fully replaced (`state`) or patched slot by slot (`stateDiff`).
+    pub fn apply_state_overrides(&mut self, overrides: StateOverride) -> EthApiResult<()> {
+        for (address, account_override) in overrides {
+            self.apply_account_override(address, account_override)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the [`AccountOverride`] to the account at the given address.
+    fn apply_account_override(&mut self, address: Address, account_override: AccountOverride) -> EthApiResult<()> {
+        let AccountOverride { balance, nonce, code, state, state_diff, .. } = account_override;
+
+        let mut account_info = self.0.basic_ref(address)?.unwrap_or_default();
+        if let Some(balance) = balance {
+            account_info.balance = balance;
+        }
+        if let Some(nonce) = nonce {
+            account_info.nonce = nonce;
+        }
+        if let Some(code) = code {
+            account_info.code_hash = keccak256(&code);
+            account_info.code = Some(Bytecode::new_raw(code));
+        }
+        self.0.insert_account_info(address, account_info);
+
+        match (state, state_diff) {
+            (Some(_), Some(_)) => return Err(RethEthApiError::BothStateAndStateDiffInOverride(address).into()),
+            (Some(state), None) => {
+                let storage = state
+                    .into_iter()
+                    .map(|(slot, value)| (U256::from_be_bytes(slot.0), U256::from_be_bytes(value.0)))
+                    .collect();
+                self.0.replace_account_storage(address, storage)?;
+            }
+            (None, Some(state_diff)) => {
+                for (slot, value) in state_diff {
+                    self.0.insert_account_storage(
+                        address,
+                        U256::from_be_bytes(slot.0),
+                        U256::from_be_bytes(value.0),
+                    )?;
+                }
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
+
 /// Ethereum database type.
 #[derive(Debug, Clone)]
 pub struct EthDatabase<P: EthereumProvider + Send + Sync> {
@@ -1,6 +1,8 @@
+use crate::eth_rpc::openrpc::schema;
 use alloy_primitives::B256;
 use alloy_rpc_types::Transaction;
 use alloy_serde::WithOtherFields;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use starknet::core::types::Felt;
 use std::ops::Deref;
@@ -40,6 +42,54 @@ pub struct EthStarknetHashes {
     pub starknet_hash: Felt,
 }
 
+/// The status of a relayed transaction as stored in the database.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct StoredRelayedTransactionStatus {
+    /// Contains the status of the relayed transaction.
+    #[serde(deserialize_with = "crate::providers::eth_provider::database::types::serde::deserialize_intermediate")]
+    pub status: RelayedTransactionStatus,
+}
+
+impl From<RelayedTransactionStatus> for StoredRelayedTransactionStatus {
+    fn from(status: RelayedTransactionStatus) -> Self {
+        Self { status }
+    }
+}
+
+/// The outcome of the relaying of an Ethereum transaction on Starknet.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, JsonSchema)]
+pub struct RelayedTransactionStatus {
+    /// The Ethereum transaction hash.
+    #[schemars(with = "schema::Bytes32")]
+    pub eth_hash: B256,
+    /// The hash of the last Starknet transaction the Ethereum transaction was relayed with, zero if
+    /// the transaction was rejected before being sent.
+    #[schemars(with = "schema::Felt")]
+    pub starknet_hash: Felt,
+    /// The status of the last Starknet transaction.
+    pub status: RelayStatus,
+    /// The reason of the failure of the last Starknet transaction, if any.
+    pub reason: Option<String>,
+    /// The number of times the Ethereum transaction was re-queued after a failure.
+    pub retries: u32,
+}
+
+/// The status of a Starknet transaction relaying an Ethereum transaction.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayStatus {
+    /// The Starknet transaction was sent and has no receipt yet.
+    Pending,
+    /// The Starknet transaction succeeded.
+    Succeeded,
+    /// The Starknet transaction was reverted.
+    Reverted,
+    /// The Starknet transaction was never included.
+    Rejected,
+    /// The Starknet transaction failed and the Ethereum transaction was put back in the pool.
+    Requeued,
+}
+
 /// A full transaction as stored in the database
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct StoredTransaction {
@@ -1,10 +1,11 @@
-use alloy_primitives::{Bytes, B256};
+use alloy_primitives::{Bytes, B256, U64};
 use alloy_rpc_types::BlockHashOrNumber;
 use alloy_sol_types::decode_revert_reason;
 use jsonrpsee::types::ErrorObject;
 use num_traits::cast::ToPrimitive;
+use reth_revm::primitives::HaltReason;
 use reth_rpc_eth_types::{error::ToRpcError, EthApiError as RethEthApiError};
-use reth_transaction_pool::error::PoolError;
+use reth_transaction_pool::error::{PoolError, PoolErrorKind};
 use starknet::core::types::Felt;
 use thiserror::Error;
 
@@ -32,14 +33,23 @@ pub enum EthRpcErrorCode {
 impl From<&EthApiError> for EthRpcErrorCode {
     fn from(error: &EthApiError) -> Self {
         match error {
-            EthApiError::UnknownBlock(_) | EthApiError::UnknownBlockNumber(_) | EthApiError::TransactionNotFound(_) => {
-                Self::ResourceNotFound
-            }
+            EthApiError::UnknownBlock(_)
+            | EthApiError::UnknownBlockNumber(_)
+            | EthApiError::TransactionNotFound(_)
+            | EthApiError::FilterNotFound(_) => Self::ResourceNotFound,
             EthApiError::Signature(_)
             | EthApiError::EthereumDataFormat(_)
             | EthApiError::CalldataExceededLimit(_, _)
             | EthApiError::RethEthApi(_) => Self::InvalidParams,
             EthApiError::Transaction(err) => err.into(),
+            EthApiError::Pool(err)
+                if matches!(
+                    err.kind,
+                    PoolErrorKind::InvalidTransaction(_) | PoolErrorKind::SpammerExceededCapacity(_)
+                ) =>
+            {
+                Self::TransactionRejected
+            }
             // TODO improve the error
             EthApiError::Unsupported(_) | EthApiError::Kakarot(_) | EthApiError::Pool(_) => Self::InternalError,
             EthApiError::Execution(_) => Self::ExecutionError,
@@ -62,6 +72,8 @@ pub enum EthApiError {
     UnknownBlockNumber(Option<u64>),
     /// When a transaction is not found
     TransactionNotFound(B256),
+    /// When a filter is not found
+    FilterNotFound(U64),
     /// Error related to transaction
     Transaction(#[from] TransactionError),
     /// Error related to transaction pool
@@ -88,6 +100,7 @@ impl std::fmt::Display for EthApiError {
             Self::UnknownBlock(block) => write!(f, "unknown block {block}"),
             Self::UnknownBlockNumber(block) => write!(f, "unknown block number {block:?}"),
             Self::TransactionNotFound(tx) => write!(f, "transaction not found {tx}"),
+            Self::FilterNotFound(id) => write!(f, "filter not found {id}"),
             Self::Transaction(err) => write!(f, "{err}"),
             Self::Pool(err) => write!(f, "{err}"),
             Self::Signature(err) => write!(f, "{err}"),
@@ -176,6 +189,27 @@ impl From<cainome::cairo_serde::Error> for ExecutionError {
     }
 }
 
+impl From<HaltReason> for ExecutionError {
+    fn from(reason: HaltReason) -> Self {
+        match reason {
+            HaltReason::OutOfGas(_) => Self::Evm(EvmError::OutOfGas),
+            HaltReason::OpcodeNotFound | HaltReason::InvalidFEOpcode | HaltReason::NotActivated => {
+                Self::Evm(EvmError::UnknownOpcode)
+            }
+            HaltReason::InvalidJump => Self::Evm(EvmError::InvalidJumpDest),
+            HaltReason::StackOverflow => Self::Evm(EvmError::StackOverflow),
+            HaltReason::StackUnderflow => Self::Evm(EvmError::StackUnderflow),
+            HaltReason::OutOfOffset => Self::Evm(EvmError::OutOfBoundsRead),
+            HaltReason::CreateCollision => Self::Evm(EvmError::AddressCollision),
+            HaltReason::StateChangeDuringStaticCall | HaltReason::CallNotAllowedInsideStatic => {
+                Self::Evm(EvmError::StateModification)
+            }
+            HaltReason::OutOfFunds => Self::Evm(EvmError::Balance),
+            reason => Self::Other(format!("{reason:?}")),
+        }
+    }
+}
+
 impl std::fmt::Display for ExecutionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("execution reverted")?;
@@ -315,8 +349,14 @@ pub enum TransactionError {
     #[error("tracing error: {0}")]
     Tracing(Box<dyn std::error::Error + Send + Sync>),
     /// Thrown if the call with state or block overrides fails
-    #[error("tracing error: {0}")]
+    #[error("call error: {0}")]
     Call(Box<dyn std::error::Error + Send + Sync>),
+    /// Thrown if the pre-flight simulation of the relayed transaction fails
+    #[error("simulation error: {0}")]
+    Simulation(#[from] SimulationError),
+    /// Thrown when no relayer has enough funds to relay the transaction
+    #[error("no relayer has enough funds to relay the transaction")]
+    NoFundedRelayer,
 }
 
 impl From<&TransactionError> for EthRpcErrorCode {
@@ -325,16 +365,44 @@ impl From<&TransactionError> for EthRpcErrorCode {
             TransactionError::InvalidChainId | TransactionError::InvalidTransactionType => Self::InvalidInput,
             TransactionError::GasOverflow
             | TransactionError::FeeCapTooLow(_, _)
-            | TransactionError::TipAboveFeeCap(_, _) => Self::TransactionRejected,
+            | TransactionError::TipAboveFeeCap(_, _)
+            | TransactionError::Simulation(_) => Self::TransactionRejected,
             TransactionError::ExpectedFullTransactions
             | TransactionError::Tracing(_)
             | TransactionError::Call(_)
             | TransactionError::Broadcast(_)
             | TransactionError::ExceedsBlockGasLimit(_, _) => Self::InternalError,
+            TransactionError::NoFundedRelayer => Self::ResourceUnavailable,
         }
     }
 }
 
+/// Error related to the pre-flight simulation of a relayed transaction.
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    /// Thrown when the EVM transaction reverts.
+    #[error("evm revert: {0}")]
+    EvmRevert(EvmError),
+    /// Thrown when the Cairo execution of the transaction fails.
+    #[error("cairo failure: {0}")]
+    CairoFailure(String),
+    /// Thrown when the relayer can't pay for the transaction.
+    #[error("fee error: {0}")]
+    Fee(String),
+    /// Thrown when the Cairo execution of the transaction fails during the fee estimation. The
+    /// estimation runs on the pending state, which can lag behind the transactions already sent,
+    /// e.g. the previous transaction of the sender.
+    #[error("fee estimation error: {0}")]
+    FeeEstimation(String),
+}
+
+impl SimulationError {
+    /// Returns `true` if the transaction would fail whichever relayer relays it.
+    pub const fn is_doomed(&self) -> bool {
+        !matches!(self, Self::Fee(_) | Self::FeeEstimation(_))
+    }
+}
+
 /// Error related to signature.
 #[derive(Debug, Error)]
 pub enum SignatureError {
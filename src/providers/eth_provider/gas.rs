@@ -1,12 +1,14 @@
 use super::{
-    constant::BLOCK_NUMBER_HEX_STRING_LEN,
     error::{ExecutionError, KakarotError},
-    starknet::kakarot_core::{core::KakarotCoreReader, KAKAROT_ADDRESS},
+    starknet::kakarot_core::core::KakarotCoreReader,
 };
 use crate::{
     into_via_wrapper,
     providers::eth_provider::{
-        database::{filter::format_hex, types::header::StoredHeader},
+        database::{
+            filter::{self, EthDatabaseFilterBuilder},
+            types::{header::StoredHeader, receipt::StoredTransactionReceipt},
+        },
         provider::{EthApiResult, EthDataProvider},
     },
 };
@@ -16,7 +18,8 @@ use alloy_rpc_types::{FeeHistory, TransactionRequest};
 use async_trait::async_trait;
 use auto_impl::auto_impl;
 use eyre::eyre;
-use mongodb::bson::doc;
+use reth_rpc_eth_types::EthApiError as RethEthApiError;
+use std::collections::HashMap;
 use tracing::Instrument;
 
 #[async_trait]
@@ -59,20 +62,34 @@ where
         &self,
         block_count: U64,
         newest_block: BlockNumberOrTag,
-        _reward_percentiles: Option<Vec<f64>>,
+        reward_percentiles: Option<Vec<f64>>,
     ) -> EthApiResult<FeeHistory> {
         if block_count == U64::ZERO {
             return Ok(FeeHistory::default());
         }
 
+        // Percentiles must be monotonically increasing values between 0 and 100.
+        if let Some(percentiles) = &reward_percentiles {
+            if percentiles.windows(2).any(|w| w[0] > w[1]) || percentiles.iter().any(|p| !(0. ..=100.).contains(p)) {
+                return Err(RethEthApiError::InvalidRewardPercentiles.into());
+            }
+        }
+
         let end_block = self.tag_into_block_number(newest_block).await?;
         let end_block_plus_one = end_block.saturating_add(1);
 
         // 0 <= start_block <= end_block
         let start_block = end_block_plus_one.saturating_sub(block_count.to());
 
-        let header_filter = doc! {"$and": [ { "header.number": { "$gte": format_hex(start_block, BLOCK_NUMBER_HEX_STRING_LEN) } }, { "header.number": { "$lte": format_hex(end_block, BLOCK_NUMBER_HEX_STRING_LEN) } } ] };
-        let blocks: Vec<StoredHeader> = self.database().get(header_filter, None).await?;
+        // The header following the range (if any) is fetched along with the range, as it holds the
+        // next base fee.
+        let header_filter = EthDatabaseFilterBuilder::<filter::Header>::default()
+            .with_block_number_range(start_block, end_block_plus_one)
+            .build();
+        let mut blocks: Vec<StoredHeader> = self.database().get(header_filter, None).await?;
+        blocks.sort_unstable_by_key(|header| header.number);
+        let next_block =
+            if blocks.last().is_some_and(|header| header.number == end_block_plus_one) { blocks.pop() } else { None };
 
         if blocks.is_empty() {
             return Err(
@@ -92,25 +109,130 @@ where
             })
             .collect();
 
-        let mut base_fee_per_gas =
-            blocks.iter().map(|header| header.base_fee_per_gas.unwrap_or_default()).collect::<Vec<_>>();
-        // TODO(EIP1559): Remove this when proper base fee computation: if gas_ratio > 50%, increase base_fee_per_gas
-        base_fee_per_gas.extend_from_within((base_fee_per_gas.len() - 1)..);
-
-        Ok(FeeHistory {
-            base_fee_per_gas: base_fee_per_gas.into_iter().map(Into::into).collect(),
-            gas_used_ratio,
-            oldest_block: start_block,
-            reward: Some(vec![]),
-            ..Default::default()
-        })
+        // Kakarot has no EIP-1559 base fee adjustment: the base fee is set on the Kakarot core contract and
+        // applies to the next block as is.
+        let next_base_fee = match next_block {
+            Some(header) => header.base_fee_per_gas.unwrap_or_default().into(),
+            None => self.gas_price().await?.to(),
+        };
+        let base_fee_per_gas = blocks
+            .iter()
+            .map(|header| u128::from(header.base_fee_per_gas.unwrap_or_default()))
+            .chain(std::iter::once(next_base_fee))
+            .collect();
+
+        let reward = match reward_percentiles {
+            Some(percentiles) => Some(self.rewards(&blocks, start_block, end_block, &percentiles).await?),
+            None => None,
+        };
+
+        Ok(FeeHistory { base_fee_per_gas, gas_used_ratio, oldest_block: start_block, reward, ..Default::default() })
     }
 
     async fn gas_price(&self) -> EthApiResult<U256> {
-        let kakarot_contract = KakarotCoreReader::new(*KAKAROT_ADDRESS, self.starknet_provider_inner());
+        let kakarot_contract =
+            KakarotCoreReader::new(self.deployment().kakarot_address, self.starknet_provider_inner());
         let span = tracing::span!(tracing::Level::INFO, "sn::base_fee");
         let gas_price =
             kakarot_contract.get_base_fee().call().instrument(span).await.map_err(ExecutionError::from)?.base_fee;
         Ok(into_via_wrapper!(gas_price))
     }
 }
+
+impl<SP> EthDataProvider<SP>
+where
+    SP: starknet::providers::Provider + Send + Sync,
+{
+    /// Returns the effective priority fee percentiles of each of the given blocks, weighted by gas used.
+    async fn rewards(
+        &self,
+        blocks: &[StoredHeader],
+        start_block: u64,
+        end_block: u64,
+        percentiles: &[f64],
+    ) -> EthApiResult<Vec<Vec<u128>>> {
+        let receipt_filter = EthDatabaseFilterBuilder::<filter::Receipt>::default()
+            .with_block_number_range(start_block, end_block)
+            .build();
+        let receipts: Vec<StoredTransactionReceipt> = self.database().get(receipt_filter, None).await?;
+
+        // Group the gas used and the effective priority fee of the receipts by block number.
+        let mut receipts_by_block = HashMap::<u64, Vec<(u128, u128)>>::new();
+        for StoredTransactionReceipt { receipt } in receipts {
+            let Some(block_number) = receipt.block_number else { continue };
+            receipts_by_block.entry(block_number).or_default().push((receipt.gas_used, receipt.effective_gas_price));
+        }
+
+        Ok(blocks
+            .iter()
+            .map(|header| {
+                let base_fee = header.base_fee_per_gas.unwrap_or_default().into();
+                let mut block_rewards = receipts_by_block
+                    .remove(&header.number)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(gas_used, effective_gas_price)| (gas_used, effective_gas_price.saturating_sub(base_fee)))
+                    .collect::<Vec<_>>();
+                calculate_reward_percentiles(&mut block_rewards, header.gas_used.into(), percentiles)
+            })
+            .collect())
+    }
+}
+
+/// Returns the reward at each of the percentiles, weighted by gas used, given the
+/// `(gas_used, reward)` pairs of the transactions in a block.
+///
+/// Blocks without transactions have a reward of zero for every percentile.
+fn calculate_reward_percentiles(rewards: &mut [(u128, u128)], block_gas_used: u128, percentiles: &[f64]) -> Vec<u128> {
+    if rewards.is_empty() {
+        return vec![0; percentiles.len()];
+    }
+
+    rewards.sort_unstable_by_key(|(_, reward)| *reward);
+
+    let mut index = 0;
+    let mut cumulative_gas_used = rewards[0].0;
+    percentiles
+        .iter()
+        .map(|percentile| {
+            // The percentiles are validated to be in [0, 100], so the threshold is positive and
+            // at most the block gas used.
+            #[allow(clippy::cast_sign_loss)]
+            let threshold = (block_gas_used as f64 * percentile / 100.) as u128;
+            while cumulative_gas_used < threshold && index < rewards.len() - 1 {
+                index += 1;
+                cumulative_gas_used += rewards[index].0;
+            }
+            rewards[index].1
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_reward_percentiles() {
+        // Given
+        let mut rewards = vec![(30_000, 3), (10_000, 1), (60_000, 2)];
+
+        // When
+        let percentiles = calculate_reward_percentiles(&mut rewards, 100_000, &[0., 10., 11., 70., 71., 100.]);
+
+        // Then
+        assert_eq!(percentiles, vec![1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_calculate_reward_percentiles_empty_block() {
+        // Given
+        let mut rewards = vec![];
+
+        // When
+        let percentiles = calculate_reward_percentiles(&mut rewards, 0, &[25., 50., 75.]);
+
+        // Then
+        assert_eq!(percentiles, vec![0, 0, 0]);
+    }
+}
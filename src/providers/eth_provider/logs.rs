@@ -1,5 +1,4 @@
 use super::{
-    constant::MAX_LOGS,
     database::{filter::EthDatabaseFilterBuilder, types::log::StoredLog},
     error::EthApiError,
 };
@@ -67,7 +66,7 @@ where
             self.database()
                 .get_and_map_to::<_, StoredLog>(
                     builder.build(),
-                    (*MAX_LOGS).map(|limit| FindOpts::default().with_limit(limit)),
+                    self.limits().max_logs.map(|limit| FindOpts::default().with_limit(limit)),
                 )
                 .await?,
         ))
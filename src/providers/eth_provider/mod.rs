@@ -8,6 +8,7 @@ pub mod gas;
 pub mod logs;
 pub mod provider;
 pub mod receipts;
+pub mod revm_utils;
 pub mod starknet;
 pub mod state;
 pub mod transactions;
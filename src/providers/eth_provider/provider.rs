@@ -5,11 +5,11 @@ use super::{
     starknet::kakarot_core::{
         self,
         core::{CallInput, KakarotCoreReader, Uint256},
-        KAKAROT_ADDRESS,
+        KakarotDeployment,
     },
 };
 use crate::{
-    constants::ETH_CHAIN_ID,
+    config::LimitsConfig,
     into_via_try_wrapper, into_via_wrapper,
     models::block::{EthBlockId, EthBlockNumberOrTag},
     providers::{
@@ -30,11 +30,8 @@ use tracing::{instrument, Instrument};
 #[cfg(feature = "hive")]
 use {
     crate::providers::eth_provider::error::SignatureError,
-    crate::providers::eth_provider::starknet::kakarot_core::{
-        account_contract::AccountContractReader, starknet_address,
-    },
-    crate::providers::eth_provider::utils::contract_not_found,
-    alloy_primitives::Address,
+    crate::providers::eth_provider::starknet::kakarot_core::account_contract::AccountContractReader,
+    crate::providers::eth_provider::utils::contract_not_found, alloy_primitives::Address,
 };
 
 /// A type alias representing a result type for Ethereum API operations.
@@ -61,7 +58,8 @@ impl<T> EthereumProvider for T where
 pub struct EthDataProvider<SP: starknet::providers::Provider + Send + Sync> {
     database: Database,
     starknet_provider: StarknetProvider<SP>,
-    pub chain_id: u64,
+    deployment: KakarotDeployment,
+    limits: LimitsConfig,
 }
 
 impl<SP> EthDataProvider<SP>
@@ -82,14 +80,29 @@ where
     pub fn starknet_provider_inner(&self) -> &SP {
         &self.starknet_provider
     }
+
+    /// Returns a reference to the Kakarot deployment served by the provider.
+    pub const fn deployment(&self) -> &KakarotDeployment {
+        &self.deployment
+    }
+
+    /// Returns a reference to the limits of the requests served by the provider.
+    pub const fn limits(&self) -> &LimitsConfig {
+        &self.limits
+    }
 }
 
 impl<SP> EthDataProvider<SP>
 where
     SP: starknet::providers::Provider + Send + Sync,
 {
-    pub fn new(database: Database, starknet_provider: StarknetProvider<SP>) -> Self {
-        Self { database, starknet_provider, chain_id: *ETH_CHAIN_ID }
+    pub const fn new(
+        database: Database,
+        starknet_provider: StarknetProvider<SP>,
+        deployment: KakarotDeployment,
+        limits: LimitsConfig,
+    ) -> Self {
+        Self { database, starknet_provider, deployment, limits }
     }
 
     /// Prepare the call input for an estimate gas or call from a transaction request.
@@ -155,7 +168,7 @@ where
         let starknet_block_id = self.to_starknet_block_id(block_id).await?;
         let call_input = self.prepare_call_input(request, block_id).await?;
 
-        let kakarot_contract = KakarotCoreReader::new(*KAKAROT_ADDRESS, self.starknet_provider_inner());
+        let kakarot_contract = KakarotCoreReader::new(self.deployment.kakarot_address, self.starknet_provider_inner());
         let span = tracing::span!(tracing::Level::INFO, "sn::eth_call");
         let call_output = kakarot_contract
             .eth_call(
@@ -192,7 +205,7 @@ where
         let starknet_block_id = self.to_starknet_block_id(block_id).await?;
         let call_input = self.prepare_call_input(request, block_id).await?;
 
-        let kakarot_contract = KakarotCoreReader::new(*KAKAROT_ADDRESS, self.starknet_provider_inner());
+        let kakarot_contract = KakarotCoreReader::new(self.deployment.kakarot_address, self.starknet_provider_inner());
         let span = tracing::span!(tracing::Level::INFO, "sn::eth_estimate_gas");
         let estimate_gas_output = kakarot_contract
             .eth_estimate_gas(
@@ -295,18 +308,18 @@ where
     /// Starknet.
     pub(crate) async fn deploy_evm_transaction_signer(&self, signer: Address) -> EthApiResult<()> {
         use crate::providers::eth_provider::{
-            constant::hive::{DEPLOY_WALLET, DEPLOY_WALLET_NONCE},
-            error::EthereumDataFormatError,
+            constant::hive::{deploy_wallet, DEPLOY_WALLET_NONCE},
+            error::{EthereumDataFormatError, KakarotError},
         };
         use starknet::{
-            accounts::ExecutionV1,
+            accounts::{ConnectedAccount, ExecutionV1},
             core::{
                 types::{BlockTag, Call},
                 utils::get_selector_from_name,
             },
         };
 
-        let signer_starknet_address = starknet_address(signer);
+        let signer_starknet_address = self.deployment.starknet_address(signer);
         let account_contract = AccountContractReader::new(signer_starknet_address, self.starknet_provider_inner());
         let maybe_is_initialized = account_contract
             .is_initialized()
@@ -315,17 +328,21 @@ where
             .await;
 
         if contract_not_found(&maybe_is_initialized) {
+            let deploy_wallet = deploy_wallet(&self.deployment);
             let execution = ExecutionV1::new(
                 vec![Call {
-                    to: *KAKAROT_ADDRESS,
+                    to: self.deployment.kakarot_address,
                     selector: get_selector_from_name("deploy_externally_owned_account").unwrap(),
                     calldata: vec![into_via_wrapper!(signer)],
                 }],
-                &*DEPLOY_WALLET,
+                &deploy_wallet,
             );
 
             let mut nonce = DEPLOY_WALLET_NONCE.lock().await;
-            let current_nonce = *nonce;
+            let current_nonce = match *nonce {
+                Some(nonce) => nonce,
+                None => deploy_wallet.get_nonce().await.map_err(KakarotError::from)?,
+            };
 
             let prepared_execution = execution
                 .nonce(current_nonce)
@@ -335,7 +352,7 @@ where
 
             let _ = prepared_execution.send().await.map_err(|_| SignatureError::SigningFailure)?;
 
-            *nonce += Felt::ONE;
+            *nonce = Some(current_nonce + Felt::ONE);
             drop(nonce);
         };
 
@@ -8,7 +8,7 @@ use crate::providers::eth_provider::{
     provider::{EthApiResult, EthDataProvider},
 };
 use alloy_eips::{BlockId, BlockNumberOrTag};
-use alloy_primitives::B256;
+use alloy_primitives::{Address, B256};
 use async_trait::async_trait;
 use auto_impl::auto_impl;
 use mongodb::bson::doc;
@@ -21,6 +21,9 @@ pub trait ReceiptProvider {
 
     /// Returns the block receipts for a block.
     async fn block_receipts(&self, block_id: Option<BlockId>) -> EthApiResult<Option<Vec<ExtendedTxReceipt>>>;
+
+    /// Returns the receipt of the transaction that deployed the contract at the address.
+    async fn contract_creation_receipt(&self, address: Address) -> EthApiResult<Option<ExtendedTxReceipt>>;
 }
 
 #[async_trait]
@@ -56,4 +59,9 @@ where
             }
         }
     }
+
+    async fn contract_creation_receipt(&self, address: Address) -> EthApiResult<Option<ExtendedTxReceipt>> {
+        let filter = EthDatabaseFilterBuilder::<filter::Receipt>::default().with_contract_address(&address).build();
+        Ok(self.database().get_one::<StoredTransactionReceipt>(filter, None).await?.map(Into::into))
+    }
 }
@@ -0,0 +1,194 @@
+use super::{
+    database::state::EthCacheDatabase,
+    error::{EvmError, ExecutionError},
+    provider::{EthApiResult, EthereumProvider},
+};
+use alloy_primitives::{Address, Bytes, TxKind, B256, U256};
+use alloy_rpc_types::{BlockOverrides, Header, TransactionRequest};
+use reth_revm::{
+    precompile::{PrecompileSpecId, Precompiles},
+    primitives::{BlockEnv, CfgEnv, ExecutionResult, SpecId, TxEnv},
+};
+
+/// Returns the [`CfgEnv`] used to execute calls (`eth_call`, `debug_traceCall`, ...).
+///
+/// Mirrors the behavior of other node implementations: the block gas limit, the base fee and
+/// EIP-3607 (rejecting transactions from senders with deployed code) are not enforced.
+pub fn call_cfg_env(chain_id: u64) -> CfgEnv {
+    let mut cfg = CfgEnv::default().with_chain_id(chain_id);
+    cfg.disable_block_gas_limit = true;
+    cfg.disable_eip3607 = true;
+    cfg.disable_base_fee = true;
+    cfg
+}
+
+/// Returns the [`BlockEnv`] for the given header, using the provided block gas limit.
+pub fn block_env(header: &Header, gas_limit: u64) -> BlockEnv {
+    BlockEnv {
+        number: U256::from(header.number),
+        timestamp: U256::from(header.timestamp),
+        gas_limit: U256::from(gas_limit),
+        coinbase: header.miner,
+        basefee: U256::from(header.base_fee_per_gas.unwrap_or_default()),
+        prevrandao: Some(B256::from_slice(&header.difficulty.to_be_bytes::<32>()[..])),
+        ..Default::default()
+    }
+}
+
+/// Converts a [`TransactionRequest`] into a [`TxEnv`], using the provided gas limit.
+///
+/// If no gas price is provided, the call is executed with a zero gas price.
+pub fn tx_env_from_request(request: TransactionRequest, gas_limit: u64) -> TxEnv {
+    let TransactionRequest {
+        from,
+        to,
+        gas_price,
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+        value,
+        input,
+        nonce,
+        chain_id,
+        access_list,
+        ..
+    } = request;
+
+    TxEnv {
+        caller: from.unwrap_or_default(),
+        gas_limit,
+        gas_price: U256::from(gas_price.or(max_fee_per_gas).unwrap_or_default()),
+        gas_priority_fee: max_priority_fee_per_gas.map(U256::from),
+        transact_to: to.unwrap_or(TxKind::Create),
+        value: value.unwrap_or_default(),
+        data: input.into_input().unwrap_or_default(),
+        nonce,
+        chain_id,
+        access_list: access_list.unwrap_or_default().0,
+        ..Default::default()
+    }
+}
+
+/// Returns the addresses of the precompiles active for the given [`SpecId`].
+pub fn precompiles(spec_id: SpecId) -> impl Iterator<Item = Address> {
+    Precompiles::new(PrecompileSpecId::from_spec_id(spec_id)).addresses().copied()
+}
+
+/// Applies the [`BlockOverrides`] to the block environment and the database.
+pub fn apply_block_overrides<P: EthereumProvider + Send + Sync>(
+    overrides: BlockOverrides,
+    db: &mut EthCacheDatabase<P>,
+    env: &mut BlockEnv,
+) {
+    let BlockOverrides { number, difficulty, time, gas_limit, coinbase, random, base_fee, block_hash } = overrides;
+
+    if let Some(block_hashes) = block_hash {
+        // Override the block hashes returned by the BLOCKHASH opcode.
+        db.0.block_hashes.extend(block_hashes.into_iter().map(|(number, hash)| (U256::from(number), hash)));
+    }
+    if let Some(number) = number {
+        env.number = number;
+    }
+    if let Some(difficulty) = difficulty {
+        env.difficulty = difficulty;
+    }
+    if let Some(time) = time {
+        env.timestamp = U256::from(time);
+    }
+    if let Some(gas_limit) = gas_limit {
+        env.gas_limit = U256::from(gas_limit);
+    }
+    if let Some(coinbase) = coinbase {
+        env.coinbase = coinbase;
+    }
+    if let Some(random) = random {
+        env.prevrandao = Some(random);
+    }
+    if let Some(base_fee) = base_fee {
+        env.basefee = base_fee;
+    }
+}
+
+/// Returns the output of a successful execution, or the corresponding
+/// [`ExecutionError`] if the execution reverted or halted.
+///
+/// The revert data is kept in the error in order to be returned to the caller.
+pub fn ensure_success(result: ExecutionResult) -> EthApiResult<Bytes> {
+    match result {
+        ExecutionResult::Success { output, .. } => Ok(output.into_data()),
+        ExecutionResult::Revert { output, .. } => Err(ExecutionError::Evm(EvmError::Other(output)).into()),
+        ExecutionResult::Halt { reason, .. } => Err(ExecutionError::from(reason).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::eth_provider::error::EthApiError;
+    use alloy_primitives::{address, bytes};
+    use alloy_rpc_types::TransactionInput;
+    use reth_revm::primitives::{HaltReason, Output, SuccessReason};
+
+    #[test]
+    fn test_tx_env_from_request() {
+        // Given
+        let request = TransactionRequest {
+            from: Some(address!("95222290dd7278aa3ddd389cc1e1d165cc4bafe5")),
+            to: Some(TxKind::Call(address!("388c818ca8b9251b393131c08a736a67ccb19297"))),
+            max_fee_per_gas: Some(10),
+            max_priority_fee_per_gas: Some(1),
+            value: Some(U256::from(5)),
+            input: TransactionInput::new(bytes!("deadbeef")),
+            nonce: Some(3),
+            ..Default::default()
+        };
+
+        // When
+        let tx_env = tx_env_from_request(request, 21000);
+
+        // Then
+        assert_eq!(tx_env.caller, address!("95222290dd7278aa3ddd389cc1e1d165cc4bafe5"));
+        assert_eq!(tx_env.transact_to, TxKind::Call(address!("388c818ca8b9251b393131c08a736a67ccb19297")));
+        assert_eq!(tx_env.gas_limit, 21000);
+        assert_eq!(tx_env.gas_price, U256::from(10));
+        assert_eq!(tx_env.gas_priority_fee, Some(U256::from(1)));
+        assert_eq!(tx_env.value, U256::from(5));
+        assert_eq!(tx_env.data, bytes!("deadbeef"));
+        assert_eq!(tx_env.nonce, Some(3));
+    }
+
+    #[test]
+    fn test_tx_env_from_empty_request() {
+        // When
+        let tx_env = tx_env_from_request(TransactionRequest::default(), 21000);
+
+        // Then
+        assert_eq!(tx_env.transact_to, TxKind::Create);
+        assert_eq!(tx_env.gas_price, U256::ZERO);
+        assert_eq!(tx_env.nonce, None);
+    }
+
+    #[test]
+    fn test_ensure_success() {
+        // Given
+        let success = ExecutionResult::Success {
+            reason: SuccessReason::Return,
+            gas_used: 0,
+            gas_refunded: 0,
+            logs: vec![],
+            output: Output::Call(bytes!("01")),
+        };
+        let revert = ExecutionResult::Revert { gas_used: 0, output: bytes!("02") };
+        let halt = ExecutionResult::Halt { reason: HaltReason::StackOverflow, gas_used: 0 };
+
+        // When/Then
+        assert_eq!(ensure_success(success).unwrap(), bytes!("01"));
+        assert!(matches!(
+            ensure_success(revert),
+            Err(EthApiError::Execution(ExecutionError::Evm(EvmError::Other(data)))) if data == bytes!("02")
+        ));
+        assert!(matches!(
+            ensure_success(halt),
+            Err(EthApiError::Execution(ExecutionError::Evm(EvmError::StackOverflow)))
+        ));
+    }
+}
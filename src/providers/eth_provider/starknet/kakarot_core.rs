@@ -1,14 +1,19 @@
 #![allow(clippy::too_many_arguments)]
 
-use crate::into_via_wrapper;
-use alloy_primitives::{Address, B256};
+use crate::{config::KakarotRpcConfig, constants::MAX_CHAIN_ID, into_via_wrapper};
+use alloy_primitives::Address;
 use cainome::rs::abigen_legacy;
-use dotenvy::dotenv;
+use num_traits::ToPrimitive;
 use starknet::{
-    core::{types::Felt, utils::get_contract_address},
+    core::{
+        types::{Felt, NonZeroFelt},
+        utils::get_contract_address,
+    },
     macros::selector,
+    providers::{Provider, ProviderError},
 };
-use std::{str::FromStr, sync::LazyLock};
+use std::sync::LazyLock;
+use url::Url;
 // Contract ABIs
 
 pub mod account_contract {
@@ -33,48 +38,67 @@ pub mod core {
     }
 }
 
-fn env_var_to_field_element(var_name: &str) -> Felt {
-    dotenv().ok();
-    let env_var = std::env::var(var_name).unwrap_or_else(|_| panic!("Missing environment variable {var_name}"));
-
-    Felt::from_str(&env_var).unwrap_or_else(|_| panic!("Invalid hex string for {var_name}"))
+/// A Kakarot deployment: the Starknet network and the Kakarot contracts deployed on it.
+///
+/// The deployment is threaded through the providers, the relayers and the validator of the
+/// pool, so that a single process can serve several deployments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KakarotDeployment {
+    /// The URL of the Starknet network.
+    pub network_url: Url,
+    /// Kakarot contract address.
+    pub kakarot_address: Felt,
+    /// Uninitialized account class hash.
+    pub uninitialized_account_class_hash: Felt,
+    /// The chain id of the underlying Starknet chain.
+    pub starknet_chain_id: Felt,
+    /// The chain id for the Ethereum chain running on the Starknet chain.
+    pub chain_id: u64,
 }
 
-/// Kakarot address
-pub static KAKAROT_ADDRESS: LazyLock<Felt> = LazyLock::new(|| env_var_to_field_element("KAKAROT_ADDRESS"));
+impl KakarotDeployment {
+    /// Creates a new [`KakarotDeployment`], deriving the Ethereum chain id from the Starknet
+    /// chain id.
+    pub fn new(
+        network_url: Url,
+        kakarot_address: Felt,
+        uninitialized_account_class_hash: Felt,
+        starknet_chain_id: Felt,
+    ) -> Self {
+        let chain_id = starknet_chain_id
+            .div_rem(&NonZeroFelt::from_felt_unchecked(Felt::from(MAX_CHAIN_ID)))
+            .1
+            .to_u64()
+            .expect("modulo");
+        Self { network_url, kakarot_address, uninitialized_account_class_hash, starknet_chain_id, chain_id }
+    }
+
+    /// Fetches the Starknet chain id of the configured deployment from the provider.
+    pub async fn fetch<P: Provider + Sync>(provider: &P, config: &KakarotRpcConfig) -> Result<Self, ProviderError> {
+        let starknet_chain_id = provider.chain_id().await?;
+        Ok(Self::new(
+            config.network_url.clone(),
+            config.kakarot_address,
+            config.uninitialized_account_class_hash,
+            starknet_chain_id,
+        ))
+    }
 
-/// Uninitialized account class hash
-pub static UNINITIALIZED_ACCOUNT_CLASS_HASH: LazyLock<Felt> =
-    LazyLock::new(|| env_var_to_field_element("UNINITIALIZED_ACCOUNT_CLASS_HASH"));
+    /// Compute the starknet address given a eth address
+    #[inline]
+    pub fn starknet_address(&self, address: Address) -> Felt {
+        let evm_address = into_via_wrapper!(address);
+        get_contract_address(
+            evm_address,
+            self.uninitialized_account_class_hash,
+            &[Felt::ONE, evm_address],
+            self.kakarot_address,
+        )
+    }
+}
 
 /// Ethereum send transaction selector
 pub static ETH_SEND_TRANSACTION: LazyLock<Felt> = LazyLock::new(|| selector!("eth_send_transaction"));
 
 /// Execute from outside selector
 pub static EXECUTE_FROM_OUTSIDE: LazyLock<Felt> = LazyLock::new(|| selector!("execute_from_outside"));
-
-/// Maximum number of felts in calldata
-pub static MAX_FELTS_IN_CALLDATA: LazyLock<usize> = LazyLock::new(|| {
-    usize::from_str(
-        &std::env::var("MAX_FELTS_IN_CALLDATA")
-            .unwrap_or_else(|_| panic!("Missing environment variable MAX_FELTS_IN_CALLDATA")),
-    )
-    .expect("Failed to parse MAX_FELTS_IN_CALLDATA")
-});
-
-pub fn get_white_listed_eip_155_transaction_hashes() -> Vec<B256> {
-    std::env::var("WHITE_LISTED_EIP_155_TRANSACTION_HASHES")
-        .unwrap_or_else(|_| panic!("Missing environment variable WHITE_LISTED_EIP_155_TRANSACTION_HASHES"))
-        .replace(' ', "")
-        .split(',')
-        .map(|hash| B256::from_str(hash).unwrap())
-        .collect()
-}
-
-// Kakarot utils
-/// Compute the starknet address given a eth address
-#[inline]
-pub fn starknet_address(address: Address) -> Felt {
-    let evm_address = into_via_wrapper!(address);
-    get_contract_address(evm_address, *UNINITIALIZED_ACCOUNT_CLASS_HASH, &[Felt::ONE, evm_address], *KAKAROT_ADDRESS)
-}
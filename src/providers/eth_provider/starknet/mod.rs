@@ -1,6 +1,7 @@
 #![allow(non_snake_case, clippy::derive_partial_eq_without_eq)]
 pub mod kakarot_core;
 pub mod relayer;
+pub mod signer;
 
 use cainome::rs::abigen_legacy;
 use starknet::core::types::Felt;
@@ -11,3 +12,7 @@ abigen_legacy!(ERC20, "./.kakarot/artifacts/ERC20.json");
 /// Starknet native token address
 pub static STARKNET_NATIVE_TOKEN: LazyLock<Felt> =
     LazyLock::new(|| Felt::from_hex("0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7").unwrap());
+
+/// Starknet STRK token address, used to pay the fees of v3 transactions
+pub static STARKNET_STRK_TOKEN: LazyLock<Felt> =
+    LazyLock::new(|| Felt::from_hex("0x4718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d").unwrap());
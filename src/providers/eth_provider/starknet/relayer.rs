@@ -1,34 +1,72 @@
 use crate::{
-    constants::STARKNET_CHAIN_ID,
     models::transaction::transaction_data_to_starknet_calldata,
     providers::eth_provider::{
         database::{ethereum::EthereumTransactionStore, types::transaction::EthStarknetHashes, Database},
-        error::{SignatureError, TransactionError},
+        error::{EvmError, SignatureError, SimulationError, TransactionError},
         provider::EthApiResult,
-        starknet::kakarot_core::{starknet_address, EXECUTE_FROM_OUTSIDE},
+        starknet::{
+            kakarot_core::{KakarotDeployment, ETH_SEND_TRANSACTION, EXECUTE_FROM_OUTSIDE},
+            signer::RelayerSigner,
+            STARKNET_NATIVE_TOKEN, STARKNET_STRK_TOKEN,
+        },
     },
 };
+use num_traits::ToPrimitive;
 use reth_primitives::TransactionSigned;
 use starknet::{
-    accounts::{Account, ConnectedAccount, ExecutionEncoding, ExecutionV1, SingleOwnerAccount},
-    core::types::{BlockTag, Felt, NonZeroFelt},
-    providers::Provider,
-    signers::{LocalWallet, SigningKey},
-};
-use std::{
-    env::var,
-    ops::Deref,
-    str::FromStr,
-    sync::{Arc, LazyLock},
+    accounts::{
+        Account, AccountError, ConnectedAccount, ExecutionEncoding, ExecutionV1, ExecutionV3, SingleOwnerAccount,
+    },
+    core::types::{
+        BlockTag, Call, ExecuteInvocation, FeeEstimate, Felt, FunctionInvocation, SimulatedTransaction, StarknetError,
+        TransactionTrace,
+    },
+    providers::{Provider, ProviderError},
 };
+use std::{ops::Deref, sync::Arc};
 
-/// Signer for all relayers
-static RELAYER_SIGNER: LazyLock<LocalWallet> = LazyLock::new(|| {
-    LocalWallet::from_signing_key(SigningKey::from_secret_scalar(
-        Felt::from_str(&var("RELAYER_PRIVATE_KEY").expect("missing relayer private key"))
-            .expect("failed to parse relayer private key"),
-    ))
-});
+/// The default multiplier applied to the estimated fee of the relayed transactions.
+pub const DEFAULT_FEE_MULTIPLIER: f64 = 1.5;
+
+/// The fee strategy of the relayers, selecting the version of the relayed invoke transactions.
+///
+/// In both versions, the fee is estimated and multiplied by `multiplier` to account for the fee
+/// variations between the estimation and the inclusion of the transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeeStrategy {
+    /// Send v1 invoke transactions, paying the fee in ETH.
+    V1 {
+        /// The multiplier applied to the estimated fee.
+        multiplier: f64,
+        /// The maximum fee paid for a transaction, in wei. Capped by the relayer balance.
+        max_fee: Option<Felt>,
+    },
+    /// Send v3 invoke transactions, paying the fee in STRK.
+    V3 {
+        /// The multiplier applied to the estimated L1 gas amount and price.
+        multiplier: f64,
+        /// The maximum L1 gas amount of a transaction.
+        max_l1_gas: u64,
+        /// The maximum L1 gas price of a transaction, in fri.
+        max_l1_gas_price: u128,
+    },
+}
+
+impl Default for FeeStrategy {
+    fn default() -> Self {
+        Self::V1 { multiplier: DEFAULT_FEE_MULTIPLIER, max_fee: None }
+    }
+}
+
+impl FeeStrategy {
+    /// Returns the address of the token the fee is paid in.
+    pub fn fee_token(&self) -> Felt {
+        match self {
+            Self::V1 { .. } => *STARKNET_NATIVE_TOKEN,
+            Self::V3 { .. } => *STARKNET_STRK_TOKEN,
+        }
+    }
+}
 
 /// A relayer holding an account and a balance.
 ///
@@ -36,9 +74,19 @@ static RELAYER_SIGNER: LazyLock<LocalWallet> = LazyLock::new(|| {
 #[derive(Debug)]
 pub struct Relayer<SP: Provider + Send + Sync> {
     /// The account used to sign and broadcast the transaction
-    account: SingleOwnerAccount<SP, LocalWallet>,
+    account: SingleOwnerAccount<SP, RelayerSigner>,
     /// The balance of the relayer
     balance: Felt,
+    /// The nonce to relay the next transaction with, fetched from the chain if `None`
+    nonce: Option<Felt>,
+    /// Whether to simulate the transaction before relaying it
+    preflight_simulation: bool,
+    /// The strategy used to compute the fee of the relayed transactions
+    fee_strategy: FeeStrategy,
+    /// The maximum number of felts in the calldata of the relayed transactions, unlimited if `None`
+    max_felts_in_calldata: Option<usize>,
+    /// The Kakarot deployment the transactions are relayed to
+    deployment: KakarotDeployment,
     /// The database used to store the relayer's transaction hashes map (Ethereum -> Starknet)
     database: Option<Arc<Database>>,
 }
@@ -47,53 +95,113 @@ impl<SP> Relayer<SP>
 where
     SP: Provider + Send + Sync,
 {
-    /// Create a new relayer with the provided Starknet provider, address, balance.
-    pub fn new(address: Felt, balance: Felt, provider: SP, database: Option<Arc<Database>>) -> Self {
-        let relayer = SingleOwnerAccount::new(
-            provider,
-            RELAYER_SIGNER.clone(),
-            address,
-            *STARKNET_CHAIN_ID,
-            ExecutionEncoding::New,
-        );
+    /// Create a new relayer with the provided Starknet provider, address, balance and signer,
+    /// relaying the transactions to the given Kakarot deployment.
+    pub fn new(
+        address: Felt,
+        balance: Felt,
+        provider: SP,
+        signer: RelayerSigner,
+        deployment: KakarotDeployment,
+        database: Option<Arc<Database>>,
+    ) -> Self {
+        let relayer =
+            SingleOwnerAccount::new(provider, signer, address, deployment.starknet_chain_id, ExecutionEncoding::New);
 
-        Self { account: relayer, balance, database }
+        Self {
+            account: relayer,
+            balance,
+            nonce: None,
+            preflight_simulation: false,
+            fee_strategy: FeeStrategy::default(),
+            max_felts_in_calldata: None,
+            deployment,
+            database,
+        }
+    }
+
+    /// Sets the nonce used to relay the transaction, instead of fetching it from the chain.
+    #[must_use]
+    pub const fn with_nonce(mut self, nonce: Felt) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Enables or disables the simulation of the transactions before relaying them.
+    ///
+    /// When enabled, transactions which would revert or which the relayer can't pay for are
+    /// rejected with a [`SimulationError`] instead of being sent.
+    #[must_use]
+    pub const fn with_preflight_simulation(mut self, preflight_simulation: bool) -> Self {
+        self.preflight_simulation = preflight_simulation;
+        self
+    }
+
+    /// Sets the strategy used to compute the fee of the relayed transactions.
+    #[must_use]
+    pub const fn with_fee_strategy(mut self, fee_strategy: FeeStrategy) -> Self {
+        self.fee_strategy = fee_strategy;
+        self
+    }
+
+    /// Sets the maximum number of felts in the calldata of the relayed transactions, unlimited if
+    /// `None`.
+    #[must_use]
+    pub const fn with_max_felts_in_calldata(mut self, max_felts_in_calldata: Option<usize>) -> Self {
+        self.max_felts_in_calldata = max_felts_in_calldata;
+        self
     }
 
     /// Relay the provided Ethereum transaction on the Starknet network.
-    /// The relayer nonce is directly fetched from the chain to have the most up-to-date value.
-    /// This is a way to avoid nonce issues.
+    /// If no nonce was provided, the relayer nonce is directly fetched from the chain to have the
+    /// most up-to-date value.
     ///
     /// Returns the corresponding Starknet transaction hash.
     pub async fn relay_transaction(&self, transaction: &TransactionSigned) -> EthApiResult<Felt> {
+        self.relay_transaction_with_max_fee(transaction).await.map(|(transaction_hash, _)| transaction_hash)
+    }
+
+    /// Relays the provided Ethereum transaction on the Starknet network, see
+    /// [`Relayer::relay_transaction`].
+    ///
+    /// Returns the corresponding Starknet transaction hash and the maximum fee the relayer pays
+    /// for it, in the fee token.
+    pub async fn relay_transaction_with_max_fee(&self, transaction: &TransactionSigned) -> EthApiResult<(Felt, Felt)> {
         // Transform the transaction's data to Starknet calldata
         let relayer_address = self.account.address();
-        let calldata = transaction_data_to_starknet_calldata(transaction, relayer_address)?;
+        let calldata = transaction_data_to_starknet_calldata(
+            transaction,
+            relayer_address,
+            self.deployment.kakarot_address,
+            self.max_felts_in_calldata,
+        )?;
 
         // Recover the signer
         let eoa_address = transaction.recover_signer().ok_or(SignatureError::Recovery)?;
-        let eoa_address = starknet_address(eoa_address);
+        let eoa_address = self.deployment.starknet_address(eoa_address);
 
         // Construct the call
-        let call = starknet::core::types::Call { to: eoa_address, selector: *EXECUTE_FROM_OUTSIDE, calldata };
-        let mut execution = ExecutionV1::new(vec![call], &self.account);
-
-        // Fetch the relayer nonce from the Starknet provider
-        let relayer_nonce = self
-            .account
-            .provider()
-            .get_nonce(starknet::core::types::BlockId::Tag(BlockTag::Pending), relayer_address)
-            .await
-            .unwrap_or_default();
-
-        execution = execution.nonce(relayer_nonce);
+        let call = Call { to: eoa_address, selector: *EXECUTE_FROM_OUTSIDE, calldata };
 
-        // We set the max fee to the balance of the account / 5. This means that the account could
-        // send up to 5 transactions before hitting a feeder gateway error.
-        execution = execution.max_fee(self.balance.floor_div(&NonZeroFelt::from_felt_unchecked(5.into())));
+        // Use the provided nonce or fetch the relayer nonce from the Starknet provider
+        let relayer_nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => self
+                .account
+                .provider()
+                .get_nonce(starknet::core::types::BlockId::Tag(BlockTag::Pending), relayer_address)
+                .await
+                .unwrap_or_default(),
+        };
 
-        let prepared = execution.prepared().map_err(|_| SignatureError::SigningFailure)?;
-        let res = prepared.send().await.map_err(|err| TransactionError::Broadcast(err.into()))?;
+        let (transaction_hash, max_fee) = match self.fee_strategy {
+            FeeStrategy::V1 { multiplier, max_fee } => {
+                self.send_v1(vec![call], relayer_nonce, multiplier, max_fee).await?
+            }
+            FeeStrategy::V3 { multiplier, max_l1_gas, max_l1_gas_price } => {
+                self.send_v3(vec![call], relayer_nonce, multiplier, max_l1_gas, max_l1_gas_price).await?
+            }
+        };
 
         // Store a transaction hash mapping from Ethereum to Starknet in the database
 
@@ -101,12 +209,82 @@ where
             database
                 .upsert_transaction_hashes(EthStarknetHashes {
                     eth_hash: transaction.hash,
-                    starknet_hash: res.transaction_hash,
+                    starknet_hash: transaction_hash,
                 })
                 .await?;
         }
 
-        Ok(res.transaction_hash)
+        Ok((transaction_hash, max_fee))
+    }
+
+    /// Sends the calls in a v1 invoke transaction, paying the fee in ETH. Returns the hash of the
+    /// transaction and its max fee.
+    ///
+    /// The max fee is the estimated fee times the multiplier, capped by the configured max fee and
+    /// the balance of the relayer.
+    async fn send_v1(
+        &self,
+        calls: Vec<Call>,
+        nonce: Felt,
+        multiplier: f64,
+        max_fee: Option<Felt>,
+    ) -> Result<(Felt, Felt), TransactionError> {
+        let execution = ExecutionV1::new(calls, &self.account).nonce(nonce);
+
+        let estimate = execution.estimate_fee().await.map_err(estimation_error)?;
+        let cap = max_fee.map_or(self.balance, |max_fee| max_fee.min(self.balance));
+        if estimate.overall_fee > cap {
+            return Err(
+                SimulationError::Fee(format!("estimated fee {} exceeds max fee {cap}", estimate.overall_fee)).into()
+            );
+        }
+        let fee = apply_multiplier(estimate.overall_fee.to_u128().unwrap_or(u128::MAX), multiplier);
+        let max_fee = Felt::from(fee).min(cap);
+        let execution = execution.max_fee(max_fee);
+
+        // Simulate the transaction in order to avoid paying for a transaction which will fail
+        if self.preflight_simulation {
+            let simulation = execution.simulate(false, false).await.map_err(simulation_error)?;
+            check_simulation(&simulation, max_fee)?;
+        }
+
+        let prepared = execution.prepared().map_err(|_| SignatureError::SigningFailure)?;
+        let res = prepared.send().await.map_err(|err| TransactionError::Broadcast(err.into()))?;
+        Ok((res.transaction_hash, max_fee))
+    }
+
+    /// Sends the calls in a v3 invoke transaction, paying the fee in STRK. Returns the hash of the
+    /// transaction and its max fee.
+    ///
+    /// The L1 gas amount and price are the estimated ones times the multiplier, each capped by its
+    /// configured maximum. The L2 gas bounds are left to zero, as L2 gas isn't charged yet.
+    async fn send_v3(
+        &self,
+        calls: Vec<Call>,
+        nonce: Felt,
+        multiplier: f64,
+        max_l1_gas: u64,
+        max_l1_gas_price: u128,
+    ) -> Result<(Felt, Felt), TransactionError> {
+        let execution = ExecutionV3::new(calls, &self.account).nonce(nonce);
+
+        let estimate = execution.estimate_fee().await.map_err(estimation_error)?;
+        let (gas, gas_price) = l1_resource_bounds(&estimate, multiplier, max_l1_gas, max_l1_gas_price)?;
+        let max_fee = Felt::from(gas) * Felt::from(gas_price);
+        if max_fee > self.balance {
+            return Err(SimulationError::Fee(format!("max fee {max_fee} exceeds balance {}", self.balance)).into());
+        }
+        let execution = execution.gas(gas).gas_price(gas_price);
+
+        // Simulate the transaction in order to avoid paying for a transaction which will fail
+        if self.preflight_simulation {
+            let simulation = execution.simulate(false, false).await.map_err(simulation_error)?;
+            check_simulation(&simulation, max_fee)?;
+        }
+
+        let prepared = execution.prepared().map_err(|_| SignatureError::SigningFailure)?;
+        let res = prepared.send().await.map_err(|err| TransactionError::Broadcast(err.into()))?;
+        Ok((res.transaction_hash, max_fee))
     }
 
     pub fn address(&self) -> Felt {
@@ -114,13 +292,443 @@ where
     }
 }
 
+/// Maps the error of a fee estimation to a [`TransactionError`].
+///
+/// Errors due to the fee or to the Cairo execution are mapped to a [`SimulationError`] which
+/// isn't [doomed](SimulationError::is_doomed), so that the transaction is relayed again: the
+/// transaction is only rejected by the preflight simulation, if enabled.
+fn estimation_error<S>(err: AccountError<S>) -> TransactionError
+where
+    S: std::error::Error + Send + Sync + 'static,
+{
+    match err {
+        AccountError::Provider(ProviderError::StarknetError(StarknetError::TransactionExecutionError(data))) => {
+            SimulationError::FeeEstimation(data.execution_error).into()
+        }
+        err => simulation_error(err),
+    }
+}
+
+/// Maps the error of a simulation to a [`TransactionError`].
+///
+/// Errors due to the fee or to the Cairo execution are mapped to a [`SimulationError`].
+fn simulation_error<S>(err: AccountError<S>) -> TransactionError
+where
+    S: std::error::Error + Send + Sync + 'static,
+{
+    match err {
+        AccountError::Provider(ProviderError::StarknetError(
+            StarknetError::InsufficientAccountBalance | StarknetError::InsufficientMaxFee,
+        )) => SimulationError::Fee(err.to_string()).into(),
+        AccountError::Provider(ProviderError::StarknetError(StarknetError::TransactionExecutionError(data))) => {
+            SimulationError::CairoFailure(data.execution_error).into()
+        }
+        err => TransactionError::Broadcast(err.into()),
+    }
+}
+
+/// Multiplies the value by the multiplier, saturating at [`u128::MAX`].
+fn apply_multiplier(value: u128, multiplier: f64) -> u128 {
+    // The multiplier is validated to be at least 1 when loading the configuration, so the product
+    // is positive, and float to integer casts saturate.
+    #[allow(clippy::cast_sign_loss)]
+    let value = (value as f64 * multiplier) as u128;
+    value
+}
+
+/// Returns the L1 gas amount and price of a v3 transaction given its fee estimate: the estimated
+/// ones times the multiplier, each capped by its maximum.
+///
+/// Fails if the estimated amount or price already exceeds its maximum.
+fn l1_resource_bounds(
+    estimate: &FeeEstimate,
+    multiplier: f64,
+    max_l1_gas: u64,
+    max_l1_gas_price: u128,
+) -> Result<(u64, u128), SimulationError> {
+    let estimated_gas_price = estimate.gas_price.to_u128().unwrap_or(u128::MAX).max(1);
+    let estimated_gas = estimate.overall_fee.to_u128().unwrap_or(u128::MAX).div_ceil(estimated_gas_price);
+    if estimated_gas > u128::from(max_l1_gas) || estimated_gas_price > max_l1_gas_price {
+        return Err(SimulationError::Fee(format!(
+            "estimated l1 gas {estimated_gas} at price {estimated_gas_price} exceeds max l1 gas {max_l1_gas} at price \
+             {max_l1_gas_price}"
+        )));
+    }
+
+    let gas = apply_multiplier(estimated_gas, multiplier).min(max_l1_gas.into()) as u64;
+    let gas_price = apply_multiplier(estimated_gas_price, multiplier).min(max_l1_gas_price);
+    Ok((gas, gas_price))
+}
+
+/// Checks the result of the simulation of a relayed transaction.
+///
+/// Fails if the estimated fee exceeds the max fee of the relayer, if the Cairo execution reverts,
+/// or if the EVM transaction reverts.
+fn check_simulation(simulation: &SimulatedTransaction, max_fee: Felt) -> Result<(), SimulationError> {
+    let fee = simulation.fee_estimation.overall_fee;
+    if fee > max_fee {
+        return Err(SimulationError::Fee(format!("estimated fee {fee} exceeds max fee {max_fee}")));
+    }
+
+    let TransactionTrace::Invoke(trace) = &simulation.transaction_trace else {
+        return Ok(());
+    };
+
+    match &trace.execute_invocation {
+        ExecuteInvocation::Reverted(reverted) => Err(SimulationError::CairoFailure(reverted.revert_reason.clone())),
+        ExecuteInvocation::Success(invocation) => {
+            find_invocation(invocation, *ETH_SEND_TRANSACTION).map_or(Ok(()), |call| check_evm_result(&call.result))
+        }
+    }
+}
+
+/// Returns the first invocation of the selector in the call tree.
+fn find_invocation(invocation: &FunctionInvocation, selector: Felt) -> Option<&FunctionInvocation> {
+    if invocation.entry_point_selector == selector {
+        return Some(invocation);
+    }
+    invocation.calls.iter().find_map(|call| find_invocation(call, selector))
+}
+
+/// Checks the result of Kakarot's `eth_send_transaction`, serialized as
+/// `[return_data_len, ...return_data, success, gas_used]`.
+fn check_evm_result(result: &[Felt]) -> Result<(), SimulationError> {
+    let Some(len) = result.first().and_then(ToPrimitive::to_usize) else {
+        return Ok(());
+    };
+    let (Some(return_data), Some(success)) = (result.get(1..=len), result.get(len + 1)) else {
+        return Ok(());
+    };
+
+    if *success == Felt::ZERO {
+        return Err(SimulationError::EvmRevert(EvmError::from(return_data.to_vec())));
+    }
+    Ok(())
+}
+
 impl<SP> Deref for Relayer<SP>
 where
     SP: Provider + Send + Sync,
 {
-    type Target = SingleOwnerAccount<SP, LocalWallet>;
+    type Target = SingleOwnerAccount<SP, RelayerSigner>;
 
     fn deref(&self) -> &Self::Target {
         &self.account
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        providers::eth_provider::error::EthApiError,
+        test_utils::mock_starknet::{
+            eth_send_transaction_invocation, fee_estimate as fee_estimate_json, reverted_invocation,
+            simulated_transaction, MockStarknet,
+        },
+    };
+    use alloy_consensus::TxEip1559;
+    use alloy_primitives::{Address, TxKind, B256, U256};
+    use reth_primitives::{sign_message, Transaction};
+    use serde_json::json;
+    use starknet::{
+        core::types::PriceUnit,
+        providers::{jsonrpc::HttpTransport, JsonRpcClient},
+        signers::{LocalWallet, SigningKey},
+    };
+
+    /// The balance of the mocked relayers, in wei.
+    const RELAYER_BALANCE: u64 = 10u64.pow(18);
+
+    #[test]
+    fn test_apply_multiplier() {
+        assert_eq!(apply_multiplier(1_000, 1.5), 1_500);
+        assert_eq!(apply_multiplier(u128::MAX, 2.), u128::MAX);
+    }
+
+    fn fee_estimate(overall_fee: u64, gas_price: u64) -> FeeEstimate {
+        FeeEstimate {
+            gas_consumed: Felt::from(overall_fee / gas_price),
+            gas_price: Felt::from(gas_price),
+            data_gas_consumed: Felt::ZERO,
+            data_gas_price: Felt::ZERO,
+            overall_fee: Felt::from(overall_fee),
+            unit: PriceUnit::Fri,
+        }
+    }
+
+    #[test]
+    fn test_l1_resource_bounds() {
+        // Given
+        let estimate = fee_estimate(1_000_000, 100);
+
+        // When
+        let bounds = l1_resource_bounds(&estimate, 1.5, u64::MAX, u128::MAX);
+
+        // Then
+        assert_eq!(bounds.unwrap(), (15_000, 150));
+    }
+
+    #[test]
+    fn test_l1_resource_bounds_capped() {
+        // Given
+        let estimate = fee_estimate(1_000_000, 100);
+
+        // When
+        let bounds = l1_resource_bounds(&estimate, 1.5, 12_000, 120);
+
+        // Then
+        // The multiplied amount and price are capped by their maximum
+        assert_eq!(bounds.unwrap(), (12_000, 120));
+    }
+
+    #[test]
+    fn test_l1_resource_bounds_exceeded() {
+        // Given
+        let estimate = fee_estimate(1_000_000, 100);
+
+        // When
+        let gas_exceeded = l1_resource_bounds(&estimate, 1.5, 9_999, u128::MAX);
+        let price_exceeded = l1_resource_bounds(&estimate, 1.5, u64::MAX, 99);
+
+        // Then
+        assert!(matches!(gas_exceeded, Err(SimulationError::Fee(_))));
+        assert!(matches!(price_exceeded, Err(SimulationError::Fee(_))));
+    }
+
+    #[test]
+    fn test_l1_resource_bounds_rounds_gas_up() {
+        // Given
+        let estimate = fee_estimate(1_001, 100);
+
+        // When
+        let bounds = l1_resource_bounds(&estimate, 1., u64::MAX, u128::MAX);
+
+        // Then
+        assert_eq!(bounds.unwrap(), (11, 100));
+    }
+
+    #[test]
+    fn test_check_evm_result_success() {
+        // Given
+        let result = vec![Felt::TWO, Felt::ONE, Felt::TWO, Felt::ONE, Felt::from(21000)];
+
+        // When
+        let res = check_evm_result(&result);
+
+        // Then
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_check_evm_result_revert() {
+        // Given
+        let reason = b"Kakarot: StackUnderflow";
+        let mut result = vec![Felt::from(reason.len())];
+        result.extend(reason.iter().map(|byte| Felt::from(*byte)));
+        result.extend([Felt::ZERO, Felt::from(21000)]);
+
+        // When
+        let res = check_evm_result(&result);
+
+        // Then
+        assert!(matches!(res, Err(SimulationError::EvmRevert(EvmError::StackUnderflow))));
+    }
+
+    #[test]
+    fn test_check_evm_result_malformed() {
+        // Given
+        let result = vec![Felt::from(10), Felt::ONE];
+
+        // When
+        let res = check_evm_result(&result);
+
+        // Then
+        assert!(res.is_ok());
+    }
+
+    /// Returns a relayer of the mocked Starknet node, simulating the transactions before
+    /// relaying them.
+    fn mock_relayer(starknet: &MockStarknet) -> Relayer<JsonRpcClient<HttpTransport>> {
+        let deployment = KakarotDeployment::new(starknet.url(), Felt::ONE, Felt::TWO, Felt::from(0x4b4b_5254_u64));
+        Relayer::new(
+            Felt::from(0x1234_u64),
+            Felt::from(RELAYER_BALANCE),
+            starknet.provider(),
+            LocalWallet::from(SigningKey::from_random()).into(),
+            deployment,
+            None,
+        )
+        .with_nonce(Felt::ZERO)
+        .with_preflight_simulation(true)
+    }
+
+    /// Returns a transaction signed by a random key.
+    fn signed_transaction() -> TransactionSigned {
+        let transaction = Transaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            gas_limit: 21000,
+            to: TxKind::Call(Address::random()),
+            value: U256::from(1000),
+            max_fee_per_gas: 875_000_000,
+            ..Default::default()
+        });
+        let signature = sign_message(B256::random(), transaction.signature_hash()).expect("Failed to sign transaction");
+        TransactionSigned::from_transaction_and_signature(transaction, signature)
+    }
+
+    /// Relays a transaction with the mocked relayer, returning the simulation error, if any.
+    async fn relay(starknet: &MockStarknet) -> Result<(Felt, Felt), SimulationError> {
+        match mock_relayer(starknet).relay_transaction_with_max_fee(&signed_transaction()).await {
+            Ok(res) => Ok(res),
+            Err(EthApiError::Transaction(TransactionError::Simulation(err))) => Err(err),
+            Err(err) => panic!("Expected a simulation error, got {err:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_simulated() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate_json(1_000)])).await;
+        let success = eth_send_transaction_invocation(&[Felt::ZERO, Felt::ONE, Felt::from(21000)]);
+        starknet.mock_result("starknet_simulateTransactions", json!([simulated_transaction(success, 1_000)])).await;
+        let send = starknet
+            .mock_result("starknet_addInvokeTransaction", json!({ "transaction_hash": Felt::from(0xabc_u64) }))
+            .await;
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        assert_eq!(res.unwrap(), (Felt::from(0xabc_u64), Felt::from(1_500)));
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_execution_error() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        let execution_error = json!({ "transaction_index": 0, "execution_error": "Kakarot: entrypoint failed" });
+        starknet.mock_error("starknet_estimateFee", 41, "Transaction execution error", Some(execution_error)).await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        // The estimation can run before the previous transactions of the sender are in the pending
+        // state, the transaction can be relayed again
+        let Err(err) = res else { panic!("Expected a simulation error") };
+        assert!(matches!(&err, SimulationError::FeeEstimation(reason) if reason == "Kakarot: entrypoint failed"));
+        assert!(!err.is_doomed());
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_simulation_execution_error() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate_json(1_000)])).await;
+        let execution_error = json!({ "transaction_index": 0, "execution_error": "Kakarot: entrypoint failed" });
+        starknet
+            .mock_error("starknet_simulateTransactions", 41, "Transaction execution error", Some(execution_error))
+            .await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        let Err(err) = res else { panic!("Expected a simulation error") };
+        assert!(matches!(&err, SimulationError::CairoFailure(reason) if reason == "Kakarot: entrypoint failed"));
+        assert!(err.is_doomed());
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_insufficient_max_fee() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate_json(1_000)])).await;
+        starknet
+            .mock_error(
+                "starknet_simulateTransactions",
+                53,
+                "Max fee is smaller than the minimal transaction cost",
+                None,
+            )
+            .await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        // The fee depends on the relayer, the transaction can be relayed again
+        let Err(err) = res else { panic!("Expected a simulation error") };
+        assert!(matches!(err, SimulationError::Fee(_)));
+        assert!(!err.is_doomed());
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_simulation_fee_exceeded() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate_json(1_000)])).await;
+        let success = eth_send_transaction_invocation(&[Felt::ZERO, Felt::ONE, Felt::from(21000)]);
+        starknet.mock_result("starknet_simulateTransactions", json!([simulated_transaction(success, 2_000)])).await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        // The simulated fee exceeds the max fee of 1.5 times the estimated one
+        assert!(matches!(res, Err(SimulationError::Fee(_))));
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_simulation_reverted() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate_json(1_000)])).await;
+        let reverted = reverted_invocation("RunResources has no remaining steps.");
+        starknet.mock_result("starknet_simulateTransactions", json!([simulated_transaction(reverted, 1_000)])).await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        assert!(
+            matches!(res, Err(SimulationError::CairoFailure(reason)) if reason == "RunResources has no remaining steps.")
+        );
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_relay_transaction_simulation_evm_revert() {
+        // Given
+        let mut starknet = MockStarknet::new().await;
+        starknet.mock_result("starknet_estimateFee", json!([fee_estimate_json(1_000)])).await;
+        let reason = b"Kakarot: StackUnderflow";
+        let mut result = vec![Felt::from(reason.len())];
+        result.extend(reason.iter().map(|byte| Felt::from(*byte)));
+        result.extend([Felt::ZERO, Felt::from(21000)]);
+        let reverted = eth_send_transaction_invocation(&result);
+        starknet.mock_result("starknet_simulateTransactions", json!([simulated_transaction(reverted, 1_000)])).await;
+        let send = starknet.mock_result("starknet_addInvokeTransaction", json!({})).await.expect(0);
+
+        // When
+        let res = relay(&starknet).await;
+
+        // Then
+        let Err(err) = res else { panic!("Expected a simulation error") };
+        assert!(matches!(err, SimulationError::EvmRevert(EvmError::StackUnderflow)));
+        assert!(err.is_doomed());
+        send.assert_async().await;
+    }
+}
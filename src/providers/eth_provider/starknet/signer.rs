@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use starknet::{
+    core::{crypto::Signature, types::Felt},
+    signers::{LocalWallet, Signer, VerifyingKey},
+};
+use std::{fmt::Debug, sync::Arc};
+
+/// A signer of relayer transactions, wrapping any Starknet [`Signer`].
+///
+/// Allows the relayers to use different kinds of signers, e.g. a local key loaded from a keystore
+/// or a remote signer, without being generic over the signer type.
+#[derive(Debug, Clone)]
+pub struct RelayerSigner(Arc<dyn DynSigner>);
+
+impl RelayerSigner {
+    /// Wraps the Starknet signer.
+    pub fn new<S>(signer: S) -> Self
+    where
+        S: Signer + Debug + Send + Sync + 'static,
+        S::GetPublicKeyError: 'static,
+        S::SignError: 'static,
+    {
+        Self(Arc::new(signer))
+    }
+}
+
+impl From<LocalWallet> for RelayerSigner {
+    fn from(wallet: LocalWallet) -> Self {
+        Self::new(wallet)
+    }
+}
+
+/// Error returned by the wrapped signer of a [`RelayerSigner`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RelayerSignerError(Box<dyn std::error::Error + Send + Sync>);
+
+#[async_trait]
+impl Signer for RelayerSigner {
+    type GetPublicKeyError = RelayerSignerError;
+    type SignError = RelayerSignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        self.0.get_public_key().await
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, Self::SignError> {
+        self.0.sign_hash(hash).await
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.0.is_interactive()
+    }
+}
+
+/// An object safe version of the Starknet [`Signer`], with type erased errors.
+#[async_trait]
+trait DynSigner: Debug + Send + Sync {
+    async fn get_public_key(&self) -> Result<VerifyingKey, RelayerSignerError>;
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, RelayerSignerError>;
+
+    fn is_interactive(&self) -> bool;
+}
+
+#[async_trait]
+impl<S> DynSigner for S
+where
+    S: Signer + Debug + Send + Sync,
+    S::GetPublicKeyError: 'static,
+    S::SignError: 'static,
+{
+    async fn get_public_key(&self) -> Result<VerifyingKey, RelayerSignerError> {
+        Signer::get_public_key(self).await.map_err(|err| RelayerSignerError(Box::new(err)))
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, RelayerSignerError> {
+        Signer::sign_hash(self, hash).await.map_err(|err| RelayerSignerError(Box::new(err)))
+    }
+
+    fn is_interactive(&self) -> bool {
+        Signer::is_interactive(self)
+    }
+}
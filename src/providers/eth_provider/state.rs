@@ -1,22 +1,27 @@
 use super::{
-    database::state::{EthCacheDatabase, EthDatabase},
-    error::{EthApiError, ExecutionError, TransactionError},
-    starknet::kakarot_core::{account_contract::AccountContractReader, starknet_address},
+    constant::{CALL_REQUEST_GAS_LIMIT, MAX_ACCESS_LIST_ITERATIONS},
+    database::{
+        ethereum::EthereumBlockStore,
+        state::{EthCacheDatabase, EthDatabase},
+    },
+    error::{EthApiError, ExecutionError, KakarotError, TransactionError},
+    revm_utils::{apply_block_overrides, block_env, call_cfg_env, ensure_success, precompiles, tx_env_from_request},
+    starknet::kakarot_core::account_contract::AccountContractReader,
     utils::{contract_not_found, entrypoint_not_found, split_u256},
 };
 use crate::{
     into_via_wrapper,
     providers::eth_provider::{
         provider::{EthApiResult, EthDataProvider},
-        BlockProvider, ChainProvider,
+        BlockProvider, ChainProvider, TransactionProvider,
     },
 };
 use alloy_eips::BlockId;
-use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_primitives::{Address, Bytes, TxKind, B256, U256};
 use alloy_rpc_types::{
     serde_helpers::JsonStorageKey,
     state::{EvmOverrides, StateOverride},
-    BlockOverrides, TransactionRequest,
+    AccessListResult, BlockOverrides, TransactionRequest,
 };
 use async_trait::async_trait;
 use auto_impl::auto_impl;
@@ -24,9 +29,15 @@ use mongodb::bson::doc;
 use num_traits::cast::ToPrimitive;
 use reth_evm_ethereum::EthEvmConfig;
 use reth_node_api::ConfigureEvm;
-use reth_revm::db::CacheDB;
-use reth_rpc_eth_types::error::ensure_success;
-use starknet::core::utils::get_storage_var_address;
+use reth_revm::{
+    db::CacheDB,
+    primitives::{Env, EnvWithHandlerCfg, HandlerCfg, SpecId},
+};
+use revm_inspectors::access_list::AccessListInspector;
+use starknet::{
+    core::{types::StarknetError, utils::get_storage_var_address},
+    providers::{Provider, ProviderError},
+};
 use std::sync::Arc;
 use tracing::Instrument;
 
@@ -47,6 +58,9 @@ pub trait StateProvider: ChainProvider + BlockProvider {
     /// Returns the code for the address at the given block.
     async fn get_code(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<Bytes>;
 
+    /// Returns whether the Kakarot account of the address is deployed at the given block.
+    async fn is_account_deployed(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<bool>;
+
     /// Returns the result of a call.
     async fn call(
         &self,
@@ -55,6 +69,14 @@ pub trait StateProvider: ChainProvider + BlockProvider {
         state_overrides: Option<StateOverride>,
         block_overrides: Option<Box<BlockOverrides>>,
     ) -> EthApiResult<Bytes>;
+
+    /// Returns the access list and the gas used by the transaction request, executed with the
+    /// generated access list.
+    async fn create_access_list(
+        &self,
+        request: TransactionRequest,
+        block_id: Option<BlockId>,
+    ) -> EthApiResult<AccessListResult>;
 }
 
 #[async_trait]
@@ -66,7 +88,10 @@ where
         // Convert the optional Ethereum block ID to a Starknet block ID.
         let starknet_block_id = self.to_starknet_block_id(block_id).await?;
         // Get the balance of the address at the given block ID.
-        self.starknet_provider().balance_at(starknet_address(address), starknet_block_id).await.map_err(Into::into)
+        self.starknet_provider()
+            .balance_at(self.deployment().starknet_address(address), starknet_block_id)
+            .await
+            .map_err(Into::into)
     }
 
     async fn storage_at(
@@ -77,7 +102,7 @@ where
     ) -> EthApiResult<B256> {
         let starknet_block_id = self.to_starknet_block_id(block_id).await?;
 
-        let address = starknet_address(address);
+        let address = self.deployment().starknet_address(address);
         let contract = AccountContractReader::new(address, self.starknet_provider_inner());
 
         let keys = split_u256(index.0);
@@ -102,7 +127,7 @@ where
     async fn get_code(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<Bytes> {
         let starknet_block_id = self.to_starknet_block_id(block_id).await?;
 
-        let address = starknet_address(address);
+        let address = self.deployment().starknet_address(address);
         let account_contract = AccountContractReader::new(address, self.starknet_provider_inner());
         let span = tracing::span!(tracing::Level::INFO, "sn::code");
         let bytecode = account_contract.bytecode().block_id(starknet_block_id).call().instrument(span).await;
@@ -116,6 +141,21 @@ where
         Ok(Bytes::from(bytecode.into_iter().filter_map(|x| x.to_u8()).collect::<Vec<_>>()))
     }
 
+    async fn is_account_deployed(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<bool> {
+        let starknet_block_id = self.to_starknet_block_id(block_id).await?;
+
+        let address = self.deployment().starknet_address(address);
+        let span = tracing::span!(tracing::Level::INFO, "sn::class_hash");
+        let class_hash =
+            self.starknet_provider_inner().get_class_hash_at(starknet_block_id, address).instrument(span).await;
+
+        match class_hash {
+            Ok(_) => Ok(true),
+            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => Ok(false),
+            Err(err) => Err(KakarotError::from(err).into()),
+        }
+    }
+
     async fn call(
         &self,
         request: TransactionRequest,
@@ -128,55 +168,113 @@ where
 
         // Check if either state_overrides or block_overrides is present.
         if evm_overrides.has_state() || evm_overrides.has_block() {
-            // // Create the configuration environment with the chain ID.
-            // let cfg_env = CfgEnv::default().with_chain_id(self.chain_id().await?.unwrap_or_default().to());
-
-            // // Retrieve the block header details.
-            // let Header { number, timestamp, miner, base_fee_per_gas, difficulty, .. } =
-            //     self.header(&block_id.unwrap_or_default()).await?.unwrap_or_default();
-
-            // // Create the block environment with the retrieved header details and transaction request.
-            // let block_env = BlockEnv {
-            //     number: U256::from(number),
-            //     timestamp: U256::from(timestamp),
-            //     gas_limit: U256::from(request.gas.unwrap_or_default()),
-            //     coinbase: miner,
-            //     basefee: U256::from(base_fee_per_gas.unwrap_or_default()),
-            //     prevrandao: Some(B256::from_slice(&difficulty.to_be_bytes::<32>()[..])),
-            //     ..Default::default()
-            // };
-
-            // // Combine the configuration environment with the handler configuration.
-            // let cfg_env_with_handler_cfg =
-            //     CfgEnvWithHandlerCfg { cfg_env, handler_cfg: HandlerCfg::new(SpecId::CANCUN) };
-
-            // Create a snapshot of the Ethereum database using the block ID.
-            let db = EthCacheDatabase(CacheDB::new(EthDatabase::new(self, block_id.unwrap_or_default())));
-
-            // TODO: no more prepare_call_env function (was put in trait, to be discussed)
-            // // Prepare the call environment with the transaction request, gas limit, and overrides.
-            // let env = prepare_call_env(
-            //     cfg_env_with_handler_cfg,
-            //     block_env,
-            //     request.clone(),
-            //     request.gas.unwrap_or_default().try_into().expect("Gas limit is too large"),
-            //     &mut db.0,
-            //     evm_overrides,
-            // )?;
-
-            // Execute the transaction using the configured EVM asynchronously.
+            let (db, env) = self.call_env(request, block_id.unwrap_or_default(), evm_overrides).await?;
+
+            // Execute the transaction using the configured EVM.
             let res = EthEvmConfig::new(Arc::new(Default::default()))
-            // TODO: We should not use default here, to be modified to activate the correct EVM config
-                .evm_with_env(db.0, Default::default())
+                .evm_with_env(db.0, env)
                 .transact()
                 .map_err(|err| <TransactionError as Into<EthApiError>>::into(TransactionError::Call(err.into())))?;
 
             // Ensure the transaction was successful and return the result.
-            return Ok(ensure_success(res.result)?);
+            return ensure_success(res.result);
         }
 
         // If no state or block overrides are present, call the helper function to execute the call.
         let output = self.call_inner(request, block_id).await?;
         Ok(Bytes::from(output.0.into_iter().filter_map(|x| x.to_u8()).collect::<Vec<_>>()))
     }
+
+    async fn create_access_list(
+        &self,
+        mut request: TransactionRequest,
+        block_id: Option<BlockId>,
+    ) -> EthApiResult<AccessListResult> {
+        let block_id = block_id.unwrap_or_default();
+
+        // The sender, the recipient and the precompiles are always warm and are excluded from the list.
+        let from = request.from.unwrap_or_default();
+        let to = match request.to {
+            Some(TxKind::Call(to)) => to,
+            _ => {
+                let nonce = match request.nonce {
+                    Some(nonce) => nonce,
+                    None => self.transaction_count(from, Some(block_id)).await?.to(),
+                };
+                from.create(nonce)
+            }
+        };
+        let mut access_list = request.access_list.take().unwrap_or_default();
+
+        let (db, mut env) = self.call_env(request, block_id, EvmOverrides::default()).await?;
+        let evm_config = EthEvmConfig::new(Arc::new(Default::default()));
+
+        // Adding an entry to the access list can change the execution path (e.g. through gas
+        // dependent branches), so the transaction is replayed until the access list stabilizes.
+        for _ in 0..MAX_ACCESS_LIST_ITERATIONS {
+            env.tx.access_list.clone_from(&access_list.0);
+
+            let mut inspector = AccessListInspector::new(access_list.clone(), from, to, precompiles(SpecId::CANCUN));
+            let res = evm_config
+                .evm_with_env_and_inspector(db.0.clone(), env.clone(), &mut inspector)
+                .transact()
+                .map_err(|err| <TransactionError as Into<EthApiError>>::into(TransactionError::Call(err.into())))?;
+
+            let new_access_list = inspector.into_access_list();
+            if new_access_list == access_list {
+                // The last execution ran with the returned access list, so its gas usage is exact.
+                let gas_used = U256::from(res.result.gas_used());
+                let error = ensure_success(res.result).err().map(|err| err.to_string());
+                return Ok(AccessListResult { access_list, gas_used, error });
+            }
+            access_list = new_access_list;
+        }
+
+        Err(ExecutionError::Other(format!(
+            "access list did not stabilize after {MAX_ACCESS_LIST_ITERATIONS} iterations"
+        ))
+        .into())
+    }
+}
+
+impl<SP> EthDataProvider<SP>
+where
+    SP: starknet::providers::Provider + Send + Sync,
+{
+    /// Returns a snapshot of the database at the given block and the environment used to execute
+    /// the request on top of it, with the [`EvmOverrides`] applied.
+    async fn call_env(
+        &self,
+        request: TransactionRequest,
+        block_id: BlockId,
+        evm_overrides: EvmOverrides,
+    ) -> EthApiResult<(EthCacheDatabase<&Self>, EnvWithHandlerCfg)> {
+        let chain_id = self.chain_id().await?.unwrap_or_default().to();
+
+        // Retrieve the header of the block the call is executed on.
+        let block_hash_or_number = self.block_id_into_block_number_or_hash(block_id).await?;
+        let header = self
+            .database()
+            .header(block_hash_or_number)
+            .await?
+            .ok_or(EthApiError::UnknownBlock(block_hash_or_number))?;
+
+        // Create a snapshot of the Ethereum database using the block ID.
+        let mut db = EthCacheDatabase(CacheDB::new(EthDatabase::new(self, block_id)));
+        let mut block_env = block_env(&header, header.gas_limit);
+
+        // Apply the state overrides first, then the block overrides.
+        if let Some(state_overrides) = evm_overrides.state {
+            db.apply_state_overrides(state_overrides)?;
+        }
+        if let Some(block_overrides) = evm_overrides.block {
+            apply_block_overrides(*block_overrides, &mut db, &mut block_env);
+        }
+
+        // Prepare the call environment with the transaction request and the block environment.
+        let gas_limit = request.gas.unwrap_or(CALL_REQUEST_GAS_LIMIT);
+        let env = Env { cfg: call_cfg_env(chain_id), block: block_env, tx: tx_env_from_request(request, gas_limit) };
+
+        Ok((db, EnvWithHandlerCfg::new(Box::new(env), HandlerCfg::new(SpecId::CANCUN))))
+    }
 }
@@ -1,10 +1,11 @@
 use super::{
     database::{
+        ethereum::EthereumTransactionStore,
         filter::EthDatabaseFilterBuilder,
         types::transaction::{ExtendedTransaction, StoredTransaction},
     },
     error::ExecutionError,
-    starknet::kakarot_core::{account_contract::AccountContractReader, starknet_address},
+    starknet::kakarot_core::account_contract::AccountContractReader,
     utils::{contract_not_found, entrypoint_not_found},
 };
 use crate::{
@@ -45,6 +46,24 @@ pub trait TransactionProvider: ChainProvider {
 
     /// Returns the nonce for the address at the given block.
     async fn transaction_count(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<U256>;
+
+    /// Returns the transaction sent by the address with the given nonce.
+    async fn transaction_by_sender_and_nonce(
+        &self,
+        sender: Address,
+        nonce: u64,
+    ) -> EthApiResult<Option<ExtendedTransaction>>;
+
+    /// Returns the transactions sent from or to the address in the inclusive block range, sorted
+    /// by block number and transaction index, in descending order if `descending` is set.
+    async fn transactions_by_address(
+        &self,
+        address: Address,
+        from: u64,
+        to: u64,
+        descending: bool,
+        limit: Option<u64>,
+    ) -> EthApiResult<Vec<ExtendedTransaction>>;
 }
 
 #[async_trait]
@@ -85,7 +104,7 @@ where
     async fn transaction_count(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<U256> {
         let starknet_block_id = self.to_starknet_block_id(block_id).await?;
 
-        let address = starknet_address(address);
+        let address = self.deployment().starknet_address(address);
         let account_contract = AccountContractReader::new(address, self.starknet_provider_inner());
         let span = tracing::span!(tracing::Level::INFO, "sn::kkrt_nonce");
         let maybe_nonce = account_contract.get_nonce().block_id(starknet_block_id).call().instrument(span).await;
@@ -97,4 +116,23 @@ where
 
         Ok(into_via_wrapper!(nonce))
     }
+
+    async fn transaction_by_sender_and_nonce(
+        &self,
+        sender: Address,
+        nonce: u64,
+    ) -> EthApiResult<Option<ExtendedTransaction>> {
+        self.database().transaction_by_sender_and_nonce(&sender, nonce).await
+    }
+
+    async fn transactions_by_address(
+        &self,
+        address: Address,
+        from: u64,
+        to: u64,
+        descending: bool,
+        limit: Option<u64>,
+    ) -> EthApiResult<Vec<ExtendedTransaction>> {
+        self.database().transactions_by_address(&address, from, to, descending, limit).await
+    }
 }
@@ -0,0 +1,554 @@
+use crate::{
+    providers::eth_provider::{
+        database::types::{header::ExtendedBlock, receipt::ExtendedTxReceipt, transaction::ExtendedTransaction},
+        error::EthApiError,
+        provider::{EthApiResult, EthereumProvider},
+    },
+    tracing::{builder::TracerBuilder, Tracer},
+};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_rpc_types::{Block, BlockTransactions, Transaction};
+use alloy_rpc_types_trace::otterscan::{
+    BlockDetails, ContractCreator, InternalOperation, OperationType, OtsBlock, OtsBlockTransactions, OtsReceipt,
+    OtsTransactionReceipt, TraceEntry, TransactionsWithReceipts,
+};
+use async_trait::async_trait;
+use auto_impl::auto_impl;
+use futures::future::try_join_all;
+use reth_revm::{inspectors::NoOpInspector, primitives::ExecutionResult};
+use reth_rpc_eth_types::EthApiError as RethEthApiError;
+use revm_inspectors::{
+    tracing::{TracingInspector, TracingInspectorConfig},
+    transfer::{TransferInspector, TransferKind},
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// The Otterscan API level implemented, see <https://github.com/otterscan/otterscan/blob/main/docs/custom-jsonrpc.md>.
+pub const OTTERSCAN_API_LEVEL: u64 = 8;
+
+/// The maximum page size of the transaction searches, as a receipt is fetched for each transaction.
+pub const MAX_SEARCH_PAGE_SIZE: usize = 100;
+
+#[async_trait]
+#[auto_impl(Arc, &)]
+pub trait OtterscanProvider {
+    /// Returns true if the address has code at the given block.
+    async fn has_code(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<bool>;
+    /// Returns the value transfers and contract creations that happened inside the transaction.
+    async fn internal_operations(&self, transaction_hash: B256) -> EthApiResult<Vec<InternalOperation>>;
+    /// Returns the revert data of the transaction, or empty bytes if the transaction succeeded.
+    async fn transaction_error(&self, transaction_hash: B256) -> EthApiResult<Option<Bytes>>;
+    /// Returns the call tree of the transaction.
+    async fn trace_transaction(&self, transaction_hash: B256) -> EthApiResult<Option<Vec<TraceEntry>>>;
+    /// Returns the block details, without the transactions.
+    async fn block_details(&self, block_number: u64) -> EthApiResult<BlockDetails>;
+    /// Returns a page of the transactions of the block along with their receipts.
+    async fn block_transactions(
+        &self,
+        block_number: u64,
+        page_number: usize,
+        page_size: usize,
+    ) -> EthApiResult<OtsBlockTransactions>;
+    /// Returns the transactions sent from or to the address before the given block (excluded).
+    async fn search_transactions_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> EthApiResult<TransactionsWithReceipts>;
+    /// Returns the transactions sent from or to the address after the given block (excluded).
+    async fn search_transactions_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> EthApiResult<TransactionsWithReceipts>;
+    /// Returns the hash of the transaction sent by the address with the given nonce.
+    async fn transaction_by_sender_and_nonce(&self, sender: Address, nonce: u64) -> EthApiResult<Option<B256>>;
+    /// Returns the creator of the contract deployed at the address.
+    async fn contract_creator(&self, address: Address) -> EthApiResult<Option<ContractCreator>>;
+}
+
+/// Otterscan provider backed by the transactions and receipts stored in the database.
+///
+/// Only the top level transactions are indexed: the searches by address don't include
+/// the internal calls, and the creator is only found for contracts deployed by a transaction.
+#[derive(Debug, Clone)]
+pub struct OtterscanDataProvider<P: EthereumProvider> {
+    eth_provider: P,
+}
+
+impl<P: EthereumProvider> OtterscanDataProvider<P> {
+    pub const fn new(eth_provider: P) -> Self {
+        Self { eth_provider }
+    }
+}
+
+impl<P: EthereumProvider + Send + Sync + 'static> OtterscanDataProvider<P> {
+    /// Returns a tracer for the block of the transaction, or `None` if the transaction is not found.
+    async fn tracer(&self, transaction_hash: B256) -> EthApiResult<Option<Tracer<Arc<&P>>>> {
+        let builder = TracerBuilder::new(Arc::new(&self.eth_provider)).await?;
+        match builder.with_transaction_hash(transaction_hash).await {
+            Ok(builder) => Ok(Some(builder.build()?)),
+            Err(EthApiError::TransactionNotFound(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the transactions sent from or to the address in the inclusive block range, in
+    /// descending order, along with a flag indicating if the search was truncated.
+    ///
+    /// At least `page_size` transactions are returned if available, but blocks are never split
+    /// between pages. The page size is capped to [`MAX_SEARCH_PAGE_SIZE`].
+    async fn search_transactions(
+        &self,
+        address: Address,
+        from: u64,
+        to: u64,
+        descending: bool,
+        page_size: usize,
+    ) -> EthApiResult<(Vec<ExtendedTransaction>, bool)> {
+        if page_size == 0 {
+            return Err(RethEthApiError::InvalidParams(
+                "invalid parameters: page size must be greater than 0".to_string(),
+            )
+            .into());
+        }
+        let page_size = page_size.min(MAX_SEARCH_PAGE_SIZE);
+        let limit = page_size as u64;
+        let mut transactions =
+            self.eth_provider.transactions_by_address(address, from, to, descending, Some(limit)).await?;
+
+        let truncated = transactions.len() >= page_size;
+        if truncated {
+            // Complete the last block of the page.
+            if let Some(last_block) = transactions.last().and_then(|tx| tx.block_number) {
+                transactions.retain(|tx| tx.block_number != Some(last_block));
+                transactions.extend(
+                    self.eth_provider
+                        .transactions_by_address(address, last_block, last_block, descending, None)
+                        .await?,
+                );
+            }
+        }
+
+        if !descending {
+            transactions.reverse();
+        }
+        Ok((transactions, truncated))
+    }
+
+    /// Returns the transactions along with their Otterscan receipts.
+    async fn with_receipts(&self, transactions: Vec<ExtendedTransaction>) -> EthApiResult<TransactionsWithReceipts> {
+        // Fetch the timestamps of the blocks of the transactions.
+        let mut timestamps = HashMap::new();
+        for block_number in transactions.iter().filter_map(|tx| tx.block_number) {
+            if let std::collections::hash_map::Entry::Vacant(entry) = timestamps.entry(block_number) {
+                let header = self.eth_provider.header(&block_number.into()).await?;
+                entry.insert(header.map(|header| header.timestamp));
+            }
+        }
+
+        let receipts = try_join_all(transactions.iter().map(|tx| self.eth_provider.transaction_receipt(tx.hash)))
+            .await?
+            .into_iter()
+            .zip(&transactions)
+            .map(|(receipt, tx)| {
+                let receipt = receipt.ok_or(EthApiError::TransactionNotFound(tx.hash))?;
+                let timestamp = tx.block_number.and_then(|number| timestamps.get(&number).copied().flatten());
+                Ok(into_ots_receipt(receipt, timestamp))
+            })
+            .collect::<EthApiResult<Vec<_>>>()?;
+
+        Ok(TransactionsWithReceipts {
+            txs: transactions.into_iter().map(|tx| tx.inner).collect(),
+            receipts,
+            first_page: false,
+            last_page: false,
+        })
+    }
+}
+
+#[async_trait]
+impl<P: EthereumProvider + Send + Sync + 'static> OtterscanProvider for OtterscanDataProvider<P> {
+    async fn has_code(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<bool> {
+        Ok(!self.eth_provider.get_code(address, block_id).await?.is_empty())
+    }
+
+    async fn internal_operations(&self, transaction_hash: B256) -> EthApiResult<Vec<InternalOperation>> {
+        let Some(tracer) = self.tracer(transaction_hash).await? else {
+            return Err(EthApiError::TransactionNotFound(transaction_hash));
+        };
+        let (inspector, _) =
+            tracer.trace_transaction_with_inspector(transaction_hash, TransferInspector::new(false))?;
+
+        Ok(inspector
+            .into_transfers()
+            .into_iter()
+            .map(|transfer| InternalOperation {
+                r#type: match transfer.kind {
+                    TransferKind::Call => OperationType::OpTransfer,
+                    TransferKind::Create => OperationType::OpCreate,
+                    TransferKind::Create2 => OperationType::OpCreate2,
+                    TransferKind::SelfDestruct => OperationType::OpSelfDestruct,
+                },
+                from: transfer.from,
+                to: transfer.to,
+                value: transfer.value,
+            })
+            .collect())
+    }
+
+    async fn transaction_error(&self, transaction_hash: B256) -> EthApiResult<Option<Bytes>> {
+        let Some(tracer) = self.tracer(transaction_hash).await? else { return Ok(None) };
+        let (_, result) = tracer.trace_transaction_with_inspector(transaction_hash, NoOpInspector)?;
+
+        Ok(Some(match result {
+            ExecutionResult::Revert { output, .. } => output,
+            _ => Bytes::new(),
+        }))
+    }
+
+    async fn trace_transaction(&self, transaction_hash: B256) -> EthApiResult<Option<Vec<TraceEntry>>> {
+        let Some(tracer) = self.tracer(transaction_hash).await? else { return Ok(None) };
+        let inspector = TracingInspector::new(TracingInspectorConfig::default_parity());
+        let (inspector, _) = tracer.trace_transaction_with_inspector(transaction_hash, inspector)?;
+
+        Ok(Some(
+            inspector
+                .into_traces()
+                .into_nodes()
+                .into_iter()
+                .map(|node| TraceEntry {
+                    r#type: if node.is_selfdestruct() {
+                        "SELFDESTRUCT".to_string()
+                    } else {
+                        node.trace.kind.to_string()
+                    },
+                    depth: u32::try_from(node.trace.depth).unwrap_or(u32::MAX),
+                    from: node.trace.caller,
+                    to: node.trace.address,
+                    value: node.trace.value,
+                    input: node.trace.data,
+                    output: node.trace.output,
+                })
+                .collect(),
+        ))
+    }
+
+    async fn block_details(&self, block_number: u64) -> EthApiResult<BlockDetails> {
+        let block_id = BlockNumberOrTag::Number(block_number);
+        let (block, receipts) = futures::try_join!(
+            self.eth_provider.block_by_number(block_id, false),
+            self.eth_provider.block_receipts(Some(block_id.into()))
+        )?;
+        let block = block.ok_or(EthApiError::UnknownBlock(block_number.into()))?;
+
+        // The base fee is burnt, the fees are the sum of the gas used times the effective gas price.
+        let total_fees = receipts
+            .unwrap_or_default()
+            .iter()
+            .map(|receipt| U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price))
+            .sum();
+
+        // There is no block reward in Kakarot.
+        Ok(BlockDetails::new(into_plain_block(block), Default::default(), total_fees))
+    }
+
+    async fn block_transactions(
+        &self,
+        block_number: u64,
+        page_number: usize,
+        page_size: usize,
+    ) -> EthApiResult<OtsBlockTransactions> {
+        let block_id = BlockNumberOrTag::Number(block_number);
+        let (block, receipts) = futures::try_join!(
+            self.eth_provider.block_by_number(block_id, true),
+            self.eth_provider.block_receipts(Some(block_id.into()))
+        )?;
+        let mut block = into_plain_block(block.ok_or(EthApiError::UnknownBlock(block_number.into()))?);
+        let mut receipts = receipts.unwrap_or_default();
+
+        let BlockTransactions::Full(transactions) = &mut block.transactions else {
+            return Err(EthApiError::UnknownBlock(block_number.into()));
+        };
+        let transaction_count = transactions.len();
+        receipts.sort_unstable_by_key(|receipt| receipt.transaction_index);
+
+        // The pages are counted from the end of the block.
+        let page_end = transaction_count.saturating_sub(page_number.saturating_mul(page_size));
+        let page_start = page_end.saturating_sub(page_size);
+        *transactions = transactions.drain(page_start..page_end).collect();
+
+        // Only the selector of the input is returned.
+        for transaction in transactions.iter_mut() {
+            if transaction.input.len() > 4 {
+                transaction.input = transaction.input.slice(..4);
+            }
+        }
+
+        let timestamp = Some(block.header.timestamp);
+        let receipts = receipts
+            .into_iter()
+            .skip(page_start)
+            .take(page_end - page_start)
+            .map(|receipt| into_ots_receipt(receipt, timestamp))
+            .collect();
+
+        let mut fullblock = OtsBlock::from(block);
+        fullblock.transaction_count = transaction_count;
+
+        Ok(OtsBlockTransactions { fullblock, receipts })
+    }
+
+    async fn search_transactions_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> EthApiResult<TransactionsWithReceipts> {
+        // A block number of 0 means that the search starts from the latest block.
+        let first_page = block_number == 0;
+        let to = if first_page { self.eth_provider.block_number().await?.to() } else { block_number - 1 };
+
+        let (transactions, truncated) = self.search_transactions(address, 0, to, true, page_size).await?;
+        let mut result = self.with_receipts(transactions).await?;
+        result.first_page = first_page;
+        result.last_page = !truncated;
+
+        Ok(result)
+    }
+
+    async fn search_transactions_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> EthApiResult<TransactionsWithReceipts> {
+        // A block number of 0 means that the search starts from the genesis block.
+        let last_page = block_number == 0;
+        let latest_block = self.eth_provider.block_number().await?.to();
+        let from = if last_page { 0 } else { block_number + 1 };
+
+        let (transactions, truncated) = self.search_transactions(address, from, latest_block, false, page_size).await?;
+        let mut result = self.with_receipts(transactions).await?;
+        result.first_page = !truncated;
+        result.last_page = last_page;
+
+        Ok(result)
+    }
+
+    async fn transaction_by_sender_and_nonce(&self, sender: Address, nonce: u64) -> EthApiResult<Option<B256>> {
+        let transaction = self.eth_provider.transaction_by_sender_and_nonce(sender, nonce).await?;
+        Ok(transaction.map(|tx| tx.hash))
+    }
+
+    async fn contract_creator(&self, address: Address) -> EthApiResult<Option<ContractCreator>> {
+        let receipt = self.eth_provider.contract_creation_receipt(address).await?;
+        Ok(receipt.map(|receipt| ContractCreator { hash: receipt.transaction_hash, creator: receipt.from }))
+    }
+}
+
+/// Converts the block into a block with plain transactions, as expected by the Otterscan types.
+fn into_plain_block(block: ExtendedBlock) -> Block<Transaction> {
+    let Block { header, uncles, transactions, size, withdrawals } = block.inner;
+    let transactions = match transactions {
+        BlockTransactions::Full(transactions) => {
+            BlockTransactions::Full(transactions.into_iter().map(|tx| tx.inner).collect())
+        }
+        BlockTransactions::Hashes(hashes) => BlockTransactions::Hashes(hashes),
+        BlockTransactions::Uncle => BlockTransactions::Uncle,
+    };
+    Block { header, uncles, transactions, size, withdrawals }
+}
+
+/// Converts the receipt into an Otterscan receipt, without the logs.
+fn into_ots_receipt(receipt: ExtendedTxReceipt, timestamp: Option<u64>) -> OtsTransactionReceipt {
+    let receipt = receipt.inner.map_inner(|envelope| OtsReceipt {
+        status: envelope.status(),
+        cumulative_gas_used: u64::try_from(envelope.cumulative_gas_used()).unwrap_or(u64::MAX),
+        logs: None,
+        logs_bloom: None,
+        r#type: envelope.tx_type().into(),
+    });
+    OtsTransactionReceipt { receipt, timestamp }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        providers::eth_provider::database::types::receipt::StoredTransactionReceipt,
+        test_utils::mock_provider::MockEthereumProviderStruct,
+    };
+    use alloy_primitives::U64;
+    use alloy_rpc_types::Header;
+    use alloy_serde::WithOtherFields;
+    use arbitrary::Arbitrary;
+
+    fn transaction(block_number: u8, index: u8) -> ExtendedTransaction {
+        WithOtherFields::new(Transaction {
+            hash: B256::left_padding_from(&[block_number, index]),
+            block_number: Some(block_number.into()),
+            transaction_index: Some(index.into()),
+            input: Bytes::from(vec![0xaa; 36]),
+            ..Default::default()
+        })
+    }
+
+    fn receipt(transaction: &ExtendedTransaction) -> ExtendedTxReceipt {
+        let bytes = [0u8; 1024];
+        let mut receipt =
+            StoredTransactionReceipt::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap().receipt;
+        receipt.transaction_hash = transaction.hash;
+        receipt.transaction_index = transaction.transaction_index;
+        receipt.block_number = transaction.block_number;
+        receipt
+    }
+
+    #[tokio::test]
+    async fn test_search_transactions_before_completes_the_last_block() {
+        // Given
+        let mut mock_provider = MockEthereumProviderStruct::new();
+        mock_provider.expect_block_number().returning(|| Ok(U64::from(10)));
+        // The page of 2 transactions ends in the middle of block 8.
+        mock_provider
+            .expect_transactions_by_address()
+            .withf(|_, from, to, descending, limit| (*from, *to, *descending, *limit) == (0, 10, true, Some(2)))
+            .returning(|_, _, _, _, _| Ok(vec![transaction(9, 0), transaction(8, 1)]));
+        mock_provider
+            .expect_transactions_by_address()
+            .withf(|_, from, to, descending, limit| (*from, *to, *descending, *limit) == (8, 8, true, None))
+            .returning(|_, _, _, _, _| Ok(vec![transaction(8, 1), transaction(8, 0)]));
+        mock_provider.expect_header().returning(|_| Ok(Some(Header { timestamp: 42, ..Default::default() })));
+        mock_provider
+            .expect_transaction_receipt()
+            .returning(|hash| Ok(Some(receipt(&transaction(hash[30], hash[31])))));
+        let otterscan_provider = OtterscanDataProvider::new(mock_provider);
+
+        // When
+        let result = otterscan_provider.search_transactions_before(Address::ZERO, 0, 2).await.unwrap();
+
+        // Then
+        let hashes: Vec<_> = result.txs.iter().map(|tx| tx.hash).collect();
+        assert_eq!(hashes, vec![transaction(9, 0).hash, transaction(8, 1).hash, transaction(8, 0).hash]);
+        assert_eq!(result.receipts.len(), 3);
+        assert!(result.receipts.iter().all(|receipt| receipt.timestamp == Some(42)));
+        assert!(result.first_page);
+        assert!(!result.last_page);
+    }
+
+    #[tokio::test]
+    async fn test_search_transactions_rejects_an_empty_page() {
+        // Given
+        let mut mock_provider = MockEthereumProviderStruct::new();
+        mock_provider.expect_block_number().returning(|| Ok(U64::from(10)));
+        let otterscan_provider = OtterscanDataProvider::new(mock_provider);
+
+        // When
+        let result = otterscan_provider.search_transactions_before(Address::ZERO, 0, 0).await;
+
+        // Then
+        assert!(matches!(result, Err(EthApiError::RethEthApi(RethEthApiError::InvalidParams(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_search_transactions_caps_the_page_size() {
+        // Given
+        let mut mock_provider = MockEthereumProviderStruct::new();
+        mock_provider.expect_block_number().returning(|| Ok(U64::from(10)));
+        mock_provider
+            .expect_transactions_by_address()
+            .withf(|_, _, _, _, limit| *limit == Some(MAX_SEARCH_PAGE_SIZE as u64))
+            .returning(|_, _, _, _, _| Ok(vec![transaction(9, 0)]));
+        mock_provider.expect_header().returning(|_| Ok(Some(Header::default())));
+        mock_provider
+            .expect_transaction_receipt()
+            .returning(|hash| Ok(Some(receipt(&transaction(hash[30], hash[31])))));
+        let otterscan_provider = OtterscanDataProvider::new(mock_provider);
+
+        // When
+        let result = otterscan_provider.search_transactions_before(Address::ZERO, 0, usize::MAX).await.unwrap();
+
+        // Then
+        assert_eq!(result.txs.len(), 1);
+        assert!(result.last_page);
+    }
+
+    #[tokio::test]
+    async fn test_block_transactions_pages_from_the_end_of_the_block() {
+        // Given
+        let mut mock_provider = MockEthereumProviderStruct::new();
+        let transactions: Vec<_> = (0..5).map(|index| transaction(1, index)).collect();
+        let receipts: Vec<_> = transactions.iter().rev().map(receipt).collect();
+        mock_provider.expect_block_by_number().returning(move |_, _| {
+            Ok(Some(WithOtherFields::new(Block {
+                transactions: BlockTransactions::Full(transactions.clone()),
+                ..Default::default()
+            })))
+        });
+        mock_provider.expect_block_receipts().returning(move |_| Ok(Some(receipts.clone())));
+        let otterscan_provider = OtterscanDataProvider::new(mock_provider);
+
+        // When
+        let first_page = otterscan_provider.block_transactions(1, 0, 2).await.unwrap();
+        let last_page = otterscan_provider.block_transactions(1, 2, 2).await.unwrap();
+
+        // Then
+        let BlockTransactions::Full(first_transactions) = &first_page.fullblock.block.transactions else {
+            panic!("Expected full transactions");
+        };
+        assert_eq!(first_page.fullblock.transaction_count, 5);
+        assert_eq!(first_transactions.iter().map(|tx| tx.transaction_index).collect::<Vec<_>>(), [Some(3), Some(4)]);
+        assert!(first_transactions.iter().all(|tx| tx.input.len() == 4));
+        assert_eq!(
+            first_page.receipts.iter().map(|receipt| receipt.receipt.transaction_index).collect::<Vec<_>>(),
+            [Some(3), Some(4)]
+        );
+
+        let BlockTransactions::Full(last_transactions) = &last_page.fullblock.block.transactions else {
+            panic!("Expected full transactions");
+        };
+        assert_eq!(last_transactions.iter().map(|tx| tx.transaction_index).collect::<Vec<_>>(), [Some(0)]);
+        assert_eq!(last_page.receipts.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_contract_creator() {
+        // Given
+        let mut mock_provider = MockEthereumProviderStruct::new();
+        let contract = Address::with_last_byte(1);
+        let creation = receipt(&transaction(1, 0));
+        let expected = (creation.transaction_hash, creation.from);
+        mock_provider
+            .expect_contract_creation_receipt()
+            .returning(move |address| Ok((address == contract).then(|| creation.clone())));
+        let otterscan_provider = OtterscanDataProvider::new(mock_provider);
+
+        // When
+        let creator = otterscan_provider.contract_creator(contract).await.unwrap();
+        let unknown_creator = otterscan_provider.contract_creator(Address::ZERO).await.unwrap();
+
+        // Then
+        assert_eq!(creator.map(|creator| (creator.hash, creator.creator)), Some(expected));
+        assert!(unknown_creator.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_error_unknown_transaction() {
+        // Given
+        let mut mock_provider = MockEthereumProviderStruct::new();
+        mock_provider.expect_chain_id().returning(|| Ok(Some(U64::from(1))));
+        mock_provider.expect_transaction_by_hash().returning(|_| Ok(None));
+        let otterscan_provider = OtterscanDataProvider::new(mock_provider);
+
+        // When
+        let error = otterscan_provider.transaction_error(B256::ZERO).await.unwrap();
+        let trace = otterscan_provider.trace_transaction(B256::ZERO).await.unwrap();
+
+        // Then
+        assert_eq!(error, None);
+        assert!(trace.is_none());
+    }
+}
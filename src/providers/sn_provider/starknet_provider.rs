@@ -46,8 +46,21 @@ where
     /// If the contract is not deployed or the class hash is not declared, a balance of 0 is returned
     /// instead of an error.
     pub async fn balance_at(&self, address: Felt, block_id: BlockId) -> Result<U256, ExecutionError> {
-        // Create a new `ERC20Reader` instance for the Starknet native token
-        let eth_contract = ERC20Reader::new(*STARKNET_NATIVE_TOKEN, &self.provider);
+        self.token_balance_at(*STARKNET_NATIVE_TOKEN, address, block_id).await
+    }
+
+    /// Retrieves the balance of a Starknet address in the given ERC20 token for a specified block.
+    ///
+    /// If the contract is not deployed or the class hash is not declared, a balance of 0 is returned
+    /// instead of an error.
+    pub async fn token_balance_at(
+        &self,
+        token: Felt,
+        address: Felt,
+        block_id: BlockId,
+    ) -> Result<U256, ExecutionError> {
+        // Create a new `ERC20Reader` instance for the token
+        let eth_contract = ERC20Reader::new(token, &self.provider);
 
         // Call the `balanceOf` method on the contract for the given address and block ID, awaiting the result
         let span = tracing::span!(tracing::Level::INFO, "sn::balance");
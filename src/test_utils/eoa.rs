@@ -1,10 +1,7 @@
 use crate::{
     client::{EthClient, KakarotTransactions},
     into_via_try_wrapper,
-    providers::eth_provider::{
-        starknet::{kakarot_core::starknet_address, relayer::Relayer},
-        ChainProvider, TransactionProvider,
-    },
+    providers::eth_provider::{starknet::relayer::Relayer, ChainProvider, TransactionProvider},
     test_utils::{
         evm_contract::{EvmContract, KakarotEvmContract, TransactionInfo, TxCommonInfo, TxFeeMarketInfo},
         tx_waiter::watch_tx,
@@ -36,7 +33,7 @@ pub const TX_GAS_PRICE: u64 = 10;
 #[async_trait]
 pub trait Eoa<P: Provider + Send + Sync + Clone> {
     fn starknet_address(&self) -> Result<Felt, eyre::Error> {
-        Ok(starknet_address(self.evm_address()?))
+        Ok(self.eth_client().eth_provider().deployment().starknet_address(self.evm_address()?))
     }
 
     fn evm_address(&self) -> Result<Address, eyre::Error> {
@@ -79,6 +76,7 @@ pub struct KakarotEOA<P: Provider + Send + Sync + Clone + 'static> {
     pub private_key: B256,
     pub eth_client: Arc<EthClient<P>>,
     pub relayer: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    pub relayer_signer: LocalWallet,
 }
 
 impl<P: Provider + Send + Sync + Clone> KakarotEOA<P> {
@@ -86,8 +84,9 @@ impl<P: Provider + Send + Sync + Clone> KakarotEOA<P> {
         private_key: B256,
         eth_client: Arc<EthClient<P>>,
         relayer: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+        relayer_signer: LocalWallet,
     ) -> Self {
-        Self { private_key, eth_client, relayer }
+        Self { private_key, eth_client, relayer, relayer_signer }
     }
 }
 
@@ -161,6 +160,8 @@ impl<P: Provider + Send + Sync + Clone> KakarotEOA<P> {
             self.relayer.address(),
             relayer_balance,
             self.starknet_provider(),
+            self.relayer_signer.clone().into(),
+            self.eth_client.eth_provider().deployment().clone(),
             Some(Arc::new(self.eth_client.eth_provider().database().clone())),
         )
         .relay_transaction(&tx_signed)
@@ -235,6 +236,8 @@ impl<P: Provider + Send + Sync + Clone> KakarotEOA<P> {
             self.relayer.address(),
             relayer_balance,
             self.starknet_provider(),
+            self.relayer_signer.clone().into(),
+            self.eth_client.eth_provider().deployment().clone(),
             Some(Arc::new(self.eth_client.eth_provider().database().clone())),
         )
         .relay_transaction(&tx_signed)
@@ -3,6 +3,7 @@ pub mod genesis;
 use super::mongo::MongoImage;
 use crate::{
     client::EthClient,
+    config::{ConfigSource, LimitsConfig, SpamProtectionConfig},
     constants::KKRT_BLOCK_GAS_LIMIT,
     providers::eth_provider::{
         constant::U64_HEX_STRING_LEN,
@@ -18,6 +19,8 @@ use crate::{
             CollectionName,
         },
         provider::EthDataProvider,
+        starknet::kakarot_core::KakarotDeployment,
+        BlockProvider,
     },
     test_utils::eoa::KakarotEOA,
 };
@@ -34,7 +37,10 @@ use mongodb::{
     options::{UpdateModifications, UpdateOptions},
 };
 use reth_transaction_pool::PoolConfig;
-use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient};
+use starknet::{
+    providers::{jsonrpc::HttpTransport, JsonRpcClient, Provider},
+    signers::{LocalWallet, SigningKey},
+};
 use std::{path::Path, sync::Arc};
 use testcontainers::ContainerAsync;
 #[cfg(any(test, feature = "arbitrary", feature = "testing"))]
@@ -82,6 +88,34 @@ pub async fn katana_sequencer() -> TestSequencer {
     .await
 }
 
+/// Returns the Kakarot deployment of the sequencer, at the addresses of the `KAKAROT_ADDRESS` and
+/// `UNINITIALIZED_ACCOUNT_CLASS_HASH` environment variables (see `make load-env`).
+#[cfg(any(test, feature = "arbitrary", feature = "testing"))]
+async fn kakarot_deployment(
+    sequencer: &TestSequencer,
+    starknet_provider: &JsonRpcClient<HttpTransport>,
+) -> KakarotDeployment {
+    let source = ConfigSource::env();
+    KakarotDeployment::new(
+        sequencer.url(),
+        source.require("KAKAROT_ADDRESS").expect("Failed to load Kakarot address"),
+        source.require("UNINITIALIZED_ACCOUNT_CLASS_HASH").expect("Failed to load uninitialized account class hash"),
+        starknet_provider.chain_id().await.expect("Failed to get Starknet chain id"),
+    )
+}
+
+/// Returns the limits of the `MAX_LOGS`, `MAX_FELTS_IN_CALLDATA` and
+/// `WHITE_LISTED_EIP_155_TRANSACTION_HASHES` environment variables (see `make load-env`).
+#[cfg(any(test, feature = "arbitrary", feature = "testing"))]
+fn kakarot_limits() -> LimitsConfig {
+    LimitsConfig::from_source(&ConfigSource::env()).expect("Failed to load Kakarot limits")
+}
+
+/// Returns the signer of the sequencer account, used as relayer.
+pub fn relayer_signer(sequencer: &TestSequencer) -> LocalWallet {
+    LocalWallet::from_signing_key(SigningKey::from_secret_scalar(sequencer.raw_account().private_key))
+}
+
 /// Represents the Katana test environment.
 #[allow(missing_debug_implementations)]
 pub struct Katana {
@@ -128,11 +162,8 @@ impl<'a> Katana {
         let pk = std::env::var("EVM_PRIVATE_KEY").expect("Failed to get EVM private key");
         let pk = B256::from_str(&pk).expect("Failed to parse EVM private key");
 
-        // Set the relayer private key in the environment variables.
-        std::env::set_var("RELAYER_PRIVATE_KEY", format!("0x{:x}", sequencer.raw_account().private_key));
-
-        // Set the starknet network in the environment variables.
-        std::env::set_var("STARKNET_NETWORK", format!("{}", sequencer.url()));
+        // Load the Kakarot deployment of the sequencer.
+        let deployment = kakarot_deployment(&sequencer, &starknet_provider).await;
 
         // Initialize a MongoFuzzer instance with the specified random bytes size.
         let mut mongo_fuzzer = MongoFuzzer::new(0).await;
@@ -157,12 +188,15 @@ impl<'a> Katana {
         // Initialize the EthClient
         let eth_client = EthClient::new(
             starknet_provider,
+            deployment,
+            kakarot_limits(),
             PoolConfig { gas_limit: KKRT_BLOCK_GAS_LIMIT, ..Default::default() },
+            SpamProtectionConfig::default(),
             database,
         );
 
         // Create a new Kakarot EOA instance with the private key and EthDataProvider instance.
-        let eoa = KakarotEOA::new(pk, Arc::new(eth_client.clone()), sequencer.account());
+        let eoa = KakarotEOA::new(pk, Arc::new(eth_client.clone()), sequencer.account(), relayer_signer(&sequencer));
 
         // Return a new instance of Katana with initialized fields.
         Self {
@@ -189,11 +223,8 @@ impl<'a> Katana {
         let pk = std::env::var("EVM_PRIVATE_KEY").expect("Failed to get EVM private key");
         let pk = B256::from_str(&pk).expect("Failed to parse EVM private key");
 
-        // Set the relayer private key in the environment variables.
-        std::env::set_var("RELAYER_PRIVATE_KEY", format!("0x{:x}", sequencer.raw_account().private_key));
-
-        // Set the starknet network in the environment variables.
-        std::env::set_var("STARKNET_NETWORK", format!("{}", sequencer.url()));
+        // Load the Kakarot deployment of the sequencer.
+        let deployment = kakarot_deployment(&sequencer, &starknet_provider).await;
 
         // Initialize a MongoFuzzer instance with the specified random bytes size.
         let mut mongo_fuzzer = MongoFuzzer::new(rnd_bytes_size).await;
@@ -208,12 +239,15 @@ impl<'a> Katana {
         // Initialize the EthClient
         let eth_client = EthClient::new(
             starknet_provider,
+            deployment,
+            kakarot_limits(),
             PoolConfig { gas_limit: KKRT_BLOCK_GAS_LIMIT, ..Default::default() },
+            SpamProtectionConfig::default(),
             database,
         );
 
         // Create a new Kakarot EOA instance with the private key and EthDataProvider instance.
-        let eoa = KakarotEOA::new(pk, Arc::new(eth_client.clone()), sequencer.account());
+        let eoa = KakarotEOA::new(pk, Arc::new(eth_client.clone()), sequencer.account(), relayer_signer(&sequencer));
 
         // Return a new instance of Katana with initialized fields.
         Self {
@@ -336,6 +370,48 @@ impl<'a> Katana {
             .expect("Failed to update block number");
     }
 
+    /// Adds receipts to the database.
+    pub async fn add_receipts_to_database(&self, receipts: Vec<ExtendedTxReceipt>) {
+        let provider = self.eth_provider();
+        let database = provider.database();
+
+        for receipt in receipts {
+            let filter =
+                EthDatabaseFilterBuilder::<filter::Receipt>::default().with_tx_hash(&receipt.transaction_hash).build();
+            database
+                .update_one(StoredTransactionReceipt { receipt }, filter, true)
+                .await
+                .expect("Failed to update receipt in database");
+        }
+    }
+
+    /// Returns the hashes of the transactions of the block.
+    pub async fn block_transaction_hashes(&self, block_number: u64) -> Vec<B256> {
+        let block = self
+            .eth_provider()
+            .block_by_number(block_number.into(), false)
+            .await
+            .expect("Failed to get block")
+            .expect("Block not found");
+        block.transactions.as_hashes().expect("Expected transaction hashes").to_vec()
+    }
+
+    /// Adds logs to the database, with their block number padded as in the indexed logs.
+    pub async fn add_logs_to_database(&self, logs: Vec<Log>) {
+        let provider = self.eth_provider();
+        let collection = provider.database().inner().collection::<Document>(StoredLog::collection_name());
+
+        for log in logs {
+            let block_number = log.block_number.unwrap_or_default();
+            let mut log_doc = bson::to_document(&StoredLog { log }).expect("Failed to serialize StoredLog to BSON");
+            log_doc
+                .get_document_mut("log")
+                .expect("Missing log document")
+                .insert("blockNumber", format_hex(block_number, U64_HEX_STRING_LEN));
+            collection.insert_one(log_doc).await.expect("Failed to insert log into the database");
+        }
+    }
+
     /// Retrieves the first stored transaction
     pub fn first_transaction(&self) -> Option<ExtendedTransaction> {
         self.transactions.first().map(Into::into)
@@ -65,6 +65,8 @@ mock! {
         async fn transaction_receipt(&self, hash: B256) -> EthApiResult<Option<ExtendedTxReceipt>>;
 
         async fn block_receipts(&self, block_id: Option<BlockId>) -> EthApiResult<Option<Vec<ExtendedTxReceipt>>>;
+
+        async fn contract_creation_receipt(&self, address: Address) -> EthApiResult<Option<ExtendedTxReceipt>>;
     }
 
     #[async_trait]
@@ -75,7 +77,11 @@ mock! {
 
         async fn get_code(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<Bytes>;
 
+        async fn is_account_deployed(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<bool>;
+
         async fn call(&self, request: TransactionRequest, block_id: Option<BlockId>, state_overrides: Option<alloy_rpc_types::state::StateOverride>, block_overrides: Option<Box<alloy_rpc_types::BlockOverrides>>) -> EthApiResult<Bytes>;
+
+        async fn create_access_list(&self, request: TransactionRequest, block_id: Option<BlockId>) -> EthApiResult<alloy_rpc_types::AccessListResult>;
     }
 
     #[async_trait]
@@ -87,5 +93,9 @@ mock! {
         async fn transaction_by_block_number_and_index(&self, number_or_tag: BlockNumberOrTag, index: alloy_rpc_types::Index) -> EthApiResult<Option<ExtendedTransaction>>;
 
         async fn transaction_count(&self, address: Address, block_id: Option<BlockId>) -> EthApiResult<U256>;
+
+        async fn transaction_by_sender_and_nonce(&self, sender: Address, nonce: u64) -> EthApiResult<Option<ExtendedTransaction>>;
+
+        async fn transactions_by_address(&self, address: Address, from: u64, to: u64, descending: bool, limit: Option<u64>) -> EthApiResult<Vec<ExtendedTransaction>>;
     }
 }
@@ -0,0 +1,134 @@
+use crate::providers::eth_provider::starknet::kakarot_core::ETH_SEND_TRANSACTION;
+use mockito::{Matcher, Mock, Server, ServerGuard};
+use serde_json::{json, Value};
+use starknet::{
+    core::types::Felt,
+    providers::{jsonrpc::HttpTransport, JsonRpcClient},
+};
+use url::Url;
+
+/// A mocked Starknet JSON-RPC node, answering each method with the configured response.
+#[derive(Debug)]
+pub struct MockStarknet {
+    server: ServerGuard,
+}
+
+impl MockStarknet {
+    /// Starts the mocked node.
+    pub async fn new() -> Self {
+        Self { server: Server::new_async().await }
+    }
+
+    /// Returns the URL of the mocked node.
+    pub fn url(&self) -> Url {
+        Url::parse(&self.server.url()).expect("Failed to parse mock server url")
+    }
+
+    /// Returns a provider connected to the mocked node.
+    pub fn provider(&self) -> JsonRpcClient<HttpTransport> {
+        JsonRpcClient::new(HttpTransport::new(self.url()))
+    }
+
+    /// Answers the calls to the method with the result.
+    pub async fn mock_result(&mut self, method: &str, result: Value) -> Mock {
+        self.mock(method, json!({ "jsonrpc": "2.0", "id": 1, "result": result })).await
+    }
+
+    /// Answers the calls to the method with the Starknet error.
+    pub async fn mock_error(&mut self, method: &str, code: i64, message: &str, data: Option<Value>) -> Mock {
+        let mut error = json!({ "code": code, "message": message });
+        if let Some(data) = data {
+            error["data"] = data;
+        }
+        self.mock(method, json!({ "jsonrpc": "2.0", "id": 1, "error": error })).await
+    }
+
+    async fn mock(&mut self, method: &str, body: Value) -> Mock {
+        self.server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({ "method": method })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create_async()
+            .await
+    }
+}
+
+/// Returns a fee estimate of the overall fee, paid in wei.
+pub fn fee_estimate(overall_fee: u64) -> Value {
+    json!({
+        "gas_consumed": Felt::from(overall_fee),
+        "gas_price": Felt::ONE,
+        "data_gas_consumed": Felt::ZERO,
+        "data_gas_price": Felt::ZERO,
+        "overall_fee": Felt::from(overall_fee),
+        "unit": "WEI",
+    })
+}
+
+/// Returns a simulated invoke transaction with the execute invocation and the overall fee.
+pub fn simulated_transaction(execute_invocation: Value, overall_fee: u64) -> Value {
+    json!({
+        "transaction_trace": {
+            "type": "INVOKE",
+            "execute_invocation": execute_invocation,
+            "execution_resources": {
+                "steps": 0,
+                "data_availability": { "l1_gas": 0, "l1_data_gas": 0 },
+            },
+        },
+        "fee_estimation": fee_estimate(overall_fee),
+    })
+}
+
+/// Returns a reverted execute invocation.
+pub fn reverted_invocation(revert_reason: &str) -> Value {
+    json!({ "revert_reason": revert_reason })
+}
+
+/// Returns a successful execute invocation, whose nested call to Kakarot's `eth_send_transaction`
+/// returns the result.
+pub fn eth_send_transaction_invocation(result: &[Felt]) -> Value {
+    let invocation = |selector: Felt, result: &[Felt], calls: Vec<Value>| {
+        json!({
+            "contract_address": Felt::ONE,
+            "entry_point_selector": selector,
+            "calldata": [],
+            "caller_address": Felt::ZERO,
+            "class_hash": Felt::ONE,
+            "entry_point_type": "EXTERNAL",
+            "call_type": "CALL",
+            "result": result,
+            "calls": calls,
+            "events": [],
+            "messages": [],
+            "execution_resources": { "steps": 0 },
+        })
+    };
+    invocation(Felt::from(0x1234_u64), &[], vec![invocation(*ETH_SEND_TRANSACTION, result, vec![])])
+}
+
+/// Returns the receipt of an invoke transaction, reverted with the reason if any.
+pub fn invoke_receipt(transaction_hash: Felt, revert_reason: Option<&str>) -> Value {
+    let mut receipt = json!({
+        "type": "INVOKE",
+        "transaction_hash": transaction_hash,
+        "actual_fee": { "amount": "0x0", "unit": "WEI" },
+        "execution_status": "SUCCEEDED",
+        "finality_status": "ACCEPTED_ON_L2",
+        "block_hash": Felt::ONE,
+        "block_number": 1,
+        "messages_sent": [],
+        "events": [],
+        "execution_resources": {
+            "steps": 0,
+            "data_availability": { "l1_gas": 0, "l1_data_gas": 0 },
+        },
+    });
+    if let Some(revert_reason) = revert_reason {
+        receipt["execution_status"] = json!("REVERTED");
+        receipt["revert_reason"] = json!(revert_reason);
+    }
+    receipt
+}
@@ -6,6 +6,8 @@ pub mod hive;
 pub mod katana;
 pub mod macros;
 pub mod mock_provider;
+pub mod mock_starknet;
 pub mod mongo;
 pub mod rpc;
+pub mod signer;
 pub mod tx_waiter;
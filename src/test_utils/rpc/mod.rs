@@ -1,5 +1,8 @@
 use super::katana::Katana;
-use crate::eth_rpc::{config::RPCConfig, rpc::KakarotRpcModuleBuilder, run_server};
+use crate::{
+    eth_rpc::{config::RPCConfig, rpc::KakarotRpcModuleBuilder, run_server},
+    prometheus_handler::Registry,
+};
 use jsonrpsee::server::ServerHandle;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -62,6 +65,7 @@ pub async fn start_kakarot_rpc_server(katana: &Katana) -> Result<(SocketAddr, Se
         RPCConfig::new_test_config_from_port(rand::random()),
         #[cfg(not(feature = "testing"))]
         RPCConfig::from_port(3030),
+        Registry::new(),
     )
     .await?)
 }
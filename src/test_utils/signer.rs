@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use starknet::{
+    core::{crypto::Signature, types::Felt},
+    signers::{LocalWallet, Signer, VerifyingKey},
+};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokio::sync::{mpsc, oneshot};
+
+/// A request sent to the signing task of a [`RemoteSigner`].
+type SignRequest = (Felt, oneshot::Sender<Result<Signature, RemoteSignerError>>);
+
+/// Error returned by a [`RemoteSigner`].
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteSignerError {
+    #[error("remote signer is unavailable")]
+    Unavailable,
+    #[error("remote signer failed to sign: {0}")]
+    Sign(String),
+}
+
+/// A stand-in for a remote signer, e.g. a KMS or an HSM.
+///
+/// The private key never leaves the signing task spawned at creation, the signer only forwards
+/// the hashes to sign to it over a channel, like a remote signer would over the network.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    public_key: VerifyingKey,
+    requests: mpsc::UnboundedSender<SignRequest>,
+    signed: Arc<AtomicUsize>,
+}
+
+impl RemoteSigner {
+    /// Spawns the signing task holding the wallet and returns the signer connected to it.
+    pub async fn spawn(wallet: LocalWallet) -> Self {
+        let public_key = wallet.get_public_key().await.expect("Failed to get public key");
+        let (requests, mut receiver) = mpsc::unbounded_channel::<SignRequest>();
+        tokio::spawn(async move {
+            while let Some((hash, response)) = receiver.recv().await {
+                let signature = wallet.sign_hash(&hash).await.map_err(|err| RemoteSignerError::Sign(err.to_string()));
+                let _ = response.send(signature);
+            }
+        });
+        Self { public_key, requests, signed: Arc::default() }
+    }
+
+    /// Returns the number of hashes signed by the signing task.
+    pub fn signed(&self) -> usize {
+        self.signed.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    type GetPublicKeyError = RemoteSignerError;
+    type SignError = RemoteSignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        Ok(self.public_key.clone())
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, Self::SignError> {
+        let (response, receiver) = oneshot::channel();
+        self.requests.send((*hash, response)).map_err(|_| RemoteSignerError::Unavailable)?;
+        let signature = receiver.await.map_err(|_| RemoteSignerError::Unavailable)??;
+        self.signed.fetch_add(1, Ordering::SeqCst);
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
@@ -6,15 +6,20 @@ use crate::providers::eth_provider::{
     },
     error::{EthApiError, TransactionError},
     provider::EthereumProvider,
+    revm_utils::block_env,
+};
+use alloy_primitives::B256;
+use alloy_rpc_types::{state::EvmOverrides, Block, BlockId, BlockTransactions};
+use alloy_rpc_types_trace::{
+    geth::{GethDebugTracingCallOptions, GethDebugTracingOptions},
+    parity::TraceType,
 };
-use alloy_primitives::{B256, U256};
-use alloy_rpc_types::{Block, BlockId, BlockTransactions, Header};
-use alloy_rpc_types_trace::geth::{GethDebugTracingCallOptions, GethDebugTracingOptions};
 use reth_revm::{
     db::CacheDB,
-    primitives::{BlockEnv, CfgEnv, Env, EnvWithHandlerCfg, HandlerCfg, SpecId},
+    primitives::{CfgEnv, Env, EnvWithHandlerCfg, HandlerCfg, SpecId},
 };
 use revm_inspectors::tracing::TracingInspectorConfig;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 pub struct Floating;
@@ -30,6 +35,10 @@ pub enum TracingOptions {
     Parity(TracingInspectorConfig),
     /// Geth debug call tracing options.
     GethCall(GethDebugTracingCallOptions),
+    /// Parity replay tracing options, with the selected trace types.
+    ParityReplay(HashSet<TraceType>),
+    /// Parity call tracing options, with the overrides applied before the calls.
+    ParityCall(EvmOverrides),
 }
 
 impl TracingOptions {
@@ -62,6 +71,31 @@ impl TracingOptions {
             None
         }
     }
+
+    /// Returns `Some` with a reference to the selected [`TraceType`]s if this is `ParityReplay`,
+    /// otherwise returns `None`.
+    pub const fn as_parity_replay(&self) -> Option<&HashSet<TraceType>> {
+        if let Self::ParityReplay(ref trace_types) = self {
+            Some(trace_types)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `Some` with a reference to [`EvmOverrides`] if this is `ParityCall`,
+    /// otherwise returns `None`.
+    pub const fn as_parity_call(&self) -> Option<&EvmOverrides> {
+        if let Self::ParityCall(ref overrides) = self {
+            Some(overrides)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the options are used to trace calls instead of the transactions of a block.
+    pub const fn is_call(&self) -> bool {
+        matches!(self, Self::GethCall(_) | Self::ParityCall(_))
+    }
 }
 
 impl Default for TracingOptions {
@@ -88,6 +122,18 @@ impl From<GethDebugTracingCallOptions> for TracingOptions {
     }
 }
 
+impl From<HashSet<TraceType>> for TracingOptions {
+    fn from(trace_types: HashSet<TraceType>) -> Self {
+        Self::ParityReplay(trace_types)
+    }
+}
+
+impl From<EvmOverrides> for TracingOptions {
+    fn from(overrides: EvmOverrides) -> Self {
+        Self::ParityCall(overrides)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TracerBuilder<P: EthereumProvider + Send + Sync + Clone, Status = Floating> {
     eth_provider: P,
@@ -187,9 +233,14 @@ impl<P: EthereumProvider + Send + Sync + Clone> TracerBuilder<P, Pinned> {
         };
 
         let env = self.init_env_with_handler_config();
-        // DB should use the state of the parent block
-        let db =
-            EthCacheDatabase(CacheDB::new(EthDatabase::new(self.eth_provider, self.block.header.parent_hash.into())));
+        // Calls are executed on top of the state of the block, while the
+        // transactions of the block should use the state of the parent block.
+        let state_block_id: BlockId = if self.tracing_options.is_call() {
+            self.block.header.number.into()
+        } else {
+            self.block.header.parent_hash.into()
+        };
+        let db = EthCacheDatabase(CacheDB::new(EthDatabase::new(self.eth_provider, state_block_id)));
 
         let tracing_options = self.tracing_options;
 
@@ -205,18 +256,7 @@ impl<P: EthereumProvider + Send + Sync + Clone> TracerBuilder<P, Pinned> {
     /// Inits the Env by using `self.block` to set the block environment.
     fn init_env_with_block_env(&self) -> Env {
         let mut env = self.env.clone();
-
-        let Header { number, timestamp, miner, base_fee_per_gas, difficulty, .. } = self.block.header.clone();
-        let block_env = BlockEnv {
-            number: U256::from(number),
-            timestamp: U256::from(timestamp),
-            gas_limit: U256::from(TRACING_BLOCK_GAS_LIMIT),
-            coinbase: miner,
-            basefee: U256::from(base_fee_per_gas.unwrap_or_default()),
-            prevrandao: Some(B256::from_slice(&difficulty.to_be_bytes::<32>()[..])),
-            ..Default::default()
-        };
-        env.block = block_env;
+        env.block = block_env(&self.block.header, TRACING_BLOCK_GAS_LIMIT);
         env
     }
 }
@@ -229,7 +269,7 @@ mod tests {
     use super::*;
     use crate::test_utils::mock_provider::MockEthereumProviderStruct;
     use alloy_primitives::U64;
-    use alloy_rpc_types::Transaction;
+    use alloy_rpc_types::{Header, Transaction};
     use alloy_serde::WithOtherFields;
     use std::sync::Arc;
 
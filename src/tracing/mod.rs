@@ -2,31 +2,38 @@ pub mod builder;
 
 use crate::{
     providers::eth_provider::{
-        database::state::EthCacheDatabase,
+        constant::CALL_REQUEST_GAS_LIMIT,
+        database::state::{EthCacheDatabase, EthDatabase},
         error::{EthApiError, TransactionError},
         provider::EthereumProvider,
+        revm_utils::{apply_block_overrides, call_cfg_env, tx_env_from_request},
     },
     tracing::builder::TracingOptions,
 };
-use alloy_primitives::{ruint::FromUintError, B256};
-use alloy_rpc_types::{TransactionInfo, TransactionRequest};
+use alloy_primitives::{ruint::FromUintError, Bytes, B256};
+use alloy_rpc_types::{state::EvmOverrides, TransactionInfo, TransactionRequest};
 use alloy_rpc_types_trace::{
     geth::{
-        GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions, GethDebugTracingOptions,
-        GethTrace, TraceResult,
+        FourByteFrame, GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions,
+        GethDebugTracingOptions, GethTrace, NoopFrame, TraceResult,
     },
-    parity::LocalizedTransactionTrace,
+    parity::{LocalizedTransactionTrace, TraceResults, TraceResultsWithTransactionHash, TraceType},
 };
 use alloy_serde::WithOtherFields;
 use eyre::eyre;
 use reth_evm_ethereum::EthEvmConfig;
 use reth_node_api::{ConfigureEvm, ConfigureEvmEnv};
 use reth_revm::{
-    primitives::{Env, EnvWithHandlerCfg},
-    DatabaseCommit,
+    db::CacheDB,
+    inspectors::NoOpInspector,
+    primitives::{Env, EnvWithHandlerCfg, ExecutionResult, ResultAndState},
+    Database, DatabaseCommit, Inspector,
+};
+use revm_inspectors::tracing::{FourByteInspector, MuxInspector, TracingInspector, TracingInspectorConfig};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
 };
-use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
-use std::{collections::HashMap, sync::Arc};
 
 pub type TracerResult<T> = Result<T, EthApiError>;
 
@@ -40,6 +47,8 @@ enum TracingResult {
     Geth(Vec<TraceResult>),
     /// Parity trace results.
     Parity(Vec<LocalizedTransactionTrace>),
+    /// Parity replay trace results.
+    ParityReplay(Vec<TraceResultsWithTransactionHash>),
 }
 
 impl TracingResult {
@@ -61,6 +70,15 @@ impl TracingResult {
         }
     }
 
+    /// Converts the tracing result into Parity replay traces.
+    const fn as_parity_replay(&self) -> Option<&Vec<TraceResultsWithTransactionHash>> {
+        if let Self::ParityReplay(traces) = self {
+            Some(traces)
+        } else {
+            None
+        }
+    }
+
     /// Creates a default failure [`TracingResult`] based on the [`TracingOptions`].
     fn default_failure(tracing_options: &TracingOptions, tx: &WithOtherFields<alloy_rpc_types::Transaction>) -> Self {
         match tracing_options {
@@ -76,6 +94,12 @@ impl TracingResult {
                     .into_parity_builder()
                     .into_localized_transaction_traces(TransactionInfo::from(&tx.inner)),
             ),
+            TracingOptions::ParityReplay(_) | TracingOptions::ParityCall(_) => {
+                Self::ParityReplay(vec![TraceResultsWithTransactionHash {
+                    full_trace: TraceResults { output: Bytes::new(), state_diff: None, trace: vec![], vm_trace: None },
+                    transaction_hash: tx.hash,
+                }])
+            }
         }
     }
 }
@@ -93,7 +117,7 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
     fn trace_geth(
         env: EnvWithHandlerCfg,
         db: &EthCacheDatabase<P>,
-        tx: &WithOtherFields<alloy_rpc_types::Transaction>,
+        tx_hash: Option<B256>,
         opts: GethDebugTracingOptions,
     ) -> TracingStateResult {
         // Extract options
@@ -102,7 +126,7 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
         // Check if tracer is provided
         if let Some(tracer) = tracer {
             match tracer {
-                // Only support CallTracer for now
+                // Call tracer
                 GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer) => {
                     // Convert tracer config to call config
                     let call_config = tracer_config
@@ -111,18 +135,10 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
                         .map_err(|err| EthApiError::Transaction(TransactionError::Tracing(err.into())))?;
 
                     // Initialize tracing inspector with call config
-                    let mut inspector =
-                        TracingInspector::new(TracingInspectorConfig::from_geth_call_config(&call_config));
-
-                    // Build EVM with environment and inspector
-                    let eth_evm_config = EthEvmConfig::new(Arc::new(Default::default()));
+                    let inspector = TracingInspector::new(TracingInspectorConfig::from_geth_call_config(&call_config));
 
-                    let res = {
-                        let mut evm = eth_evm_config.evm_with_env_and_inspector(db.0.clone(), env, &mut inspector);
-
-                        // Execute transaction
-                        evm.transact().map_err(|err| TransactionError::Tracing(err.into()))?
-                    };
+                    // Execute transaction
+                    let (res, inspector) = transact(db.0.clone(), env, inspector)?;
 
                     // Get call traces
                     let call_frame = inspector.into_geth_builder().geth_call_traces(
@@ -130,41 +146,108 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
                         res.result.gas_used(),
                     );
 
+                    // Return success trace result
+                    return Ok((
+                        TracingResult::Geth(vec![TraceResult::Success { result: call_frame.into(), tx_hash }]),
+                        res.state,
+                    ));
+                }
+                // Prestate tracer, with or without diff mode
+                GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::PreStateTracer) => {
+                    // Convert tracer config to prestate config
+                    let prestate_config =
+                        tracer_config.into_pre_state_config().map_err(|err| TransactionError::Tracing(err.into()))?;
+
+                    // Initialize tracing inspector with prestate config
+                    let inspector =
+                        TracingInspector::new(TracingInspectorConfig::from_geth_prestate_config(&prestate_config));
+
+                    // Execute transaction
+                    let (res, inspector) = transact(db.0.clone(), env, inspector)?;
+
+                    // Get the prestate (or the state diff in diff mode), using the state before the execution
+                    let frame = inspector.into_geth_builder().geth_prestate_traces(&res, &prestate_config, &db.0)?;
+
+                    // Return success trace result
+                    return Ok((
+                        TracingResult::Geth(vec![TraceResult::Success { result: frame.into(), tx_hash }]),
+                        res.state,
+                    ));
+                }
+                // Four byte tracer, collecting the selectors and calldata sizes of the calls
+                GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::FourByteTracer) => {
+                    let inspector = FourByteInspector::default();
+
+                    // Execute transaction
+                    let (res, inspector) = transact(db.0.clone(), env, inspector)?;
+
+                    // Return success trace result
+                    let frame = FourByteFrame::from(&inspector);
+                    return Ok((
+                        TracingResult::Geth(vec![TraceResult::Success { result: frame.into(), tx_hash }]),
+                        res.state,
+                    ));
+                }
+                // Noop tracer, the transaction is still executed in order to update the state
+                GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::NoopTracer) => {
+                    let (res, _) = transact(db.0.clone(), env, NoOpInspector)?;
+
                     // Return success trace result
                     return Ok((
                         TracingResult::Geth(vec![TraceResult::Success {
-                            result: call_frame.into(),
-                            tx_hash: Some(tx.hash),
+                            result: NoopFrame::default().into(),
+                            tx_hash,
                         }]),
                         res.state,
                     ));
                 }
+                // Mux tracer, running multiple tracers in a single execution
+                GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::MuxTracer) => {
+                    // Convert tracer config to mux config
+                    let mux_config =
+                        tracer_config.into_mux_config().map_err(|err| TransactionError::Tracing(err.into()))?;
+
+                    // Initialize the mux inspector, which fails on unsupported nested tracers
+                    let inspector = MuxInspector::try_from_config(mux_config)
+                        .map_err(|err| TransactionError::Tracing(err.into()))?;
+
+                    // Keep the transaction info for the nested tracers
+                    let transaction_info = TransactionInfo {
+                        hash: tx_hash,
+                        block_number: Some(env.env.block.number.saturating_to()),
+                        base_fee: Some(env.env.block.basefee.saturating_to()),
+                        ..Default::default()
+                    };
+
+                    // Execute transaction
+                    let (res, inspector) = transact(db.0.clone(), env, inspector)?;
+
+                    // Get the nested traces, using the state before the execution
+                    let frame = inspector.try_into_mux_frame(&res, &db.0, transaction_info)?;
+
+                    // Return success trace result
+                    return Ok((
+                        TracingResult::Geth(vec![TraceResult::Success { result: frame.into(), tx_hash }]),
+                        res.state,
+                    ));
+                }
                 // Return error for unsupported tracers
                 _ => {
                     return Err(EthApiError::Transaction(TransactionError::Tracing(
-                        eyre!("only call tracer is currently supported").into(),
+                        eyre!("only call, prestate, 4byte, noop and mux tracers are currently supported").into(),
                     )))
                 }
             }
         }
 
         // Use default tracer
-        let mut inspector = TracingInspector::new(TracingInspectorConfig::from_geth_config(&config));
-        let eth_evm_config = EthEvmConfig::new(Arc::new(Default::default()));
-
-        let res = {
-            let mut evm = eth_evm_config.evm_with_env_and_inspector(db.0.clone(), env, &mut inspector);
-            // Execute transaction
-            evm.transact().map_err(|err| TransactionError::Tracing(err.into()))?
-        };
+        let inspector = TracingInspector::new(TracingInspectorConfig::from_geth_config(&config));
+        let (res, inspector) = transact(db.0.clone(), env, inspector)?;
 
         let gas_used = res.result.gas_used();
         let return_value = res.result.into_output().unwrap_or_default();
         let frame = inspector.into_geth_builder().geth_traces(gas_used, return_value, config);
-        Ok((
-            TracingResult::Geth(vec![TraceResult::Success { result: frame.into(), tx_hash: Some(tx.hash) }]),
-            res.state,
-        ))
+        Ok((TracingResult::Geth(vec![TraceResult::Success { result: frame.into(), tx_hash }]), res.state))
     }
 
     /// Traces the transaction with Parity tracing options and returns the resulting traces and state.
@@ -183,18 +266,10 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
             .map_err(|err: FromUintError<u128>| TransactionError::Tracing(err.into()))?;
 
         // Initialize tracing inspector with given config
-        let mut inspector = TracingInspector::new(tracing_config);
-
-        // Build EVM with environment and inspector
-        let eth_evm_config = EthEvmConfig::new(Arc::new(Default::default()));
+        let inspector = TracingInspector::new(tracing_config);
 
         // Execute transaction
-        let res = {
-            let mut evm = eth_evm_config.evm_with_env_and_inspector(db.0.clone(), env, &mut inspector);
-
-            // Execute transaction
-            evm.transact().map_err(|err| TransactionError::Tracing(err.into()))?
-        };
+        let (res, inspector) = transact(db.0.clone(), env, inspector)?;
 
         // Create transaction info
         let transaction_info = TransactionInfo::from(&tx.inner).with_base_fee(block_base_fee);
@@ -206,39 +281,164 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
         ))
     }
 
+    /// Replays the transaction with the selected Parity trace types and returns the resulting traces and state.
+    fn trace_parity_replay(
+        env: EnvWithHandlerCfg,
+        db: &EthCacheDatabase<P>,
+        trace_types: &HashSet<TraceType>,
+    ) -> TracerResult<(TraceResults, reth_revm::primitives::EvmState)> {
+        // Initialize tracing inspector with the config required by the trace types
+        let inspector = TracingInspector::new(TracingInspectorConfig::from_parity_config(trace_types));
+
+        // Execute transaction
+        let (res, inspector) = transact(db.0.clone(), env, inspector)?;
+
+        // Build the trace results, using the state before the execution for the state diff
+        let trace_results = inspector.into_parity_builder().into_trace_results_with_state(&res, trace_types, &db.0)?;
+
+        Ok((trace_results, res.state))
+    }
+
     /// Trace the block in the parity format.
     pub fn trace_block(self) -> TracerResult<Option<Vec<LocalizedTransactionTrace>>> {
         let txs = self.transactions.clone();
         Ok(Some(self.trace_transactions(TracingResult::as_parity, &txs)?))
     }
 
+    /// Returns the parity traces of the transaction with the given hash.
+    pub fn trace_transaction(self, transaction_hash: B256) -> TracerResult<Vec<LocalizedTransactionTrace>> {
+        self.trace_transaction_in_block(TracingResult::as_parity, transaction_hash)
+    }
+
+    /// Replays the transactions of the block with the selected Parity trace types.
+    pub fn replay_block_transactions(self) -> TracerResult<Vec<TraceResultsWithTransactionHash>> {
+        let txs = self.transactions.clone();
+        self.trace_transactions(TracingResult::as_parity_replay, &txs)
+    }
+
+    /// Replays the transaction with the given hash with the selected Parity trace types.
+    pub fn replay_transaction(self, transaction_hash: B256) -> TracerResult<TraceResults> {
+        let traces = self.trace_transaction_in_block(TracingResult::as_parity_replay, transaction_hash)?;
+        traces
+            .into_iter()
+            .next()
+            .map(|trace| trace.full_trace)
+            .ok_or_else(|| TransactionError::Tracing(eyre!("No trace found").into()).into())
+    }
+
+    /// Traces the call with the selected Parity trace types.
+    pub fn trace_call(
+        self,
+        request: TransactionRequest,
+        trace_types: HashSet<TraceType>,
+    ) -> TracerResult<TraceResults> {
+        let mut traces = self.trace_call_many(vec![(request, trace_types)])?;
+        traces.pop().ok_or_else(|| TransactionError::Tracing(eyre!("No trace found").into()).into())
+    }
+
+    /// Traces the calls with their selected Parity trace types.
+    ///
+    /// The calls are executed on top of the state of the block, after applying the
+    /// state and block overrides. Each call is executed on top of the state changes
+    /// of the previous calls.
+    pub fn trace_call_many(
+        mut self,
+        calls: Vec<(TransactionRequest, HashSet<TraceType>)>,
+    ) -> TracerResult<Vec<TraceResults>> {
+        // Attempt to get the overrides from the provided tracing options.
+        let EvmOverrides { state, block } = self
+            .tracing_options
+            .as_parity_call()
+            .ok_or_else(|| {
+                // Return an error if the tracing options are not supported.
+                EthApiError::Transaction(TransactionError::Tracing(
+                    eyre!("only `EvmOverrides` tracing options are supported for parity call tracing").into(),
+                ))
+            })?
+            .clone();
+
+        // Apply the state overrides, then the block overrides.
+        if let Some(state) = state {
+            self.db.apply_state_overrides(state)?;
+        }
+        let mut env = self.env;
+        if let Some(block) = block {
+            apply_block_overrides(*block, &mut self.db, &mut env.env.block);
+        }
+        env.env.cfg = call_cfg_env(env.env.cfg.chain_id);
+
+        let mut traces = Vec::with_capacity(calls.len());
+        let mut calls = calls.into_iter().peekable();
+        while let Some((request, trace_types)) = calls.next() {
+            let gas_limit = request.gas.unwrap_or(CALL_REQUEST_GAS_LIMIT);
+            let mut env = env.clone();
+            env.env.tx = tx_env_from_request(request, gas_limit);
+
+            let (trace, state_changes) = Self::trace_parity_replay(env, &self.db, &trace_types)?;
+            traces.push(trace);
+
+            // Only commit to the database if there are more calls to process.
+            if calls.peek().is_some() {
+                self.db.0.commit(state_changes);
+            }
+        }
+
+        Ok(traces)
+    }
+
     /// Returns the debug trace in the Geth.
-    /// Currently only supports the call tracer or the default tracer.
+    /// Currently supports the call, prestate, 4byte, noop and mux tracers, as well as the default tracer.
     pub fn debug_block(self) -> TracerResult<Vec<TraceResult>> {
         let txs = self.transactions.clone();
         self.trace_transactions(TracingResult::as_geth, &txs)
     }
 
-    pub fn debug_transaction(mut self, transaction_hash: B256) -> TracerResult<GethTrace> {
+    pub fn debug_transaction(self, transaction_hash: B256) -> TracerResult<GethTrace> {
+        into_geth_trace(self.trace_transaction_in_block(TracingResult::as_geth, transaction_hash)?)
+    }
+
+    /// Traces the transaction with the given hash, after executing the
+    /// previous transactions of the block without tracing them.
+    fn trace_transaction_in_block<T: Clone>(
+        mut self,
+        convert_result: fn(&TracingResult) -> Option<&Vec<T>>,
+        transaction_hash: B256,
+    ) -> TracerResult<Vec<T>> {
+        let tx = self.execute_until(transaction_hash)?;
+        // We only want to trace the transaction with the given hash.
+        self.trace_transactions(convert_result, &[tx])
+    }
+
+    /// Executes the transaction with the given hash with the provided inspector, after executing
+    /// the previous transactions of the block without inspecting them.
+    ///
+    /// Returns the inspector along with the result of the execution.
+    pub fn trace_transaction_with_inspector<I>(
+        mut self,
+        transaction_hash: B256,
+        inspector: I,
+    ) -> TracerResult<(I, ExecutionResult)>
+    where
+        I: Inspector<CacheDB<EthDatabase<P>>>,
+    {
+        let tx = self.execute_until(transaction_hash)?;
+        let env = env_with_tx(&self.env, &tx)?;
+        let (res, inspector) = transact(self.db.0.clone(), env, inspector)?;
+
+        Ok((inspector, res.result))
+    }
+
+    /// Executes and commits the transactions of the block preceding the transaction with the
+    /// given hash, and returns that transaction.
+    fn execute_until(&mut self, transaction_hash: B256) -> TracerResult<WithOtherFields<alloy_rpc_types::Transaction>> {
         for tx in self.transactions.clone() {
             if tx.hash == transaction_hash {
-                // We only want to trace the transaction with the given hash.
-                let trace = self
-                    .trace_transactions(TracingResult::as_geth, &[tx])?
-                    .first()
-                    .cloned()
-                    .ok_or(TransactionError::Tracing(eyre!("No trace found").into()))?;
-                return match trace {
-                    TraceResult::Success { result, .. } => Ok(result),
-                    TraceResult::Error { error, .. } => Err(TransactionError::Tracing(error.into()).into()),
-                };
+                return Ok(tx);
             }
 
             let env = env_with_tx(&self.env, &tx)?;
-            let eth_evm_config = EthEvmConfig::new(Arc::new(Default::default()));
-
-            let mut evm = eth_evm_config.evm_with_env(&mut self.db.0, env);
-            evm.transact_commit().map_err(|err| TransactionError::Tracing(err.into()))?;
+            let (res, _) = transact(&mut self.db.0, env, NoOpInspector)?;
+            self.db.0.commit(res.state);
         }
 
         Err(EthApiError::TransactionNotFound(transaction_hash))
@@ -246,9 +446,10 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
 
     /// Debugs a transaction request by tracing it using the provided tracing options.
     ///
-    /// This function returns an error if the tracing options are not supported or if there is an issue
-    /// with the EVM environment or transaction execution.
-    pub fn debug_transaction_request(self, _request: &TransactionRequest) -> TracerResult<GethTrace> {
+    /// The request is executed on top of the state of the block, after applying the
+    /// state and block overrides. If no tracer is provided, the default struct logs
+    /// trace is returned.
+    pub fn debug_transaction_request(mut self, request: &TransactionRequest) -> TracerResult<GethTrace> {
         // Attempt to get Geth tracing options from the provided tracing options.
         let opts = self
             .tracing_options
@@ -260,68 +461,23 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
                 ))
             })?
             .clone();
+        let GethDebugTracingCallOptions { tracing_options, state_overrides, block_overrides } = opts;
 
-        // Extract the tracing options from the obtained Geth tracing options.
-        let GethDebugTracingCallOptions { tracing_options, .. } = opts;
-        let GethDebugTracingOptions { tracer, tracer_config, .. } = tracing_options;
-
-        // Check if a tracer is provided.
-        if let Some(tracer) = tracer {
-            match tracer {
-                // Only support CallTracer for now.
-                GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer) => {
-                    // Build the EVM environment using the provided configuration and request.
-
-                    // TODO: build_call_evm_env not available anymore, to be discussed
-                    // let env = build_call_evm_env(
-                    //     CfgEnvWithHandlerCfg { cfg_env: self.env.cfg.clone(), handler_cfg: self.env.handler_cfg },
-                    //     self.env.block.clone(),
-                    //     request.clone(),
-                    // )?;
-
-                    // Convert the tracer configuration into call configuration.
-                    let call_config =
-                        tracer_config.into_call_config().map_err(|err| TransactionError::Tracing(err.into()))?;
-
-                    // Create a new tracing inspector with the call configuration.
-                    let mut inspector =
-                        TracingInspector::new(TracingInspectorConfig::from_geth_call_config(&call_config));
-
-                    // Build EVM with environment and inspector.
-                    let eth_evm_config = EthEvmConfig::new(Arc::new(Default::default()));
-                    // TODO: we should not use default here to be discussed
-                    let gas_used = {
-                        let mut evm = eth_evm_config.evm_with_env_and_inspector(
-                            self.db.0,
-                            EnvWithHandlerCfg::default(),
-                            &mut inspector,
-                        );
-
-                        // Execute the transaction.
-                        let res = evm.transact().map_err(|err| TransactionError::Tracing(err.into()))?;
-
-                        // Capture the gas used before `evm` goes out of scope.
-                        res.result.gas_used()
-                    };
-
-                    // Get the call traces from the inspector.
-                    let frame = inspector.into_geth_builder().geth_call_traces(call_config, gas_used);
-
-                    // Return the obtained call traces.
-                    return Ok(frame.into());
-                }
-
-                // Return an error for unsupported tracers.
-                _ => {
-                    return Err(EthApiError::Transaction(TransactionError::Tracing(
-                        eyre!("only call tracer is currently supported").into(),
-                    )))
-                }
-            }
+        // Apply the state overrides, then the block overrides.
+        if let Some(state_overrides) = state_overrides {
+            self.db.apply_state_overrides(state_overrides)?;
         }
+        let mut env = self.env;
+        if let Some(block_overrides) = block_overrides {
+            apply_block_overrides(block_overrides, &mut self.db, &mut env.env.block);
+        }
+
+        // Build the call environment from the request.
+        env.env.cfg = call_cfg_env(env.env.cfg.chain_id);
+        env.env.tx = tx_env_from_request(request.clone(), request.gas.unwrap_or(CALL_REQUEST_GAS_LIMIT));
 
-        // Return a default Geth trace if no tracer is provided.
-        Ok(GethTrace::Default(Default::default()))
+        let (res, _) = Self::trace_geth(env, &self.db, None, tracing_options)?;
+        into_geth_trace(res.as_geth().cloned().unwrap_or_default())
     }
 
     /// Traces the provided transactions using the given closure.
@@ -343,13 +499,24 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
                 (TracingResult::default_failure(&self.tracing_options, tx), HashMap::default())
             } else {
                 match &self.tracing_options {
-                    TracingOptions::Geth(opts) => Self::trace_geth(env, &db, tx, opts.clone())?,
+                    TracingOptions::Geth(opts) => Self::trace_geth(env, &db, Some(tx.hash), opts.clone())?,
                     TracingOptions::Parity(tracing_config) => Self::trace_parity(env, &db, tx, *tracing_config)?,
+                    TracingOptions::ParityReplay(trace_types) => {
+                        let (full_trace, state) = Self::trace_parity_replay(env, &db, trace_types)?;
+                        let trace = TraceResultsWithTransactionHash { full_trace, transaction_hash: tx.hash };
+                        (TracingResult::ParityReplay(vec![trace]), state)
+                    }
                     TracingOptions::GethCall(_) => {
                         return Err(EthApiError::Transaction(TransactionError::Tracing(
                             eyre!("`TracingOptions::GethCall` is not supported in `trace_transactions` context").into(),
                         )))
                     }
+                    TracingOptions::ParityCall(_) => {
+                        return Err(EthApiError::Transaction(TransactionError::Tracing(
+                            eyre!("`TracingOptions::ParityCall` is not supported in `trace_transactions` context")
+                                .into(),
+                        )))
+                    }
                 }
             };
 
@@ -367,6 +534,33 @@ impl<P: EthereumProvider + Send + Sync + Clone> Tracer<P> {
     }
 }
 
+/// Returns the first trace of the provided traces as a [`GethTrace`].
+fn into_geth_trace(traces: Vec<TraceResult>) -> TracerResult<GethTrace> {
+    let trace = traces.into_iter().next().ok_or(TransactionError::Tracing(eyre!("No trace found").into()))?;
+    match trace {
+        TraceResult::Success { result, .. } => Ok(result),
+        TraceResult::Error { error, .. } => Err(TransactionError::Tracing(error.into()).into()),
+    }
+}
+
+/// Executes the transaction of the environment on top of the database with the inspector, without
+/// committing the state changes.
+///
+/// Returns the result of the execution along with the inspector.
+fn transact<DB, I>(db: DB, env: EnvWithHandlerCfg, mut inspector: I) -> TracerResult<(ResultAndState, I)>
+where
+    DB: Database,
+    DB::Error: std::error::Error + Send + Sync + 'static,
+    I: Inspector<DB>,
+{
+    let eth_evm_config = EthEvmConfig::new(Arc::new(Default::default()));
+    let res = eth_evm_config
+        .evm_with_env_and_inspector(db, env, &mut inspector)
+        .transact()
+        .map_err(|err| TransactionError::Tracing(err.into()))?;
+    Ok((res, inspector))
+}
+
 /// Returns the environment with the transaction env updated to the given transaction.
 fn env_with_tx(
     env: &EnvWithHandlerCfg,
@@ -385,28 +579,31 @@ fn env_with_tx(
 mod tests {
     use super::*;
     use crate::providers::{
-        eth_provider::{database::Database, provider::EthDataProvider},
+        eth_provider::{database::Database, provider::EthDataProvider, starknet::kakarot_core::KakarotDeployment},
         sn_provider::StarknetProvider,
     };
     use builder::TracerBuilder;
     use mongodb::options::{DatabaseOptions, ReadConcern, WriteConcern};
-    use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient};
+    use starknet::{
+        core::types::Felt,
+        providers::{jsonrpc::HttpTransport, JsonRpcClient, Provider},
+    };
     use std::{str::FromStr, sync::Arc};
     use url::Url;
 
     #[tokio::test(flavor = "multi_thread")]
     #[ignore = "this test is used for debugging purposes only"]
     async fn test_debug_tracing() {
-        // Set the env vars
-        std::env::set_var("KAKAROT_ADDRESS", "CHECK THE KAKAROT ADDRESS FOR THE BLOCK YOU ARE DEBUGGING");
-        std::env::set_var(
-            "UNINITIALIZED_ACCOUNT_CLASS_HASH",
-            "CHECK THE KAKAROT UNINITIALIZED ACCOUNT CLASS HASH FOR THE BLOCK YOU ARE DEBUGGING",
-        );
-
         // Given
         let url = Url::parse("https://juno-kakarot-dev.karnot.xyz/").unwrap();
-        let starknet_provider = JsonRpcClient::new(HttpTransport::new(url));
+        let starknet_provider = JsonRpcClient::new(HttpTransport::new(url.clone()));
+        let deployment = KakarotDeployment::new(
+            url,
+            Felt::from_hex("CHECK THE KAKAROT ADDRESS FOR THE BLOCK YOU ARE DEBUGGING").unwrap(),
+            Felt::from_hex("CHECK THE KAKAROT UNINITIALIZED ACCOUNT CLASS HASH FOR THE BLOCK YOU ARE DEBUGGING")
+                .unwrap(),
+            starknet_provider.chain_id().await.unwrap(),
+        );
 
         // Start a local mongodb instance with the state of the network:
         // - Install `mongod`.
@@ -429,7 +626,12 @@ mod tests {
             ),
         );
 
-        let eth_provider = Arc::new(EthDataProvider::new(db, StarknetProvider::new(Arc::new(starknet_provider))));
+        let eth_provider = Arc::new(EthDataProvider::new(
+            db,
+            StarknetProvider::new(Arc::new(starknet_provider)),
+            deployment,
+            Default::default(),
+        ));
         let tracer = TracerBuilder::new(eth_provider)
             .await
             .unwrap()
@@ -8,25 +8,32 @@ use alloy_rpc_types::{
     request::TransactionInput,
     serde_helpers::JsonStorageKey,
     state::{AccountOverride, StateOverride},
-    Filter, FilterBlockOption, FilterChanges, Log, RpcBlockHash, Topic, TransactionRequest,
+    AccessList, AccessListItem, BlockOverrides, Filter, FilterBlockOption, FilterChanges, Log, RpcBlockHash, Topic,
+    TransactionRequest,
 };
 use alloy_sol_types::{sol, SolCall};
 use arbitrary::Arbitrary;
 use kakarot_rpc::{
-    client::{KakarotTransactions, TransactionHashProvider},
+    client::{EthClient, KakarotTransactions, TransactionHashProvider},
+    config::{LimitsConfig, SpamProtectionConfig},
     into_via_try_wrapper,
     models::felt::Felt252Wrapper,
-    providers::eth_provider::{
-        constant::{MAX_LOGS, STARKNET_MODULUS},
-        database::{
-            ethereum::EthereumTransactionStore,
-            filter,
-            filter::EthDatabaseFilterBuilder,
-            types::transaction::{EthStarknetHashes, StoredEthStarknetTransactionHash, StoredTransaction},
+    providers::{
+        eth_provider::{
+            constant::STARKNET_MODULUS,
+            database::{
+                ethereum::EthereumTransactionStore,
+                filter,
+                filter::EthDatabaseFilterBuilder,
+                types::transaction::{EthStarknetHashes, StoredEthStarknetTransactionHash, StoredTransaction},
+            },
+            error::{EthApiError, TransactionError},
+            provider::{EthDataProvider, EthereumProvider},
+            starknet::relayer::Relayer,
+            BlockProvider, ChainProvider, GasProvider, LogProvider, ReceiptProvider, StateProvider,
+            TransactionProvider,
         },
-        provider::EthereumProvider,
-        starknet::relayer::Relayer,
-        BlockProvider, ChainProvider, GasProvider, LogProvider, ReceiptProvider, StateProvider, TransactionProvider,
+        sn_provider::StarknetProvider,
     },
     test_utils::{
         eoa::Eoa,
@@ -38,7 +45,8 @@ use kakarot_rpc::{
 };
 use rand::Rng;
 use reth_primitives::{sign_message, Transaction, TransactionSigned};
-use reth_transaction_pool::{TransactionOrigin, TransactionPool};
+use reth_rpc_eth_types::EthApiError as RethEthApiError;
+use reth_transaction_pool::{PoolConfig, TransactionOrigin, TransactionPool};
 use rstest::*;
 use starknet::{
     accounts::Account,
@@ -324,19 +332,24 @@ async fn filter_logs(filter: Filter, provider: Arc<dyn EthereumProvider>) -> Vec
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
 async fn test_get_logs_limit(#[future] katana: Katana, _setup: ()) {
-    // Get the Ethereum provider from Katana.
-    let provider = katana.eth_provider();
-
     // Set the limit of logs to be retrieved.
-    std::env::set_var("MAX_LOGS", "500");
+    let max_logs = 500;
+
+    // Get an Ethereum provider of the Katana instance limited to max_logs logs.
+    let provider = Arc::new(EthDataProvider::new(
+        katana.eth_provider().database().clone(),
+        StarknetProvider::new(katana.starknet_provider()),
+        katana.eth_provider().deployment().clone(),
+        LimitsConfig { max_logs: Some(max_logs), ..Default::default() },
+    ));
 
     // Add mock logs to the Katana instance's database.
-    // The number of logs added is MAX_LOGS + 20, ensuring there are more logs than the limit.
-    katana.add_mock_logs(((*MAX_LOGS).unwrap() + 20) as usize).await;
+    // The number of logs added is max_logs + 20, ensuring there are more logs than the limit.
+    katana.add_mock_logs((max_logs + 20) as usize).await;
 
     // Assert that the number of logs returned by filter_logs is equal to the limit.
-    // This ensures that the log retrieval respects the MAX_LOGS constraint.
-    assert_eq!(filter_logs(Filter::default(), provider.clone()).await.len(), (*MAX_LOGS).unwrap() as usize);
+    // This ensures that the log retrieval respects the max_logs constraint.
+    assert_eq!(filter_logs(Filter::default(), provider).await.len(), max_logs as usize);
 }
 
 #[rstest]
@@ -573,6 +586,29 @@ async fn test_fee_history(#[future] katana: Katana, _setup: ()) {
     assert_eq!(fee_history.oldest_block, 0);
 }
 
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fee_history_reward_percentiles(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let eth_provider = katana.eth_provider();
+    let newest_block = katana.block_number();
+    let nbr_blocks = katana.headers.len();
+    let percentiles = vec![25., 50., 75.];
+
+    // When
+    let fee_history =
+        eth_provider.fee_history(U64::from(u64::MAX), newest_block.into(), Some(percentiles.clone())).await.unwrap();
+
+    // Then
+    let reward = fee_history.reward.expect("Missing rewards");
+    assert_eq!(reward.len(), nbr_blocks);
+    assert!(reward.iter().all(|block_reward| block_reward.len() == percentiles.len()));
+
+    // Non monotonic percentiles are rejected.
+    assert!(eth_provider.fee_history(U64::from(1), newest_block.into(), Some(vec![50., 25.])).await.is_err());
+}
+
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
@@ -593,11 +629,13 @@ async fn test_predeploy_eoa(#[future] katana: Katana, _setup: ()) {
         b256!("00000000000000012330000000000000000000000000000000000000000abde1"),
         Arc::new(eth_client.clone()),
         katana.sequencer.account(),
+        katana.eoa.relayer_signer.clone(),
     );
     let other_eoa_2 = KakarotEOA::new(
         b256!("00000000000000123123456000000000000000000000000000000000000abde2"),
         Arc::new(eth_client),
         katana.sequencer.account(),
+        katana.eoa.relayer_signer.clone(),
     );
 
     let evm_address = eoa.evm_address().unwrap();
@@ -756,6 +794,8 @@ async fn test_send_raw_transaction(#[future] katana_empty: Katana, _setup: ()) {
         katana.eoa.relayer.address(),
         relayer_balance,
         &(*(*eth_client.starknet_provider())),
+        katana.eoa.relayer_signer.clone().into(),
+        eth_client.eth_provider().deployment().clone(),
         Some(Arc::new(eth_client.eth_provider().database().clone())),
     )
     .relay_transaction(&transaction_signed)
@@ -851,6 +891,27 @@ async fn test_send_raw_transaction_wrong_nonce(#[future] katana_empty: Katana, _
     assert_eq!(mempool_size_after_wrong_send.total, 0);
 }
 
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_send_raw_transaction_without_funded_relayer(#[future] katana_empty: Katana, _setup: ()) {
+    // Given
+    let eth_client = katana_empty.eth_client();
+    let (_, transaction_signed) = create_sample_transactions(&katana_empty, 1)
+        .await
+        .expect("Failed to create sample transaction")
+        .pop()
+        .expect("Expected at least one transaction");
+    eth_client.relayer_funds().set_funded(false);
+
+    // When
+    let res = eth_client.send_raw_transaction(transaction_signed.encoded_2718().into()).await;
+
+    // Then
+    assert!(matches!(res, Err(EthApiError::Transaction(TransactionError::NoFundedRelayer))));
+    assert_eq!(eth_client.mempool().pool_size().total, 0);
+}
+
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
@@ -981,7 +1042,6 @@ async fn test_send_raw_transaction_pre_eip_155(#[future] katana_empty: Katana, _
     // Given
     let katana = katana_empty;
     let eth_provider = katana.eth_provider();
-    let eth_client = katana.eth_client();
     let nonce: u64 = katana.eoa().nonce().await.unwrap().try_into().expect("Failed to convert nonce");
 
     // Use the transaction for the Arachnid deployer
@@ -992,11 +1052,19 @@ async fn test_send_raw_transaction_pre_eip_155(#[future] katana_empty: Katana, _
     let signature = sign_message(katana.eoa().private_key(), transaction.signature_hash()).unwrap();
     let transaction_signed = TransactionSigned::from_transaction_and_signature(transaction, signature);
 
-    // Set the WHITE_LISTED_EIP_155_TRANSACTION_HASHES env var to the hash
-    // and add a blank space and an unknown hash to test the env var
-    let hash = transaction_signed.hash();
-    let random_hash = B256::random();
-    std::env::set_var("WHITE_LISTED_EIP_155_TRANSACTION_HASHES", format!("{hash}, {random_hash}"));
+    // Whitelist the hash of the transaction, along with an unknown hash
+    let limits = LimitsConfig {
+        white_listed_eip_155_transaction_hashes: vec![transaction_signed.hash(), B256::random()],
+        ..eth_provider.limits().clone()
+    };
+    let eth_client = EthClient::new(
+        katana.starknet_provider(),
+        eth_provider.deployment().clone(),
+        limits,
+        PoolConfig::default(),
+        SpamProtectionConfig::default(),
+        eth_provider.database().clone(),
+    );
 
     let mempool_size = eth_client.mempool().pool_size();
     // Assert that the number of pending and total transactions in the mempool is 0
@@ -1028,6 +1096,8 @@ async fn test_send_raw_transaction_pre_eip_155(#[future] katana_empty: Katana, _
         katana.eoa.relayer.address(),
         relayer_balance,
         &(*(*katana.eth_client.starknet_provider())),
+        katana.eoa.relayer_signer.clone().into(),
+        katana.eth_client.eth_provider().deployment().clone(),
         Some(Arc::new(katana.eth_client.eth_provider().database().clone())),
     )
     .relay_transaction(&transaction_signed)
@@ -1231,7 +1301,6 @@ async fn test_call_with_state_override_balance_success(#[future] katana: Katana,
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
-#[ignore = "fails because of reth tracing utils temporarily removed in PR 1389"]
 async fn test_call_with_state_override_balance_failure(#[future] katana: Katana, _setup: ()) {
     // Obtain an Ethereum provider instance from the Katana instance
     let eth_provider = katana.eth_provider();
@@ -1262,7 +1331,145 @@ async fn test_call_with_state_override_balance_failure(#[future] katana: Katana,
     let err = res.unwrap_err().to_string();
 
     // Check if the error is due to insufficient funds
-    assert_eq!(err, "tracing error: transaction validation error: lack of funds (1000000000) for max fee (1000210001)");
+    assert_eq!(err, "call error: transaction validation error: lack of funds (1000000000) for max fee (1000210001)");
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_with_state_override_storage(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let eth_provider = katana.eth_provider();
+    let contract_address = address!("1000000000000000000000000000000000000042");
+    // Returns the values of the storage slots 0 and 1.
+    let code = bytes!("60005460005260015460205260406000f3");
+    let request = TransactionRequest { to: Some(TxKind::Call(contract_address)), ..Default::default() };
+    let slot_override = |slots: &[(u8, u8)]| -> Option<_> {
+        Some(slots.iter().map(|(slot, value)| (B256::with_last_byte(*slot), B256::with_last_byte(*value))).collect())
+    };
+    let storage_override = |account_override: AccountOverride| {
+        let mut state_override = StateOverride::default();
+        state_override.insert(contract_address, AccountOverride { code: Some(code.clone()), ..account_override });
+        state_override
+    };
+
+    // When
+    // The whole storage is replaced, so the slot 0 is empty.
+    let state = storage_override(AccountOverride { state: slot_override(&[(1, 0x11)]), ..Default::default() });
+    let state_result =
+        eth_provider.call(request.clone(), None, Some(state), None).await.expect("Failed to call with state");
+    // The storage is patched slot by slot.
+    let state_diff =
+        storage_override(AccountOverride { state_diff: slot_override(&[(0, 0x22), (1, 0x11)]), ..Default::default() });
+    let state_diff_result =
+        eth_provider.call(request.clone(), None, Some(state_diff), None).await.expect("Failed to call with stateDiff");
+    let both = storage_override(AccountOverride {
+        state: slot_override(&[(1, 0x11)]),
+        state_diff: slot_override(&[(1, 0x11)]),
+        ..Default::default()
+    });
+    let both_result = eth_provider.call(request, None, Some(both), None).await;
+
+    // Then
+    assert_eq!(state_result, Bytes::from([B256::ZERO.0, B256::with_last_byte(0x11).0].concat()));
+    assert_eq!(state_diff_result, Bytes::from([B256::with_last_byte(0x22).0, B256::with_last_byte(0x11).0].concat()));
+    let Err(EthApiError::RethEthApi(RethEthApiError::BothStateAndStateDiffInOverride(address))) = both_result else {
+        panic!("Expected a state and stateDiff error, got {both_result:?}")
+    };
+    assert_eq!(address, contract_address);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_with_state_override_nonce(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let eth_provider = katana.eth_provider();
+    let contract_address = address!("1000000000000000000000000000000000000042");
+    // Creates an empty contract and returns its address, which is derived from the nonce of the creator.
+    let code = bytes!("60008080f060005260206000f3");
+    let mut state_override = StateOverride::default();
+    state_override
+        .insert(contract_address, AccountOverride { code: Some(code), nonce: Some(42), ..Default::default() });
+    let request = TransactionRequest { to: Some(TxKind::Call(contract_address)), ..Default::default() };
+
+    // When
+    let result = eth_provider.call(request, None, Some(state_override), None).await.expect("Failed to call");
+
+    // Then
+    assert_eq!(result, Bytes::from(B256::left_padding_from(contract_address.create(42).as_slice()).to_vec()));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_with_block_overrides(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let eth_provider = katana.eth_provider();
+    let contract_address = address!("1000000000000000000000000000000000000042");
+    // Returns the NUMBER, TIMESTAMP, BASEFEE and BLOCKHASH(999) of the block.
+    let code = bytes!("4360005242602052486040526103e74060605260806000f3");
+    let mut state_override = StateOverride::default();
+    state_override.insert(contract_address, AccountOverride { code: Some(code), ..Default::default() });
+    let block_hash = B256::random();
+    let block_overrides = BlockOverrides {
+        number: Some(U256::from(1000)),
+        time: Some(1234),
+        base_fee: Some(U256::from(7)),
+        block_hash: Some([(999, block_hash)].into_iter().collect()),
+        ..Default::default()
+    };
+    let request = TransactionRequest { to: Some(TxKind::Call(contract_address)), ..Default::default() };
+
+    // When
+    let result = eth_provider
+        .call(request, None, Some(state_override), Some(Box::new(block_overrides)))
+        .await
+        .expect("Failed to call");
+
+    // Then
+    let expected = [
+        U256::from(1000).to_be_bytes::<32>(),
+        U256::from(1234).to_be_bytes::<32>(),
+        U256::from(7).to_be_bytes::<32>(),
+        block_hash.0,
+    ]
+    .concat();
+    assert_eq!(result, Bytes::from(expected));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_access_list(#[future] counter: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = counter.0;
+    let counter = counter.1;
+    let eth_provider = katana.eth_provider();
+    let eoa_address = katana.eoa().evm_address().expect("Failed to get eoa address");
+    let counter_address: Felt252Wrapper = counter.evm_address.into();
+    let counter_address = counter_address.try_into().expect("Failed to convert EVM address");
+
+    // Selector of the `inc()` function of the counter contract
+    let request = TransactionRequest {
+        from: Some(eoa_address),
+        to: Some(TxKind::Call(counter_address)),
+        input: TransactionInput { input: Some(bytes!("371303c0")), data: None },
+        ..Default::default()
+    };
+
+    // When
+    let result = eth_provider.create_access_list(request, None).await.expect("Failed to create access list");
+
+    // Then
+    // The counter is stored at slot 0 of the called contract. Only the addresses of the sender, the
+    // recipient and the precompiles are excluded from the list, not the storage slots they access.
+    assert!(result.error.is_none());
+    assert_eq!(
+        result.access_list,
+        AccessList(vec![AccessListItem { address: counter_address, storage_keys: vec![B256::ZERO] }])
+    );
+    assert!(result.gas_used > U256::from(21000));
 }
 
 #[rstest]
@@ -1401,6 +1608,8 @@ async fn test_transaction_by_hash(#[future] katana_empty: Katana, _setup: ()) {
         katana_empty.eoa.relayer.address(),
         relayer_balance,
         &(*(*katana_empty.eth_client.starknet_provider())),
+        katana_empty.eoa.relayer_signer.clone().into(),
+        katana_empty.eth_client.eth_provider().deployment().clone(),
         Some(Arc::new(katana_empty.eth_client.eth_provider().database().clone())),
     )
     .relay_transaction(&transaction_signed)
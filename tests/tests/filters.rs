@@ -0,0 +1,128 @@
+#![allow(clippy::used_underscore_binding)]
+#![cfg(feature = "testing")]
+
+use crate::tests::{
+    mempool::create_sample_transactions,
+    pubsub_api::{add_block, log, next_header},
+};
+use alloy_primitives::Address;
+use alloy_rpc_types::{Filter, FilterChanges};
+use kakarot_rpc::{
+    eth_rpc::filters::EthFilters,
+    providers::eth_provider::error::EthApiError,
+    test_utils::{
+        fixtures::{katana, katana_empty, setup},
+        katana::Katana,
+    },
+};
+use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
+use rstest::*;
+use std::{sync::Arc, time::Duration};
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_log_filter_changes(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let filters = EthFilters::new(Arc::new(katana.eth_client()));
+    let address = Address::with_last_byte(0x42);
+    let id = filters.new_filter(Filter::new().address(address)).await.expect("Failed to install log filter");
+
+    // When
+    let initial_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    // The new block holds a log of the filtered address and a log of another address.
+    let header = next_header(&katana).await;
+    let expected = log(address, &header, 0);
+    katana.add_logs_to_database(vec![expected.clone(), log(Address::with_last_byte(0x43), &header, 1)]).await;
+    katana.add_transactions_with_header_to_database(vec![], header).await;
+    let changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    let next_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+
+    // Then
+    // The logs of the blocks before the installation of the filter are not returned.
+    assert!(matches!(initial_changes, FilterChanges::Empty));
+    let FilterChanges::Logs(logs) = changes else { panic!("Expected logs, got {changes:?}") };
+    assert_eq!(logs, vec![expected]);
+    // The logs are only returned once.
+    assert!(matches!(next_changes, FilterChanges::Empty));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_filter_changes(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let filters = EthFilters::new(Arc::new(katana.eth_client()));
+    let id = filters.new_block_filter().await.expect("Failed to install block filter");
+
+    // When
+    let initial_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    let first_header = add_block(&katana).await;
+    let first_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    let second_header = add_block(&katana).await;
+    let second_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    let next_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+
+    // Then
+    assert!(matches!(initial_changes, FilterChanges::Empty));
+    let FilterChanges::Hashes(first_hashes) = first_changes else { panic!("Expected hashes, got {first_changes:?}") };
+    assert_eq!(first_hashes, vec![first_header.hash]);
+    // Only the blocks added since the last poll are returned.
+    let FilterChanges::Hashes(second_hashes) = second_changes else {
+        panic!("Expected hashes, got {second_changes:?}")
+    };
+    assert_eq!(second_hashes, vec![second_header.hash]);
+    assert!(matches!(next_changes, FilterChanges::Empty));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pending_transaction_filter_changes(#[future] katana_empty: Katana, _setup: ()) {
+    // Given
+    let katana = katana_empty;
+    let eth_client = Arc::new(katana.eth_client());
+    let filters = EthFilters::new(eth_client.clone());
+    let id = filters.new_pending_transaction_filter().await;
+    let mut transactions = create_sample_transactions(&katana, 2).await.expect("Failed to create sample transactions");
+    let (second, _) = transactions.pop().expect("Missing sample transaction");
+    let (first, _) = transactions.pop().expect("Missing sample transaction");
+
+    // When
+    eth_client.mempool().add_transaction(TransactionOrigin::Local, first.clone()).await.expect("Failed to add");
+    let first_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    let empty_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+    eth_client.mempool().add_transaction(TransactionOrigin::Local, second.clone()).await.expect("Failed to add");
+    let second_changes = filters.filter_changes(id).await.expect("Failed to poll filter");
+
+    // Then
+    let FilterChanges::Hashes(first_hashes) = first_changes else { panic!("Expected hashes, got {first_changes:?}") };
+    assert_eq!(first_hashes, vec![*first.hash()]);
+    // The hashes are only returned once.
+    let FilterChanges::Hashes(empty_hashes) = empty_changes else { panic!("Expected hashes, got {empty_changes:?}") };
+    assert!(empty_hashes.is_empty());
+    let FilterChanges::Hashes(second_hashes) = second_changes else {
+        panic!("Expected hashes, got {second_changes:?}")
+    };
+    assert_eq!(second_hashes, vec![*second.hash()]);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_evicted_filter_not_found(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let idle_timeout = Duration::from_millis(200);
+    let filters = EthFilters::with_idle_timeout(Arc::new(katana.eth_client()), idle_timeout);
+    let id = filters.new_block_filter().await.expect("Failed to install block filter");
+    filters.filter_changes(id).await.expect("Failed to poll filter");
+
+    // When
+    // The idle filters are evicted every half of the idle timeout.
+    tokio::time::sleep(idle_timeout * 2).await;
+    let changes = filters.filter_changes(id).await;
+
+    // Then
+    assert!(matches!(changes, Err(EthApiError::FilterNotFound(filter_id)) if filter_id == id));
+    assert!(!filters.uninstall_filter(id).await);
+}
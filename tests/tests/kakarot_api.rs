@@ -3,13 +3,24 @@
 
 use alloy_primitives::B256;
 use kakarot_rpc::{
-    providers::eth_provider::constant::Constant,
+    client::EthClient,
+    config::{LimitsConfig, SpamProtectionConfig},
+    eth_rpc::{config::RPCConfig, rpc::KakarotRpcModuleBuilder, run_server},
+    prometheus_handler::Registry,
+    providers::eth_provider::{
+        constant::Constant,
+        database::{
+            ethereum::EthereumTransactionStore,
+            types::transaction::{RelayStatus, RelayedTransactionStatus},
+        },
+    },
     test_utils::{
         fixtures::{katana, setup},
         katana::Katana,
         rpc::{start_kakarot_rpc_server, RawRpcParamsBuilder},
     },
 };
+use reth_transaction_pool::PoolConfig;
 use rstest::*;
 use serde_json::Value;
 use starknet::core::types::Felt;
@@ -65,30 +76,39 @@ async fn test_send_raw_transaction_forwarding(#[future] katana: Katana, _setup:
 #[tokio::test(flavor = "multi_thread")]
 async fn test_kakarot_get_config(#[future] katana: Katana, _setup: ()) {
     // Define variables
-    let starknet_network = "http://0.0.0.0:1010/";
-    let white_listed_eip_155_transaction_hashes = "0xe65425dacfc1423823cb4766aa0192ffde61eaa9bf81af9fe15149a89ef36c28";
-    let max_logs = 10000;
-    let max_felts_in_calldata = 22500;
-
-    // Set environment variables for the test
-    std::env::set_var("STARKNET_NETWORK", starknet_network);
-    std::env::set_var("WHITE_LISTED_EIP_155_TRANSACTION_HASHES", white_listed_eip_155_transaction_hashes);
-    std::env::set_var("MAX_LOGS", max_logs.to_string());
-    std::env::set_var("MAX_FELTS_IN_CALLDATA", max_felts_in_calldata.to_string());
-    std::env::set_var("KAKAROT_ADDRESS", "0x03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb");
-
-    // Hardcoded expected values
+    let deployment = katana.eth_provider().deployment().clone();
+    let limits = LimitsConfig {
+        max_logs: Some(10000),
+        max_felts_in_calldata: Some(22500),
+        white_listed_eip_155_transaction_hashes: vec![B256::from_str(
+            "0xe65425dacfc1423823cb4766aa0192ffde61eaa9bf81af9fe15149a89ef36c28",
+        )
+        .unwrap()],
+    };
+
+    // Expected values
     let expected_constant = Constant {
-        max_logs: Some(max_logs),
-        starknet_network: (starknet_network).to_string(),
-        max_felts_in_calldata,
-        white_listed_eip_155_transaction_hashes: vec![B256::from_str(white_listed_eip_155_transaction_hashes).unwrap()],
-        kakarot_address: Felt::from_hex("0x03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb").unwrap(),
+        max_logs: limits.max_logs,
+        starknet_network: deployment.network_url.to_string(),
+        max_felts_in_calldata: limits.max_felts_in_calldata,
+        white_listed_eip_155_transaction_hashes: limits.white_listed_eip_155_transaction_hashes.clone(),
+        kakarot_address: deployment.kakarot_address,
     };
 
-    // Start the Kakarot RPC server
+    // Start the Kakarot RPC server, serving a chain with the limits
+    let eth_client = EthClient::new(
+        katana.starknet_provider(),
+        deployment.clone(),
+        limits,
+        PoolConfig::default(),
+        SpamProtectionConfig::default(),
+        katana.eth_provider().database().clone(),
+    );
+    let rpc_module = KakarotRpcModuleBuilder::new(eth_client.into()).rpc_module().expect("Failed to build RPC module");
     let (server_addr, server_handle) =
-        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+        run_server(rpc_module, RPCConfig::new_test_config_from_port(rand::random()), Registry::new())
+            .await
+            .expect("Error setting up Kakarot RPC server");
 
     // Send the RPC request to get the configuration
     let reqwest_client = reqwest::Client::new();
@@ -110,3 +130,41 @@ async fn test_kakarot_get_config(#[future] katana: Katana, _setup: ()) {
 
     drop(server_handle);
 }
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kakarot_get_transaction_status(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let eth_hash = B256::random();
+    let status = RelayedTransactionStatus {
+        eth_hash,
+        starknet_hash: Felt::from_hex("0x0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a").unwrap(),
+        status: RelayStatus::Requeued,
+        reason: Some("Invalid transaction nonce".to_string()),
+        retries: 1,
+    };
+    katana.eth_provider().database().upsert_transaction_status(status.clone()).await.unwrap();
+
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+
+    // When
+    let reqwest_client = reqwest::Client::new();
+    let res = reqwest_client
+        .post(format!("http://localhost:{}", server_addr.port()))
+        .header("Content-Type", "application/json")
+        .body(RawRpcParamsBuilder::new("kakarot_getTransactionStatus").add_param(eth_hash).build())
+        .send()
+        .await
+        .expect("kakarot_getTransactionStatus error");
+
+    // Then
+    let result: Option<RelayedTransactionStatus> =
+        serde_json::from_str(&res.text().await.expect("Failed to get response body"))
+            .and_then(|raw: Value| serde_json::from_value(raw["result"].clone()))
+            .expect("Failed to deserialize response body");
+    assert_eq!(result, Some(status));
+
+    drop(server_handle);
+}
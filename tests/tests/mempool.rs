@@ -4,9 +4,16 @@ use alloy_consensus::{TxEip1559, EMPTY_ROOT_HASH};
 use alloy_eips::eip2718::Encodable2718;
 use alloy_primitives::{Address, TxKind, B64, U256};
 use alloy_rpc_types::Header;
+use alloy_signer_local::PrivateKeySigner;
 use kakarot_rpc::{
+    config::RelayerConfig,
     constants::KKRT_BLOCK_GAS_LIMIT,
-    pool::mempool::maintain_transaction_pool,
+    pool::{
+        keystore::{RelayerKey, RelayerKeySource},
+        mempool::{maintain_transaction_pool, AccountManager},
+        monitor::RelayerMetrics,
+    },
+    prometheus_handler::Registry,
     providers::eth_provider::{
         constant::U64_HEX_STRING_LEN,
         database::{
@@ -14,12 +21,15 @@ use kakarot_rpc::{
             types::header::StoredHeader,
         },
         error::SignatureError,
+        starknet::signer::RelayerSigner,
         ChainProvider,
     },
     test_utils::{
         eoa::Eoa,
         fixtures::{katana, katana_empty, setup},
         katana::Katana,
+        signer::RemoteSigner,
+        tx_waiter::watch_tx,
     },
 };
 use mongodb::{
@@ -30,6 +40,7 @@ use reth_primitives::{sign_message, Transaction, TransactionSigned, TransactionS
 use reth_transaction_pool::{EthPooledTransaction, PoolTransaction, TransactionOrigin, TransactionPool};
 use revm_primitives::B256;
 use rstest::*;
+use starknet::{accounts::Account, core::types::Felt};
 use std::{sync::Arc, time::Duration};
 
 #[rstest]
@@ -369,6 +380,38 @@ pub async fn create_sample_transactions(
     Ok(transactions)
 }
 
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_mempool_rejects_undeployed_sender(#[future] katana_empty: Katana, _setup: ()) {
+    // Given
+    let eth_client = katana_empty.eth_client();
+    let chain_id = katana_empty.eth_provider().chain_id().await.unwrap_or_default().unwrap_or_default().to();
+    let private_key = B256::random();
+    let sender = PrivateKeySigner::from_bytes(&private_key).expect("Failed to create signer").address();
+    let transaction = Transaction::Eip1559(TxEip1559 {
+        chain_id,
+        gas_limit: 21000,
+        to: TxKind::Call(Address::random()),
+        max_fee_per_gas: 875_000_000,
+        ..Default::default()
+    });
+    let signature = sign_message(private_key, transaction.signature_hash()).expect("Failed to sign transaction");
+    let transaction_signed = TransactionSigned::from_transaction_and_signature(transaction, signature);
+    let transaction_signed_ec_recovered =
+        TransactionSignedEcRecovered::from_signed_transaction(transaction_signed, sender);
+    let encoded_length = transaction_signed_ec_recovered.encode_2718_len();
+    let transaction = EthPooledTransaction::new(transaction_signed_ec_recovered, encoded_length);
+
+    // When
+    let result = eth_client.mempool().add_transaction(TransactionOrigin::Local, transaction).await;
+
+    // Then
+    let err = result.expect_err("Expected the transaction to be rejected");
+    assert!(err.to_string().contains("is not deployed"));
+    assert_eq!(eth_client.mempool().pool_size().total, 0);
+}
+
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
@@ -486,3 +529,106 @@ async fn test_mempool_config(#[future] katana: Katana, _setup: ()) {
     // Check the gas limit for Kakarot blocks
     assert_eq!(eth_client.mempool().config().gas_limit, KKRT_BLOCK_GAS_LIMIT);
 }
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_account_manager_leases_relayers_exclusively(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let relayer_address = katana.eoa().relayer.address();
+    let signer = katana.eoa().relayer_signer.clone();
+    let key_source = RelayerKeySource::Static(vec![RelayerKey { address: relayer_address, signer: signer.into() }]);
+    let account_manager = AccountManager::new(key_source, RelayerConfig::default(), Arc::new(katana.eth_client()))
+        .await
+        .expect("Failed to create account manager");
+
+    // When
+    let lease = account_manager.get_relayer().await.expect("Failed to lease relayer");
+
+    // Then
+    // The only relayer is leased, no other relayer can be fetched until the lease is released.
+    assert_eq!(lease.address(), relayer_address);
+    assert!(account_manager.get_relayer().await.is_err());
+
+    drop(lease);
+    let lease = account_manager.get_relayer().await.expect("Failed to lease relayer after release");
+    assert_eq!(lease.address(), relayer_address);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_account_manager_relays_with_remote_signer(#[future] katana_empty: Katana, _setup: ()) {
+    // Given
+    let katana = katana_empty;
+    let relayer_address = katana.eoa().relayer.address();
+    let signer = RemoteSigner::spawn(katana.eoa().relayer_signer.clone()).await;
+    let key_source = RelayerKeySource::Static(vec![RelayerKey {
+        address: relayer_address,
+        signer: RelayerSigner::new(signer.clone()),
+    }]);
+    let account_manager = AccountManager::new(key_source, RelayerConfig::default(), Arc::new(katana.eth_client()))
+        .await
+        .expect("Failed to create account manager");
+    let (_, transaction_signed) = create_sample_transactions(&katana, 1)
+        .await
+        .expect("Failed to create sample transaction")
+        .pop()
+        .expect("Expected at least one transaction");
+
+    // When
+    let lease = account_manager.get_relayer().await.expect("Failed to lease relayer");
+    let starknet_hash = lease.relay_transaction(&transaction_signed).await.expect("Failed to relay transaction");
+
+    // Then
+    // The Starknet transaction was signed by the remote signer and accepted by the node.
+    assert_eq!(signer.signed(), 1);
+    watch_tx(katana.eth_provider().starknet_provider_inner(), starknet_hash, std::time::Duration::from_millis(300), 60)
+        .await
+        .expect("Relayed transaction failed");
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_account_manager_monitor_relayers(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let eth_client = Arc::new(katana.eth_client());
+    let signer = katana.eoa().relayer_signer.clone();
+    let funded = RelayerKey { address: katana.eoa().relayer.address(), signer: signer.clone().into() };
+    let unfunded = RelayerKey { address: Felt::from(0xdead_u64), signer: signer.into() };
+    let registry = Registry::new();
+    let account_manager = AccountManager::new(
+        RelayerKeySource::Static(vec![funded, unfunded.clone()]),
+        RelayerConfig::default(),
+        eth_client.clone(),
+    )
+    .await
+    .expect("Failed to create account manager")
+    .with_metrics(RelayerMetrics::new(&registry).expect("Failed to register metrics"));
+    eth_client.relayer_funds().set_funded(false);
+
+    // When
+    account_manager.monitor_relayers().await;
+
+    // Then
+    assert!(eth_client.relayer_funds().is_funded());
+    let families = registry.gather();
+    let family = |name: &str| families.iter().find(|family| family.get_name() == name).expect("Missing metric");
+    assert!((family("relayer_funded_count").get_metric()[0].get_gauge().get_value() - 1.).abs() < f64::EPSILON);
+    assert_eq!(family("relayer_balance").get_metric().len(), 2);
+    assert_eq!(family("relayer_nonce").get_metric().len(), 2);
+
+    // Given
+    let account_manager =
+        AccountManager::new(RelayerKeySource::Static(vec![unfunded]), RelayerConfig::default(), eth_client.clone())
+            .await
+            .expect("Failed to create account manager");
+
+    // When
+    account_manager.monitor_relayers().await;
+
+    // Then
+    // The balance of the only relayer was read, and is too low to relay transactions
+    assert!(!eth_client.relayer_funds().is_funded());
+}
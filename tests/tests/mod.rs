@@ -1,8 +1,12 @@
 pub mod alchemy_api;
 pub mod debug_api;
 pub mod eth_provider;
+pub mod filters;
 pub mod kakarot_api;
 pub mod mempool;
+pub mod otterscan_api;
+pub mod pubsub_api;
+pub mod rpc_api;
 pub mod trace_api;
 pub mod tracer;
 pub mod txpool_api;
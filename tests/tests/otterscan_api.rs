@@ -0,0 +1,275 @@
+#![allow(clippy::used_underscore_binding)]
+#![cfg(feature = "testing")]
+use crate::tests::tracer::{tracing, TRACING_BLOCK_NUMBER, TRACING_TRANSACTIONS_COUNT};
+use alloy_primitives::{Address, B256, U256};
+use alloy_rpc_types::{BlockTransactions, Header};
+use alloy_rpc_types_trace::otterscan::OperationType;
+use kakarot_rpc::{
+    models::felt::Felt252Wrapper,
+    providers::{
+        eth_provider::{
+            database::types::transaction::ExtendedTransaction, error::EthApiError, BlockProvider, ReceiptProvider,
+        },
+        otterscan_provider::{OtterscanDataProvider, OtterscanProvider},
+    },
+    test_utils::{
+        eoa::Eoa as _,
+        evm_contract::KakarotEvmContract,
+        fixtures::{katana, plain_opcodes, setup},
+        katana::Katana,
+    },
+};
+use rstest::*;
+
+/// Copies the transaction and its receipt to `count` new blocks after the latest block, and
+/// returns the hashes of the copies.
+async fn copy_transaction_to_new_blocks(katana: &Katana, transaction: &ExtendedTransaction, count: u64) -> Vec<B256> {
+    let receipt = katana
+        .eth_provider()
+        .transaction_receipt(transaction.hash)
+        .await
+        .expect("Failed to get receipt")
+        .expect("Receipt not found");
+
+    let mut hashes = Vec::new();
+    for block_number in katana.block_number() + 1..=katana.block_number() + count {
+        let (hash, block_hash) = (B256::random(), B256::random());
+
+        let mut transaction = transaction.clone();
+        transaction.hash = hash;
+        transaction.block_hash = Some(block_hash);
+        transaction.block_number = Some(block_number);
+        let mut receipt = receipt.clone();
+        receipt.transaction_hash = hash;
+        receipt.block_hash = Some(block_hash);
+        receipt.block_number = Some(block_number);
+
+        let header = Header { number: block_number, hash: block_hash, ..Default::default() };
+        katana.add_transactions_with_header_to_database(vec![transaction], header).await;
+        katana.add_receipts_to_database(vec![receipt]).await;
+        hashes.push(hash);
+    }
+    hashes
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_transaction_by_sender_and_nonce(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let first_transaction = katana.first_transaction().unwrap();
+
+    // When
+    let hash = otterscan_provider
+        .transaction_by_sender_and_nonce(first_transaction.from, first_transaction.nonce)
+        .await
+        .unwrap();
+    let unknown_hash = otterscan_provider.transaction_by_sender_and_nonce(Address::ZERO, u64::MAX).await.unwrap();
+
+    // Then
+    assert_eq!(hash, Some(first_transaction.hash));
+    assert_eq!(unknown_hash, None);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_search_transactions_before(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let first_transaction = katana.first_transaction().unwrap();
+
+    // When
+    let result = otterscan_provider.search_transactions_before(first_transaction.from, 0, 25).await.unwrap();
+
+    // Then
+    assert!(result.first_page);
+    assert_eq!(result.txs.len(), result.receipts.len());
+    assert!(result.txs.iter().any(|tx| tx.hash == first_transaction.hash));
+    assert!(result.txs.iter().all(|tx| tx.from == first_transaction.from || tx.to == Some(first_transaction.from)));
+    // The transactions are sorted in descending order.
+    assert!(result.txs.windows(2).all(|txs| txs[0].block_number >= txs[1].block_number));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_search_transactions_after(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let first_transaction = katana.first_transaction().unwrap();
+    let copies = copy_transaction_to_new_blocks(&katana, &first_transaction, 3).await;
+
+    // When
+    let result = otterscan_provider.search_transactions_after(first_transaction.from, 0, 25).await.unwrap();
+
+    // Then
+    assert!(result.last_page);
+    assert_eq!(result.txs.len(), result.receipts.len());
+    assert!(result.txs.iter().any(|tx| tx.hash == first_transaction.hash));
+    assert!(copies.iter().all(|hash| result.txs.iter().any(|tx| tx.hash == *hash)));
+    assert!(result.txs.iter().all(|tx| tx.from == first_transaction.from || tx.to == Some(first_transaction.from)));
+    // The transactions span several blocks and are sorted in descending order.
+    assert!(result.txs.windows(2).any(|txs| txs[0].block_number != txs[1].block_number));
+    assert!(result.txs.windows(2).all(|txs| txs[0].block_number >= txs[1].block_number));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_details(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let block_number = katana.first_transaction().unwrap().block_number.unwrap();
+    let transaction_count =
+        katana.eth_provider().block_transaction_count_by_number(block_number.into()).await.unwrap().unwrap_or_default();
+
+    // When
+    let details = otterscan_provider.block_details(block_number).await.unwrap();
+    let unknown_block = otterscan_provider.block_details(u64::MAX).await;
+
+    // Then
+    let details = serde_json::to_value(details).unwrap();
+    assert_eq!(details["block"]["number"], serde_json::to_value(U256::from(block_number)).unwrap());
+    assert_eq!(details["block"]["transactionCount"], serde_json::to_value(transaction_count.to::<usize>()).unwrap());
+    assert!(matches!(unknown_block, Err(EthApiError::UnknownBlock(_))));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_transactions(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let block_number = katana.first_transaction().unwrap().block_number.unwrap();
+    let mut transactions =
+        katana.eth_provider().block_transactions(Some(block_number.into())).await.unwrap().unwrap_or_default();
+    transactions.sort_unstable_by_key(|tx| tx.transaction_index);
+
+    // When
+    let result = otterscan_provider.block_transactions(block_number, 0, 1).await.unwrap();
+
+    // Then
+    let BlockTransactions::Full(page) = &result.fullblock.block.transactions else {
+        panic!("Expected full transactions");
+    };
+    assert_eq!(result.fullblock.transaction_count, transactions.len());
+    // The first page holds the last transaction of the block.
+    assert_eq!(page.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![transactions.last().unwrap().hash]);
+    assert_eq!(result.receipts.len(), 1);
+    assert_eq!(result.receipts[0].receipt.transaction_hash, page[0].hash);
+    assert!(page[0].input.len() <= 4);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_contract_creator(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let first_transaction = katana.first_transaction().unwrap();
+    let receipt = katana.eth_provider().transaction_receipt(first_transaction.hash).await.unwrap().unwrap();
+    let contract = receipt.contract_address.expect("Expected a contract address");
+
+    // When
+    let creator = otterscan_provider.contract_creator(contract).await.unwrap().expect("Creator not found");
+    let unknown_creator = otterscan_provider.contract_creator(Address::repeat_byte(0xab)).await.unwrap();
+
+    // Then
+    assert_eq!(creator.hash, first_transaction.hash);
+    assert_eq!(creator.creator, first_transaction.from);
+    assert!(unknown_creator.is_none());
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_has_code(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let contract_address: Address = Felt252Wrapper::from(plain_opcodes.evm_address).try_into().unwrap();
+
+    // When
+    let contract_has_code = otterscan_provider.has_code(contract_address, None).await.unwrap();
+    let eoa_has_code = otterscan_provider.has_code(katana.eoa().evm_address().unwrap(), None).await.unwrap();
+
+    // Then
+    assert!(contract_has_code);
+    assert!(!eoa_has_code);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_internal_operations(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let contract_address: Address = Felt252Wrapper::from(plain_opcodes.evm_address).try_into().unwrap();
+    let tx_hash = katana.block_transaction_hashes(TRACING_BLOCK_NUMBER).await[1];
+
+    // When
+    let operations = otterscan_provider.internal_operations(tx_hash).await.unwrap();
+    let unknown_operations = otterscan_provider.internal_operations(B256::ZERO).await;
+
+    // Then
+    // The counter creation is the only operation, the calls don't transfer any value.
+    assert_eq!(operations.len(), 1);
+    assert!(matches!(operations[0].r#type, OperationType::OpCreate));
+    assert_eq!(operations[0].from, contract_address);
+    assert_eq!(operations[0].value, U256::ZERO);
+    assert!(matches!(unknown_operations, Err(EthApiError::TransactionNotFound(_))));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_trace_transaction(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let contract_address: Address = Felt252Wrapper::from(plain_opcodes.evm_address).try_into().unwrap();
+    let eoa_address = katana.eoa().evm_address().unwrap();
+    let tx_hash = katana.block_transaction_hashes(TRACING_BLOCK_NUMBER).await[1];
+
+    // When
+    let entries = otterscan_provider.trace_transaction(tx_hash).await.unwrap().expect("Transaction not found");
+    let unknown_entries = otterscan_provider.trace_transaction(B256::ZERO).await.unwrap();
+
+    // Then
+    // The call to the contract creates a counter and invokes it.
+    assert_eq!(entries.iter().map(|entry| entry.r#type.as_str()).collect::<Vec<_>>(), ["CALL", "CREATE", "CALL"]);
+    assert_eq!(entries.iter().map(|entry| entry.depth).collect::<Vec<_>>(), [0, 1, 1]);
+    assert_eq!((entries[0].from, entries[0].to), (eoa_address, contract_address));
+    assert!(entries[1..].iter().all(|entry| entry.from == contract_address));
+    assert!(unknown_entries.is_none());
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_transaction_error(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    let otterscan_provider = OtterscanDataProvider::new((*katana.eth_provider()).clone());
+    let tx_hashes = katana.block_transaction_hashes(TRACING_BLOCK_NUMBER).await;
+    assert_eq!(tx_hashes.len(), TRACING_TRANSACTIONS_COUNT);
+
+    // When
+    let error = otterscan_provider.transaction_error(tx_hashes[1]).await.unwrap();
+    let unknown_error = otterscan_provider.transaction_error(B256::ZERO).await.unwrap();
+
+    // Then
+    // The transaction succeeded, there is no revert data.
+    assert_eq!(error, Some(Default::default()));
+    assert_eq!(unknown_error, None);
+}
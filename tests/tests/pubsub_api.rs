@@ -0,0 +1,207 @@
+#![allow(clippy::used_underscore_binding)]
+#![cfg(feature = "testing")]
+
+use crate::tests::mempool::create_sample_transactions;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Address, Bytes, LogData, B256};
+use alloy_rpc_types::{Header, Log};
+use futures::StreamExt;
+use jsonrpsee::{
+    core::client::{Subscription, SubscriptionClientT},
+    rpc_params,
+    ws_client::{WsClient, WsClientBuilder},
+};
+use kakarot_rpc::{
+    providers::eth_provider::database::ethereum::EthereumBlockStore,
+    test_utils::{
+        fixtures::{katana, katana_empty, setup},
+        katana::Katana,
+        rpc::{start_kakarot_rpc_server, RawRpcParamsBuilder},
+    },
+};
+use rstest::*;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::{net::SocketAddr, time::Duration};
+
+/// Time left to the headers tail to record the latest block before a new block is added.
+const TAIL_START_DELAY: Duration = Duration::from_secs(3);
+/// Maximum time to wait for a notification.
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Connects a WebSocket client to the server.
+async fn ws_client(server_addr: SocketAddr) -> WsClient {
+    WsClientBuilder::default()
+        .build(format!("ws://localhost:{}", server_addr.port()))
+        .await
+        .expect("Failed to connect to the server")
+}
+
+/// Returns the next notification of the subscription, or `None` if none arrives in time.
+async fn next_notification<T: DeserializeOwned>(subscription: &mut Subscription<T>, timeout: Duration) -> Option<T> {
+    let notification = tokio::time::timeout(timeout, subscription.next()).await.ok()?;
+    Some(notification.expect("Subscription closed").expect("Failed to deserialize notification"))
+}
+
+/// Returns the header of a new block on top of the latest block of the database.
+pub async fn next_header(katana: &Katana) -> Header {
+    let latest_header =
+        katana.eth_provider().database().latest_header().await.expect("Failed to get the latest header");
+    let number = latest_header.map_or(0, |header| header.number + 1);
+    Header { number, hash: B256::random(), ..Default::default() }
+}
+
+/// Adds a new block on top of the latest block of the database and returns its header.
+pub async fn add_block(katana: &Katana) -> Header {
+    let header = next_header(katana).await;
+    katana.add_transactions_with_header_to_database(vec![], header.clone()).await;
+    header
+}
+
+/// Returns a log emitted by the address in the block.
+pub fn log(address: Address, header: &Header, log_index: u64) -> Log {
+    Log {
+        inner: alloy_primitives::Log {
+            address,
+            data: LogData::new_unchecked(vec![B256::with_last_byte(0x1)], Bytes::from_static(&[0x1])),
+        },
+        block_hash: Some(header.hash),
+        block_number: Some(header.number),
+        block_timestamp: None,
+        transaction_hash: Some(B256::random()),
+        transaction_index: Some(0),
+        log_index: Some(log_index),
+        removed: false,
+    }
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_new_heads(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+    let client = ws_client(server_addr).await;
+    let mut subscription: Subscription<Header> = client
+        .subscribe("eth_subscribe", rpc_params!["newHeads"], "eth_unsubscribe")
+        .await
+        .expect("Failed to subscribe to newHeads");
+    tokio::time::sleep(TAIL_START_DELAY).await;
+
+    // When
+    let header = add_block(&katana).await;
+
+    // Then
+    let notification = next_notification(&mut subscription, NOTIFICATION_TIMEOUT).await.expect("No new head received");
+    assert_eq!(notification.number, header.number);
+    assert_eq!(notification.hash, header.hash);
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_new_heads_after_all_subscriptions_closed(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+    let client = ws_client(server_addr).await;
+    let subscription: Subscription<Header> = client
+        .subscribe("eth_subscribe", rpc_params!["newHeads"], "eth_unsubscribe")
+        .await
+        .expect("Failed to subscribe to newHeads");
+    subscription.unsubscribe().await.expect("Failed to unsubscribe from newHeads");
+    // The headers tail stops once all the subscriptions are closed.
+    tokio::time::sleep(TAIL_START_DELAY).await;
+
+    // When
+    let mut subscription: Subscription<Header> = client
+        .subscribe("eth_subscribe", rpc_params!["newHeads"], "eth_unsubscribe")
+        .await
+        .expect("Failed to subscribe to newHeads");
+    tokio::time::sleep(TAIL_START_DELAY).await;
+    let header = add_block(&katana).await;
+
+    // Then
+    // A new headers tail is started for the new subscription.
+    let notification = next_notification(&mut subscription, NOTIFICATION_TIMEOUT).await.expect("No new head received");
+    assert_eq!(notification.number, header.number);
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_logs_with_filter(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+    let client = ws_client(server_addr).await;
+    let address = Address::with_last_byte(0x42);
+    let mut subscription: Subscription<Log> = client
+        .subscribe("eth_subscribe", rpc_params!["logs", json!({ "address": address })], "eth_unsubscribe")
+        .await
+        .expect("Failed to subscribe to logs");
+    tokio::time::sleep(TAIL_START_DELAY).await;
+
+    // When
+    // The block holds a log of the filtered address and a log of another address.
+    let header = next_header(&katana).await;
+    let expected = log(address, &header, 0);
+    katana.add_logs_to_database(vec![expected.clone(), log(Address::with_last_byte(0x43), &header, 1)]).await;
+    katana.add_transactions_with_header_to_database(vec![], header).await;
+
+    // Then
+    let notification = next_notification(&mut subscription, NOTIFICATION_TIMEOUT).await.expect("No log received");
+    assert_eq!(notification, expected);
+    // The log of the other address is filtered out.
+    assert!(next_notification(&mut subscription, TAIL_START_DELAY).await.is_none());
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_subscribe_new_pending_transactions(#[future] katana_empty: Katana, _setup: ()) {
+    // Given
+    let katana = katana_empty;
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+    let client = ws_client(server_addr).await;
+    let mut hashes: Subscription<B256> = client
+        .subscribe("eth_subscribe", rpc_params!["newPendingTransactions"], "eth_unsubscribe")
+        .await
+        .expect("Failed to subscribe to newPendingTransactions");
+    let mut transactions: Subscription<Value> = client
+        .subscribe("eth_subscribe", rpc_params!["newPendingTransactions", true], "eth_unsubscribe")
+        .await
+        .expect("Failed to subscribe to full newPendingTransactions");
+    let (_, transaction) = create_sample_transactions(&katana, 1)
+        .await
+        .expect("Failed to create sample transactions")
+        .pop()
+        .expect("Missing sample transaction");
+
+    // When
+    let request =
+        RawRpcParamsBuilder::new("eth_sendRawTransaction").add_param(Bytes::from(transaction.encoded_2718())).build();
+    reqwest::Client::new()
+        .post(format!("http://localhost:{}", server_addr.port()))
+        .header("Content-Type", "application/json")
+        .body(request)
+        .send()
+        .await
+        .expect("eth_sendRawTransaction error");
+
+    // Then
+    let hash = next_notification(&mut hashes, NOTIFICATION_TIMEOUT).await.expect("No transaction hash received");
+    assert_eq!(hash, transaction.hash());
+    let full = next_notification(&mut transactions, NOTIFICATION_TIMEOUT).await.expect("No transaction received");
+    assert_eq!(full["hash"], json!(transaction.hash()));
+
+    drop(server_handle);
+}
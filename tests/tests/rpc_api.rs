@@ -0,0 +1,208 @@
+#![allow(clippy::used_underscore_binding)]
+#![cfg(feature = "testing")]
+
+use crate::tests::mempool::create_sample_transactions;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::Bytes;
+use kakarot_rpc::{
+    client::EthClient,
+    config::SpamProtectionConfig,
+    eth_rpc::{
+        config::RPCConfig,
+        middleware::prefix_methods,
+        rpc::{KakarotRpcModule, KakarotRpcModuleBuilder},
+        run_server,
+    },
+    pool::spam::RateLimit,
+    prometheus_handler::Registry,
+    providers::eth_provider::{error::EthRpcErrorCode, starknet::kakarot_core::KakarotDeployment},
+    test_utils::{
+        fixtures::{katana, katana_empty, setup},
+        katana::Katana,
+        rpc::{start_kakarot_rpc_server, RawRpcParamsBuilder},
+    },
+};
+use reth_primitives::{sign_message, TransactionSigned};
+use reth_transaction_pool::PoolConfig;
+use rstest::*;
+use serde_json::Value;
+use std::{collections::BTreeMap, net::SocketAddr, time::Duration};
+
+async fn call(server_addr: SocketAddr, method: &str) -> Value {
+    call_path(server_addr, "/", method).await["result"].clone()
+}
+
+/// Calls the method at the path of the server, returning the raw response.
+async fn call_path(server_addr: SocketAddr, path: &str, method: &str) -> Value {
+    let reqwest_client = reqwest::Client::new();
+    let res = reqwest_client
+        .post(format!("http://localhost:{}{path}", server_addr.port()))
+        .header("Content-Type", "application/json")
+        .body(RawRpcParamsBuilder::new(method).build())
+        .send()
+        .await
+        .unwrap_or_else(|_| panic!("{method} error"));
+    let raw: Value = serde_json::from_str(&res.text().await.expect("Failed to get response body"))
+        .expect("Failed to deserialize response body");
+    raw
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rpc_modules_and_discover(#[future] katana: Katana, _setup: ()) {
+    // Given
+    let rpc_module =
+        KakarotRpcModuleBuilder::new(katana.eth_client().into()).rpc_module().expect("Failed to build RPC module");
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+
+    // When
+    let modules: BTreeMap<String, String> =
+        serde_json::from_value(call(server_addr, "rpc_modules").await).expect("Failed to deserialize modules");
+    let document = call(server_addr, "rpc.discover").await;
+
+    // Then
+    assert_eq!(modules.len(), KakarotRpcModule::ALL.len());
+    assert!(modules.values().all(|version| version == "1.0"));
+
+    let mut described: Vec<_> = document["methods"]
+        .as_array()
+        .expect("Missing methods")
+        .iter()
+        .map(|method| method["name"].as_str().expect("Missing method name").to_string())
+        .collect();
+    let mut registered: Vec<_> = rpc_module.method_names().map(ToString::to_string).collect();
+    described.sort();
+    registered.sort();
+    assert_eq!(described, registered);
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rpc_enabled_modules(#[future] katana: Katana, _setup: ()) {
+    // When
+    let rpc_module = KakarotRpcModuleBuilder::new(katana.eth_client().into())
+        .with_enabled_modules([KakarotRpcModule::Eth, KakarotRpcModule::Net])
+        .rpc_module()
+        .expect("Failed to build RPC module");
+
+    // Then
+    assert!(rpc_module
+        .method_names()
+        .all(|method| { method.starts_with("eth_") || method.starts_with("net_") || method.starts_with("rpc") }));
+    assert!(rpc_module.method_names().any(|method| method == "eth_getLogs"));
+    assert!(rpc_module.method_names().any(|method| method == "rpc_modules"));
+    assert!(!rpc_module.method_names().any(|method| method.starts_with("debug_")));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rpc_chain_routing(#[future] katana: Katana, _setup: ()) {
+    // Given
+    // A second deployment, with another chain id, served under /chain/other
+    let deployment = katana.eth_provider().deployment().clone();
+    let other_deployment = KakarotDeployment { chain_id: deployment.chain_id + 1, ..deployment.clone() };
+    let other_eth_client = EthClient::new(
+        katana.starknet_provider(),
+        other_deployment,
+        katana.eth_provider().limits().clone(),
+        PoolConfig::default(),
+        SpamProtectionConfig::default(),
+        katana.eth_provider().database().clone(),
+    );
+    let mut rpc_module =
+        KakarotRpcModuleBuilder::new(katana.eth_client().into()).rpc_module().expect("Failed to build RPC module");
+    let other_rpc_module =
+        KakarotRpcModuleBuilder::new(other_eth_client.into()).rpc_module().expect("Failed to build RPC module");
+    rpc_module
+        .merge(prefix_methods(other_rpc_module, "other").expect("Failed to prefix methods"))
+        .expect("Failed to merge RPC modules");
+    let (server_addr, server_handle) =
+        run_server(rpc_module, RPCConfig::new_test_config_from_port(rand::random()), Registry::new())
+            .await
+            .expect("Error setting up Kakarot RPC server");
+
+    // When
+    let chain_id = call_path(server_addr, "/", "eth_chainId").await;
+    let other_chain_id = call_path(server_addr, "/chain/other", "eth_chainId").await;
+    let unknown_chain = call_path(server_addr, "/chain/unknown", "eth_chainId").await;
+    let direct_call = call_path(server_addr, "/", "other/eth_chainId").await;
+
+    // Then
+    assert_eq!(chain_id["result"], format!("{:#x}", deployment.chain_id));
+    assert_eq!(other_chain_id["result"], format!("{:#x}", deployment.chain_id + 1));
+    // Unknown chains and direct calls to the methods of a chain are rejected
+    assert!(unknown_chain["error"].is_object());
+    assert!(direct_call["error"].is_object());
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rpc_send_raw_transaction_rate_limited(#[future] katana_empty: Katana, _setup: ()) {
+    // Given
+    // A pool accepting a single valid transaction per RPC client within the window
+    let katana = katana_empty;
+    let eth_client = EthClient::new(
+        katana.starknet_provider(),
+        katana.eth_provider().deployment().clone(),
+        katana.eth_provider().limits().clone(),
+        PoolConfig::default(),
+        SpamProtectionConfig {
+            origin_rate_limit: Some(RateLimit { max_transactions: 1, window: Duration::from_secs(60) }),
+            ..Default::default()
+        },
+        katana.eth_provider().database().clone(),
+    );
+    let rpc_module = KakarotRpcModuleBuilder::new(eth_client.into()).rpc_module().expect("Failed to build RPC module");
+    let (server_addr, server_handle) =
+        run_server(rpc_module, RPCConfig::new_test_config_from_port(rand::random()), Registry::new())
+            .await
+            .expect("Error setting up Kakarot RPC server");
+    let transactions = create_sample_transactions(&katana, 2).await.expect("Failed to create sample transactions");
+    let send = |transaction: &TransactionSigned| {
+        let request = RawRpcParamsBuilder::new("eth_sendRawTransaction")
+            .add_param(Bytes::from(transaction.encoded_2718()))
+            .build();
+        async move {
+            let res = reqwest::Client::new()
+                .post(format!("http://localhost:{}", server_addr.port()))
+                .header("Content-Type", "application/json")
+                .body(request)
+                .send()
+                .await
+                .expect("eth_sendRawTransaction error");
+            serde_json::from_str::<Value>(&res.text().await.expect("Failed to get response body"))
+                .expect("Failed to deserialize response body")
+        }
+    };
+
+    // A transaction for another chain, rejected by the pool
+    let mut transaction = transactions[0].1.transaction.clone();
+    transaction.set_chain_id(katana.eth_provider().deployment().chain_id + 1);
+    let signature =
+        sign_message(katana.eoa().private_key(), transaction.signature_hash()).expect("Failed to sign transaction");
+    let invalid = TransactionSigned::from_transaction_and_signature(transaction, signature);
+
+    // When
+    let invalid = send(&invalid).await;
+    let accepted = send(&transactions[0].1).await;
+    let rejected = send(&transactions[1].1).await;
+
+    // Then
+    // The invalid transaction doesn't use up the quota of the client
+    assert!(invalid["error"].is_object());
+    assert!(!invalid["error"]["message"].as_str().expect("Missing error message").contains("rate limited"));
+    assert_eq!(accepted["result"], format!("{:#x}", transactions[0].1.hash()));
+    assert_eq!(rejected["error"]["code"], EthRpcErrorCode::TransactionRejected as i32);
+    assert!(rejected["error"]["message"].as_str().expect("Missing error message").contains("rate limited"));
+
+    drop(server_handle);
+}
@@ -3,20 +3,25 @@
 use alloy_consensus::Transaction;
 use alloy_dyn_abi::DynSolValue;
 use alloy_eips::BlockId;
-use alloy_primitives::{Address, TxKind, B256, U256};
+use alloy_primitives::{Address, Bytes, TxKind, B256, B64, U256};
 use alloy_rpc_types::{request::TransactionInput, TransactionRequest};
-use alloy_rpc_types_trace::geth::{
-    CallFrame, GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions, GethDebugTracingOptions,
-    GethTrace,
+use alloy_rpc_types_trace::{
+    filter::TraceFilter,
+    geth::{
+        CallFrame, GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions,
+        GethDebugTracingOptions, GethTrace,
+    },
+    parity::{Action, LocalizedTransactionTrace, TraceResults, TraceType},
 };
 use alloy_serde::{OtherFields, WithOtherFields};
 use alloy_sol_types::{sol, SolCall};
 use kakarot_rpc::{
+    eth_rpc::servers::trace_rpc::MAX_TRACE_FILTER_BLOCKS,
     providers::eth_provider::ChainProvider,
     test_utils::{
         eoa::Eoa,
         evm_contract::{EvmContract, KakarotEvmContract, TransactionInfo, TxCommonInfo, TxFeeMarketInfo},
-        fixtures::{plain_opcodes, setup},
+        fixtures::{counter, plain_opcodes, setup},
         katana::Katana,
         rpc::{start_kakarot_rpc_server, RawRpcParamsBuilder},
     },
@@ -24,6 +29,7 @@ use kakarot_rpc::{
 use rstest::*;
 use serde_json::Value;
 use starknet::{core::types::MaybePendingBlockWithTxHashes, providers::Provider};
+use std::{collections::HashSet, net::SocketAddr};
 
 /// The block number on which tracing will be performed.
 const TRACING_BLOCK_NUMBER: u64 = 0x3;
@@ -38,6 +44,8 @@ fn header(block_number: u64, hash: B256, parent_hash: B256, base_fee: u64) -> al
         parent_hash,
         gas_limit: u64::MAX,
         base_fee_per_gas: Some(base_fee),
+        mix_hash: Some(B256::ZERO),
+        nonce: Some(B64::ZERO),
         ..Default::default()
     }
 }
@@ -130,10 +138,22 @@ pub async fn tracing(
     katana.add_transactions_with_header_to_database(txs, header).await;
 }
 
+/// Sends the JSON-RPC request to the Kakarot RPC server and returns the raw response.
+async fn send_request(server_addr: SocketAddr, request: RawRpcParamsBuilder) -> Value {
+    let res = reqwest::Client::new()
+        .post(format!("http://localhost:{}", server_addr.port()))
+        .header("Content-Type", "application/json")
+        .body(request.build())
+        .send()
+        .await
+        .expect("Failed to call Trace RPC");
+    let response = res.text().await.expect("Failed to get response body");
+    serde_json::from_str(&response).expect("Failed to deserialize response body")
+}
+
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
-#[ignore = "failing because of relayer change"]
 async fn test_trace_call(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
     // Setup the Kakarot RPC server.
     let katana = plain_opcodes.0;
@@ -150,7 +170,6 @@ async fn test_trace_call(#[future] plain_opcodes: (Katana, KakarotEvmContract),
         gas: Some(21000),
         gas_price: Some(10),
         value: Some(U256::ZERO),
-        nonce: Some(2),
         ..Default::default()
     };
 
@@ -201,17 +220,16 @@ async fn test_trace_call(#[future] plain_opcodes: (Katana, KakarotEvmContract),
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
-#[ignore = "failing because of relayer change"]
-async fn test_trace_call_counter(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+async fn test_trace_call_counter(#[future] counter: (Katana, KakarotEvmContract), _setup: ()) {
     // Test function for tracing a call to a counter contract
 
     // Extract Katana instance and get the EOA's EVM address
-    let katana = plain_opcodes.0;
+    let katana = counter.0;
     let evm_address = katana.eoa().evm_address().expect("Failed to get eoa address");
-    let plain_opcodes = plain_opcodes.1;
+    let counter = counter.1;
 
     // Perform tracing setup
-    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    tracing(&katana, &counter, "inc", Box::new(|_| vec![])).await;
 
     // Start the Kakarot RPC server
     let (server_addr, server_handle) =
@@ -227,16 +245,15 @@ async fn test_trace_call_counter(#[future] plain_opcodes: (Katana, KakarotEvmCon
 
     // Prepare the calldata for invoking the incForLoop function with 5 iterations
     let calldata = CounterContract::incForLoopCall { iterations: U256::from(5) }.abi_encode();
-    let contract_address = Address::from_slice(&plain_opcodes.evm_address.to_bytes_be()[12..]);
+    let contract_address = Address::from_slice(&counter.evm_address.to_bytes_be()[12..]);
 
     // Define the transaction request
     let request = TransactionRequest {
         from: Some(evm_address),
         to: Some(TxKind::Call(contract_address)),
         gas: Some(210_000),
-        gas_price: Some(1_000_000_000_000_000_000_000),
+        gas_price: Some(10),
         value: Some(U256::ZERO),
-        nonce: Some(2),
         input: TransactionInput { input: Some(calldata.clone().into()), data: None },
         ..Default::default()
     };
@@ -274,20 +291,185 @@ async fn test_trace_call_counter(#[future] plain_opcodes: (Katana, KakarotEvmCon
         serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result from trace");
 
     // Assert that the trace result matches expectations
-    assert_eq!(
-        trace,
-        GethTrace::CallTracer(CallFrame {
-            from: evm_address,
-            gas: U256::from(210_000),
-            gas_used: U256::from(21410),
-            to: Some(contract_address),
-            value: Some(U256::ZERO),
-            typ: "CALL".to_string(),
-            input: calldata.into(),
-            ..Default::default()
-        })
-    );
+    let GethTrace::CallTracer(frame) = trace else {
+        panic!("Expected a call tracer frame");
+    };
+    assert_eq!(frame.from, evm_address);
+    assert_eq!(frame.to, Some(contract_address));
+    assert_eq!(frame.gas, U256::from(210_000));
+    assert_eq!(frame.value, Some(U256::ZERO));
+    assert_eq!(frame.typ, "CALL");
+    assert_eq!(frame.input, Bytes::from(calldata));
+    assert_eq!(frame.error, None);
+    // The loop increments the counter storage, on top of the intrinsic gas of the call
+    assert!(frame.gas_used > U256::from(21000) && frame.gas_used < U256::from(210_000));
 
     // Clean up by dropping the server handle
     drop(server_handle);
 }
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_trace_transaction_and_get(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    let tx_hash = katana.block_transaction_hashes(TRACING_BLOCK_NUMBER).await[1];
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+
+    // When
+    let raw = send_request(server_addr, RawRpcParamsBuilder::new("trace_transaction").add_param(tx_hash)).await;
+    let traces: Vec<LocalizedTransactionTrace> =
+        serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result");
+
+    // Then
+    // We expect 3 traces for the transaction: CALL, CREATE, and CALL.
+    assert_eq!(traces.len(), 3);
+    assert!(traces.iter().all(|trace| trace.transaction_hash == Some(tx_hash)));
+
+    // When
+    let raw =
+        send_request(server_addr, RawRpcParamsBuilder::new("trace_get").add_param(tx_hash).add_param(["0x1"])).await;
+    let trace: Option<LocalizedTransactionTrace> =
+        serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result");
+
+    // Then
+    assert_eq!(trace.as_ref(), Some(&traces[1]));
+    assert!(matches!(trace.unwrap().trace.action, Action::Create(_)));
+
+    // When
+    let out_of_range =
+        send_request(server_addr, RawRpcParamsBuilder::new("trace_get").add_param(tx_hash).add_param(["0x3"])).await;
+    let unknown_transaction =
+        send_request(server_addr, RawRpcParamsBuilder::new("trace_get").add_param(B256::ZERO).add_param(["0x0"])).await;
+
+    // Then
+    // The transaction only has 3 traces, and unknown transactions have none.
+    assert_eq!(out_of_range["result"], Value::Null);
+    assert_eq!(unknown_transaction["result"], Value::Null);
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_trace_call_and_call_many(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+
+    let eoa_address = katana.eoa().evm_address().expect("Failed to get eoa address");
+    let contract_address = Address::from_slice(&plain_opcodes.evm_address.to_bytes_be()[12..]);
+    let calldata = plain_opcodes
+        .prepare_call_transaction(
+            "createCounterAndInvoke",
+            &[],
+            &TransactionInfo::FeeMarketInfo(TxFeeMarketInfo::default()),
+        )
+        .expect("Failed to prepare call transaction")
+        .input()
+        .clone();
+    let request = TransactionRequest {
+        from: Some(eoa_address),
+        to: Some(TxKind::Call(contract_address)),
+        gas: Some(1_000_000),
+        input: TransactionInput { input: Some(calldata), data: None },
+        ..Default::default()
+    };
+    let trace_types: HashSet<_> = [TraceType::Trace].into_iter().collect();
+
+    // When
+    let raw = send_request(
+        server_addr,
+        RawRpcParamsBuilder::new("trace_call")
+            .add_param(&request)
+            .add_param(&trace_types)
+            .add_param(BlockId::Number(TRACING_BLOCK_NUMBER.into())),
+    )
+    .await;
+    let results: TraceResults = serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result");
+
+    // Then
+    // We expect 3 traces for the call: CALL, CREATE, and CALL.
+    assert_eq!(results.trace.len(), 3);
+    assert!(matches!(results.trace[1].action, Action::Create(_)));
+    assert!(results.state_diff.is_none());
+    assert!(results.vm_trace.is_none());
+
+    // When
+    let calls = vec![(request.clone(), trace_types.clone()), (request, trace_types)];
+    let raw = send_request(
+        server_addr,
+        RawRpcParamsBuilder::new("trace_callMany")
+            .add_param(calls)
+            .add_param(BlockId::Number(TRACING_BLOCK_NUMBER.into())),
+    )
+    .await;
+    let results: Vec<TraceResults> =
+        serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result");
+
+    // Then
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.trace.len() == 3));
+    // The second call is executed on top of the first one, so it creates its counter at another address.
+    assert!(results.iter().all(|result| matches!(result.trace[1].action, Action::Create(_))));
+    assert_ne!(results[0].trace[1].result, results[1].trace[1].result);
+
+    drop(server_handle);
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_trace_filter(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    // Given
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+    let (server_addr, server_handle) =
+        start_kakarot_rpc_server(&katana).await.expect("Error setting up Kakarot RPC server");
+    let filter = TraceFilter {
+        from_block: Some(TRACING_BLOCK_NUMBER),
+        to_block: Some(TRACING_BLOCK_NUMBER),
+        ..Default::default()
+    };
+
+    // When
+    let raw = send_request(server_addr, RawRpcParamsBuilder::new("trace_filter").add_param(&filter)).await;
+    let traces: Vec<LocalizedTransactionTrace> =
+        serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result");
+
+    // Then
+    // We expect 3 traces per transaction: CALL, CREATE, and CALL.
+    // Except for the last one which is out of resources.
+    assert_eq!(traces.len(), 3 * (TRACING_TRANSACTIONS_COUNT - 1) + 1);
+    assert!(traces.iter().all(|trace| trace.block_number == Some(TRACING_BLOCK_NUMBER)));
+
+    // When
+    let page = TraceFilter { after: Some(1), count: Some(2), ..filter.clone() };
+    let raw = send_request(server_addr, RawRpcParamsBuilder::new("trace_filter").add_param(&page)).await;
+    let page_traces: Vec<LocalizedTransactionTrace> =
+        serde_json::from_value(raw["result"].clone()).expect("Failed to deserialize result");
+
+    // Then
+    assert_eq!(page_traces, traces[1..3]);
+
+    // When
+    let reversed = TraceFilter { from_block: Some(TRACING_BLOCK_NUMBER + 1), ..filter.clone() };
+    let too_large = TraceFilter { from_block: Some(0), to_block: Some(MAX_TRACE_FILTER_BLOCKS), ..filter };
+    let reversed = send_request(server_addr, RawRpcParamsBuilder::new("trace_filter").add_param(reversed)).await;
+    let too_large = send_request(server_addr, RawRpcParamsBuilder::new("trace_filter").add_param(too_large)).await;
+
+    // Then
+    assert!(reversed["error"]["message"].as_str().unwrap().contains("fromBlock cannot be greater than toBlock"));
+    assert!(too_large["error"]["message"].as_str().unwrap().contains("block range exceeds the limit"));
+
+    drop(server_handle);
+}
@@ -2,10 +2,18 @@
 #![cfg(feature = "testing")]
 use alloy_consensus::Transaction;
 use alloy_dyn_abi::DynSolValue;
-use alloy_primitives::{Address, Bytes, B256, B64, U256};
+use alloy_primitives::{address, bytes, Address, Bytes, TxKind, B256, B64, U256};
+use alloy_rpc_types::{
+    state::{AccountOverride, StateOverride},
+    TransactionInput, TransactionRequest,
+};
 use alloy_rpc_types_trace::{
-    geth::{GethDebugTracingOptions, GethTrace, TraceResult},
-    parity::{Action, CallAction, CallOutput, CallType, TraceOutput, TransactionTrace},
+    geth::{
+        DefaultFrame, FourByteFrame, GethDebugBuiltInTracerType, GethDebugTracerConfig, GethDebugTracerType,
+        GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, MuxConfig, PreStateConfig, PreStateFrame,
+        TraceResult,
+    },
+    parity::{Action, CallAction, CallOutput, CallType, TraceOutput, TraceType, TransactionTrace},
 };
 use alloy_serde::{OtherFields, WithOtherFields};
 use kakarot_rpc::{
@@ -22,12 +30,12 @@ use revm_inspectors::tracing::TracingInspectorConfig;
 use rstest::*;
 use serde_json::json;
 use starknet::{core::types::MaybePendingBlockWithTxHashes, providers::Provider};
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 /// The block number on which tracing will be performed.
-const TRACING_BLOCK_NUMBER: u64 = 0x3;
+pub const TRACING_BLOCK_NUMBER: u64 = 0x3;
 /// The amount of transactions to be traced.
-const TRACING_TRANSACTIONS_COUNT: usize = 5;
+pub const TRACING_TRANSACTIONS_COUNT: usize = 5;
 
 /// Helper to create a header.
 fn header(block_number: u64, hash: B256, parent_hash: B256, base_fee: u64) -> alloy_rpc_types::Header {
@@ -188,6 +196,78 @@ async fn test_trace_block(#[future] plain_opcodes: (Katana, KakarotEvmContract),
     );
 }
 
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_trace_transaction(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+
+    // Given
+    let eth_provider = katana.eth_provider();
+    let block = eth_provider
+        .block_by_number(TRACING_BLOCK_NUMBER.into(), false)
+        .await
+        .expect("Failed to get block")
+        .expect("Block not found");
+    let tx_hash = block.transactions.as_hashes().unwrap()[1];
+
+    // When
+    let tracer = TracerBuilder::new(Arc::new(&eth_provider))
+        .await
+        .expect("Failed to create tracer_builder")
+        .with_transaction_hash(tx_hash)
+        .await
+        .expect("Failed to set transaction hash")
+        .with_tracing_options(TracingInspectorConfig::default_parity().into())
+        .build()
+        .expect("Failed to build tracer");
+    let traces = tracer.trace_transaction(tx_hash).expect("Failed to trace transaction");
+
+    // Then
+    // We expect 3 traces for the transaction: CALL, CREATE, and CALL.
+    assert_eq!(traces.len(), 3);
+    assert!(traces.iter().all(|trace| trace.transaction_hash == Some(tx_hash)));
+    assert!(matches!(traces[1].trace.action, Action::Create(_)));
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_replay_block_transactions(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+
+    // Given
+    let eth_provider = katana.eth_provider();
+    let trace_types: HashSet<_> = [TraceType::Trace, TraceType::StateDiff].into_iter().collect();
+
+    // When
+    let tracer = TracerBuilder::new(Arc::new(&eth_provider))
+        .await
+        .expect("Failed to create tracer_builder")
+        .with_block_id(TRACING_BLOCK_NUMBER.into())
+        .await
+        .expect("Failed to set block number")
+        .with_tracing_options(trace_types.into())
+        .build()
+        .expect("Failed to build tracer");
+    let replays = tracer.replay_block_transactions().expect("Failed to replay block transactions");
+
+    // Then
+    assert_eq!(replays.len(), TRACING_TRANSACTIONS_COUNT);
+    for replay in &replays[..TRACING_TRANSACTIONS_COUNT - 1] {
+        // We expect 3 traces per transaction: CALL, CREATE, and CALL.
+        assert_eq!(replay.full_trace.trace.len(), 3);
+        assert!(replay.full_trace.state_diff.as_ref().is_some_and(|state_diff| !state_diff.0.is_empty()));
+        assert!(replay.full_trace.vm_trace.is_none());
+    }
+    // The last transaction is out of resources and has no trace.
+    assert!(replays.last().unwrap().full_trace.trace.is_empty());
+}
+
 #[rstest]
 #[awt]
 #[tokio::test(flavor = "multi_thread")]
@@ -303,3 +383,183 @@ async fn test_debug_trace_transaction(#[future] plain_opcodes: (Katana, KakarotE
         TraceResult::Error { .. } => panic!("Expected a success trace result"),
     };
 }
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_debug_trace_call_default_tracer(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+
+    // Given
+    // A sender which only has a balance thanks to the state override.
+    let sender = address!("95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+    let mut state_overrides = StateOverride::default();
+    state_overrides.insert(sender, AccountOverride { balance: Some(U256::from(1_000_000)), ..Default::default() });
+
+    let request = TransactionRequest {
+        from: Some(sender),
+        to: Some(TxKind::Call(Address::ZERO)),
+        gas: Some(21000),
+        value: Some(U256::from(1)),
+        ..Default::default()
+    };
+    let opts = GethDebugTracingCallOptions {
+        tracing_options: GethDebugTracingOptions::default(),
+        state_overrides: Some(state_overrides),
+        block_overrides: None,
+    };
+
+    // When
+    let eth_provider = katana.eth_provider();
+    let tracer = TracerBuilder::new(Arc::new(&eth_provider))
+        .await
+        .expect("Failed to create tracer_builder")
+        .with_block_id(TRACING_BLOCK_NUMBER.into())
+        .await
+        .expect("Failed to set block number")
+        .with_tracing_options(opts.into())
+        .build()
+        .expect("Failed to build tracer");
+    let trace = tracer.debug_transaction_request(&request).expect("Failed to trace call");
+
+    // Then
+    assert_eq!(
+        trace,
+        GethTrace::Default(DefaultFrame { failed: false, gas: 21000, return_value: Bytes::new(), struct_logs: vec![] })
+    );
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_debug_trace_call_prestate_tracer(#[future] plain_opcodes: (Katana, KakarotEvmContract), _setup: ()) {
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+
+    // Given
+    // A sender which only has a balance thanks to the state override.
+    let sender = address!("95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+    let recipient = address!("388c818ca8b9251b393131c08a736a67ccb19297");
+    let mut state_overrides = StateOverride::default();
+    state_overrides.insert(sender, AccountOverride { balance: Some(U256::from(1_000_000)), ..Default::default() });
+
+    let request = TransactionRequest {
+        from: Some(sender),
+        to: Some(TxKind::Call(recipient)),
+        gas: Some(21000),
+        value: Some(U256::from(1)),
+        ..Default::default()
+    };
+    let eth_provider = katana.eth_provider();
+
+    for diff_mode in [false, true] {
+        let opts = GethDebugTracingCallOptions {
+            tracing_options: GethDebugTracingOptions::default()
+                .with_tracer(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::PreStateTracer))
+                .with_prestate_config(PreStateConfig { diff_mode: Some(diff_mode), ..Default::default() }),
+            state_overrides: Some(state_overrides.clone()),
+            block_overrides: None,
+        };
+
+        // When
+        let tracer = TracerBuilder::new(Arc::new(&eth_provider))
+            .await
+            .expect("Failed to create tracer_builder")
+            .with_block_id(TRACING_BLOCK_NUMBER.into())
+            .await
+            .expect("Failed to set block number")
+            .with_tracing_options(opts.into())
+            .build()
+            .expect("Failed to build tracer");
+        let trace = tracer.debug_transaction_request(&request).expect("Failed to trace call");
+
+        // Then
+        match trace {
+            GethTrace::PreStateTracer(PreStateFrame::Default(prestate)) => {
+                assert!(!diff_mode);
+                assert_eq!(prestate.0[&sender].balance, Some(U256::from(1_000_000)));
+                assert!(prestate.0.contains_key(&recipient));
+            }
+            GethTrace::PreStateTracer(PreStateFrame::Diff(diff)) => {
+                assert!(diff_mode);
+                assert_eq!(diff.pre[&sender].balance, Some(U256::from(1_000_000)));
+                assert_eq!(diff.post[&sender].balance, Some(U256::from(999_999)));
+                assert_eq!(diff.post[&recipient].balance, Some(U256::from(1)));
+            }
+            trace => panic!("Unexpected trace {trace:?}"),
+        }
+    }
+}
+
+#[rstest]
+#[awt]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_debug_trace_call_four_byte_and_mux_tracers(
+    #[future] plain_opcodes: (Katana, KakarotEvmContract),
+    _setup: (),
+) {
+    let katana = plain_opcodes.0;
+    let plain_opcodes = plain_opcodes.1;
+    tracing(&katana, &plain_opcodes, "createCounterAndInvoke", Box::new(|_| vec![])).await;
+
+    // Given
+    // A call with a selector and one extra byte of calldata.
+    let sender = address!("95222290dd7278aa3ddd389cc1e1d165cc4bafe5");
+    let mut state_overrides = StateOverride::default();
+    state_overrides.insert(sender, AccountOverride { balance: Some(U256::from(1_000_000)), ..Default::default() });
+
+    let request = TransactionRequest {
+        from: Some(sender),
+        to: Some(TxKind::Call(Address::ZERO)),
+        gas: Some(50000),
+        input: TransactionInput::new(bytes!("deadbeef00")),
+        ..Default::default()
+    };
+    let four_byte = GethDebugBuiltInTracerType::FourByteTracer;
+    let mux_config =
+        MuxConfig([(four_byte, None), (GethDebugBuiltInTracerType::NoopTracer, None)].into_iter().collect());
+    let eth_provider = katana.eth_provider();
+
+    let trace = |tracing_options: GethDebugTracingOptions| {
+        let eth_provider = &eth_provider;
+        let request = &request;
+        let opts = GethDebugTracingCallOptions {
+            tracing_options,
+            state_overrides: Some(state_overrides.clone()),
+            block_overrides: None,
+        };
+        async move {
+            TracerBuilder::new(Arc::new(eth_provider))
+                .await
+                .expect("Failed to create tracer_builder")
+                .with_block_id(TRACING_BLOCK_NUMBER.into())
+                .await
+                .expect("Failed to set block number")
+                .with_tracing_options(opts.into())
+                .build()
+                .expect("Failed to build tracer")
+                .debug_transaction_request(request)
+                .expect("Failed to trace call")
+        }
+    };
+
+    // When
+    let four_byte_trace =
+        trace(GethDebugTracingOptions::default().with_tracer(GethDebugTracerType::BuiltInTracer(four_byte))).await;
+    let mux_trace = trace(GethDebugTracingOptions {
+        tracer: Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::MuxTracer)),
+        tracer_config: GethDebugTracerConfig(serde_json::to_value(mux_config).expect("Failed to serialize")),
+        ..Default::default()
+    })
+    .await;
+
+    // Then
+    let expected_four_byte = FourByteFrame([("0xdeadbeef-1".to_string(), 1)].into_iter().collect());
+    assert_eq!(four_byte_trace, GethTrace::FourByteTracer(expected_four_byte.clone()));
+    let GethTrace::MuxTracer(mux_frame) = mux_trace else { panic!("Unexpected trace {mux_trace:?}") };
+    assert_eq!(mux_frame.0[&four_byte], GethTrace::FourByteTracer(expected_four_byte));
+    assert!(matches!(mux_frame.0[&GethDebugBuiltInTracerType::NoopTracer], GethTrace::NoopTracer(_)));
+}